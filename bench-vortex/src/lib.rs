@@ -16,6 +16,7 @@ use vortex::array::chunked::ChunkedArray;
 use vortex::arrow::FromArrowType;
 use vortex::compress::Compressor;
 use vortex::encoding::EncodingRef;
+use vortex::stream::ChunkCompressor;
 use vortex::{Array, Context, IntoArray, ToArrayData};
 use vortex_alp::ALPEncoding;
 use vortex_datetime_parts::DateTimePartsEncoding;
@@ -139,13 +140,14 @@ pub fn compress_taxi_data() -> Array {
 
     let schema = reader.schema();
     let mut uncompressed_size: usize = 0;
+    let mut compressor = ChunkCompressor::new(Compressor::new(&CTX));
     let chunks = reader
         .into_iter()
         .map(|batch_result| batch_result.unwrap())
         .map(|batch| batch.to_array_data().into_array())
         .map(|array| {
             uncompressed_size += array.nbytes();
-            Compressor::new(&CTX).compress(&array, None).unwrap()
+            compressor.compress(&array).unwrap()
         })
         .collect_vec();
 
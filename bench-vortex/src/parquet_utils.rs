@@ -3,7 +3,7 @@ use std::os::unix::fs::MetadataExt;
 use std::path::Path;
 
 use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
-use vortex::arrow::FromArrowType;
+use vortex::arrow::TryFromArrowType;
 use vortex_dtype::DType;
 use vortex_error::VortexResult;
 
@@ -26,7 +26,7 @@ pub fn sum_column_chunk_sizes(path: &Path) -> VortexResult<CompressionRunStats>
     }
 
     let stats = CompressionRunStats {
-        schema: DType::from_arrow(builder.schema().clone()),
+        schema: DType::try_from_arrow(builder.schema().clone())?,
         file_type: FileType::Parquet,
         total_compressed_size: Some(total_compressed_size),
         compressed_sizes,
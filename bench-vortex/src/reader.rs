@@ -21,16 +21,14 @@ use tokio::io::{AsyncReadExt, AsyncSeekExt};
 use tokio::runtime::Runtime;
 use vortex::array::chunked::ChunkedArray;
 use vortex::array::primitive::PrimitiveArray;
-use vortex::arrow::FromArrowType;
-use vortex::compress::Compressor;
+use vortex::iter::ArrayIteratorExt;
 use vortex::stream::ArrayStreamExt;
-use vortex::{Array, IntoArray, IntoCanonical, ToArrayData, ViewContext};
+use vortex::{Array, IntoArray, IntoCanonical};
 use vortex_buffer::Buffer;
-use vortex_dtype::DType;
+use vortex_cli::convert::{convert_parquet, ConvertOptions, ParquetChunks};
 use vortex_error::{vortex_err, VortexResult};
 use vortex_ipc::chunked_reader::ChunkedArrayReader;
 use vortex_ipc::io::{TokioAdapter, VortexWrite};
-use vortex_ipc::writer::ArrayWriter;
 use vortex_ipc::MessageReader;
 
 use crate::CTX;
@@ -73,13 +71,13 @@ pub async fn rewrite_parquet_as_vortex<W: VortexWrite>(
     parquet_path: PathBuf,
     write: W,
 ) -> VortexResult<()> {
-    let chunked = compress_parquet_to_vortex(parquet_path.as_path())?;
-
-    let written = ArrayWriter::new(write, ViewContext::from(&CTX.clone()))
-        .write_context()
-        .await?
-        .write_array_stream(chunked.array_stream())
-        .await?;
+    let written = convert_parquet(
+        File::open(parquet_path)?,
+        write,
+        &CTX,
+        &ConvertOptions::default().with_chunk_size(BATCH_SIZE),
+    )
+    .await?;
 
     let layout = written.array_layouts()[0].clone();
     let mut w = written.into_inner();
@@ -98,22 +96,12 @@ pub async fn rewrite_parquet_as_vortex<W: VortexWrite>(
 }
 
 pub fn compress_parquet_to_vortex(parquet_path: &Path) -> VortexResult<ChunkedArray> {
-    let taxi_pq = File::open(parquet_path)?;
-    let builder = ParquetRecordBatchReaderBuilder::try_new(taxi_pq)?;
-
-    // FIXME(ngates): #157 the compressor should handle batch size.
-    let reader = builder.with_batch_size(BATCH_SIZE).build()?;
-
-    let dtype = DType::from_arrow(reader.schema());
-
-    let chunks = reader
-        .map(|batch_result| batch_result.unwrap())
-        .map(|record_batch| {
-            let vortex_array = record_batch.to_array_data().into_array();
-            Compressor::new(&CTX).compress(&vortex_array, None).unwrap()
-        })
-        .collect_vec();
-    ChunkedArray::try_new(chunks, dtype)
+    ParquetChunks::try_new(
+        File::open(parquet_path)?,
+        &CTX,
+        &ConvertOptions::default().with_chunk_size(BATCH_SIZE),
+    )?
+    .try_into_chunked()
 }
 
 pub fn write_csv_as_parquet(csv_path: PathBuf, output_path: &Path) -> VortexResult<()> {
@@ -12,6 +12,25 @@ use crate::alp::ALPFloat;
 use crate::array::{ALPArray, ALPEncoding};
 use crate::Exponents;
 
+/// The number of values checked for exceptions when ALP limits them, see [`ALPConfig`].
+const EXCEPTION_SAMPLE_SIZE: usize = 1024;
+
+/// Configures ALP compression through [`CompressConfig::with_encoding_config`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ALPConfig {
+    /// ALP is only considered for arrays where at most this fraction of values would be stored
+    /// as exceptions.
+    pub max_exception_ratio: f32,
+}
+
+impl Default for ALPConfig {
+    fn default() -> Self {
+        Self {
+            max_exception_ratio: 1.0,
+        }
+    }
+}
+
 #[macro_export]
 macro_rules! match_each_alp_float_ptype {
     ($self:expr, | $_:tt $enc:ident | $($body:tt)*) => ({
@@ -27,10 +46,14 @@ macro_rules! match_each_alp_float_ptype {
 }
 
 impl EncodingCompression for ALPEncoding {
+    fn decode_cost(&self) -> f32 {
+        1.0
+    }
+
     fn can_compress(
         &self,
         array: &Array,
-        _config: &CompressConfig,
+        config: &CompressConfig,
     ) -> Option<&dyn EncodingCompression> {
         // Only support primitive arrays
         let parray = PrimitiveArray::try_from(array).ok()?;
@@ -40,6 +63,19 @@ impl EncodingCompression for ALPEncoding {
             return None;
         }
 
+        let max_exception_ratio = config
+            .encoding_config::<ALPConfig>()
+            .copied()
+            .unwrap_or_default()
+            .max_exception_ratio;
+        if max_exception_ratio < 1.0
+            && match_each_alp_float_ptype!(parray.ptype(), |$T| {
+                exception_ratio::<$T>(parray.maybe_null_slice::<$T>())
+            }) > max_exception_ratio
+        {
+            return None;
+        }
+
         Some(self)
     }
 
@@ -79,6 +115,24 @@ impl EncodingCompression for ALPEncoding {
     }
 }
 
+/// Estimate the fraction of values that ALP would store as exceptions.
+fn exception_ratio<T: ALPFloat>(values: &[T]) -> f32 {
+    if values.is_empty() {
+        return 0.0;
+    }
+
+    let exponents = T::find_best_exponents(values);
+    let sample = values
+        .iter()
+        .step_by(values.len().div_ceil(EXCEPTION_SAMPLE_SIZE))
+        .collect::<Vec<_>>();
+    let exceptions = sample
+        .iter()
+        .filter(|&&&v| T::encode_single(v, &exponents).is_err())
+        .count();
+    exceptions as f32 / sample.len() as f32
+}
+
 fn encode_to_array<T>(
     values: &PrimitiveArray,
     exponents: Option<&Exponents>,
@@ -209,4 +263,28 @@ mod tests {
         let decoded = decompress(encoded).unwrap();
         assert_eq!(values, decoded.maybe_null_slice::<f64>());
     }
+
+    #[test]
+    fn test_max_exception_ratio() {
+        let values = (0..1000)
+            .map(|i| {
+                if i % 10 == 0 {
+                    std::f64::consts::PI
+                } else {
+                    1.5
+                }
+            })
+            .collect::<Vec<_>>();
+        let array = PrimitiveArray::from(values).into_array();
+        let config = CompressConfig::default();
+        assert!(ALPEncoding.can_compress(&array, &config).is_some());
+        let config = config.with_encoding_config(ALPConfig {
+            max_exception_ratio: 0.2,
+        });
+        assert!(ALPEncoding.can_compress(&array, &config).is_some());
+        let config = config.with_encoding_config(ALPConfig {
+            max_exception_ratio: 0.05,
+        });
+        assert!(ALPEncoding.can_compress(&array, &config).is_none());
+    }
 }
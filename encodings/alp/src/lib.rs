@@ -1,5 +1,6 @@
 pub use alp::*;
 pub use array::*;
+pub use compress::ALPConfig;
 
 mod alp;
 mod array;
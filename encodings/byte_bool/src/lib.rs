@@ -119,7 +119,11 @@ impl AcceptArrayVisitor for ByteBoolArray {
     }
 }
 
-impl EncodingCompression for ByteBoolEncoding {}
+impl EncodingCompression for ByteBoolEncoding {
+    fn decode_cost(&self) -> f32 {
+        0.2
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -9,6 +9,10 @@ use vortex_error::VortexResult;
 use crate::{DateTimePartsArray, DateTimePartsEncoding};
 
 impl EncodingCompression for DateTimePartsEncoding {
+    fn decode_cost(&self) -> f32 {
+        1.0
+    }
+
     fn can_compress(
         &self,
         array: &Array,
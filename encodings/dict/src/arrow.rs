@@ -0,0 +1,71 @@
+use arrow_array::types::ArrowDictionaryKeyType;
+use arrow_array::{new_null_array, Array as ArrowArray, DictionaryArray};
+use arrow_buffer::ArrowNativeType;
+use arrow_select::concat::concat;
+use vortex::array::primitive::PrimitiveArray;
+use vortex::arrow::TryFromArrowArray;
+use vortex::{ArrayData, IntoArray};
+use vortex_error::VortexResult;
+
+use crate::DictArray;
+
+/// Import an Arrow dictionary without decoding it.
+///
+/// Null keys are mapped to a null value appended to the dictionary, since codes are non-nullable.
+impl<K: ArrowDictionaryKeyType> TryFromArrowArray<&DictionaryArray<K>> for DictArray {
+    fn try_from_arrow(value: &DictionaryArray<K>, nullable: bool) -> VortexResult<Self> {
+        let keys = value.keys();
+        let (values, null_code) = if keys.null_count() > 0 {
+            let null = new_null_array(value.values().data_type(), 1);
+            (
+                concat(&[value.values().as_ref(), null.as_ref()])?,
+                value.values().len(),
+            )
+        } else {
+            (value.values().clone(), 0)
+        };
+
+        let codes = keys.iter().map(|k| k.map_or(null_code, |k| k.as_usize()));
+        let codes = if values.len() <= u32::MAX as usize {
+            PrimitiveArray::from(codes.map(|c| c as u32).collect::<Vec<_>>())
+        } else {
+            PrimitiveArray::from(codes.map(|c| c as u64).collect::<Vec<_>>())
+        };
+
+        Self::try_new(
+            codes.into_array(),
+            ArrayData::try_from_arrow(values, nullable)?.into_array(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::types::Int32Type;
+    use arrow_array::DictionaryArray;
+    use vortex::arrow::TryFromArrowArray;
+    use vortex::compute::unary::scalar_at::scalar_at;
+    use vortex::{ArrayDType, IntoArray};
+
+    use crate::DictArray;
+
+    #[test]
+    fn test_null_keys() {
+        let arrow: DictionaryArray<Int32Type> = vec![Some("a"), None, Some("b"), Some("a")]
+            .into_iter()
+            .collect();
+        let array = DictArray::try_from_arrow(&arrow, true).unwrap();
+        assert_eq!(array.values().len(), 3);
+
+        let array = array.into_array();
+        assert!(array.dtype().is_nullable());
+        assert!(scalar_at(&array, 1).unwrap().is_null());
+        assert_eq!(scalar_at(&array, 3).unwrap(), scalar_at(&array, 0).unwrap());
+    }
+
+    #[test]
+    fn test_non_nullable_with_nulls() {
+        let arrow: DictionaryArray<Int32Type> = vec![Some("a"), None].into_iter().collect();
+        assert!(DictArray::try_from_arrow(&arrow, false).is_err());
+    }
+}
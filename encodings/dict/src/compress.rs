@@ -2,14 +2,14 @@ use std::hash::{Hash, Hasher};
 
 use ahash::RandomState;
 use hashbrown::hash_map::{Entry, RawEntryMut};
-use hashbrown::HashMap;
+use hashbrown::{HashMap, HashSet};
 use num_traits::AsPrimitive;
 use vortex::accessor::ArrayAccessor;
 use vortex::array::primitive::{Primitive, PrimitiveArray};
 use vortex::array::varbin::{VarBin, VarBinArray};
-use vortex::compress::{CompressConfig, Compressor, EncodingCompression};
-use vortex::stats::ArrayStatistics;
-use vortex::validity::Validity;
+use vortex::compress::{sample, CompressConfig, Compressor, EncodingCompression};
+use vortex::stats::{ArrayStatistics, Stat};
+use vortex::validity::{ArrayValidity, Validity};
 use vortex::{Array, ArrayDType, ArrayDef, IntoArray, ToArray};
 use vortex_dtype::{match_each_native_ptype, DType};
 use vortex_dtype::{NativePType, ToBytes};
@@ -17,27 +17,74 @@ use vortex_error::VortexResult;
 
 use crate::dict::{DictArray, DictEncoding};
 
+/// Relative error of the estimated distinct count within which it is too close to
+/// [`DictConfig::max_dict_size`] to decide whether it exceeds it, about four standard errors.
+const DISTINCT_COUNT_MARGIN: f64 = 0.15;
+
+/// Configures dictionary compression through [`CompressConfig::with_encoding_config`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DictConfig {
+    /// Dictionary encoding is only considered for arrays with at most this many distinct values.
+    pub max_dict_size: usize,
+    /// Dictionary encoding is not considered for arrays whose estimated distinct count is at least
+    /// this fraction of their non-null values. The codes of such an array take about as much space
+    /// as its values, so the dictionary is pure overhead. The estimate has a few percent of error,
+    /// hence the default of 0.9 rather than 1.
+    pub max_unique_ratio: f64,
+}
+
+impl Default for DictConfig {
+    fn default() -> Self {
+        Self {
+            max_dict_size: usize::MAX,
+            max_unique_ratio: 0.9,
+        }
+    }
+}
+
 impl EncodingCompression for DictEncoding {
+    fn decode_cost(&self) -> f32 {
+        0.5
+    }
+
     fn can_compress(
         &self,
         array: &Array,
-        _config: &CompressConfig,
+        config: &CompressConfig,
     ) -> Option<&dyn EncodingCompression> {
         // TODO(robert): Add support for VarBinView
         if array.encoding().id() != Primitive::ID && array.encoding().id() != VarBin::ID {
             return None;
         };
 
-        // No point dictionary coding if the array is unique.
-        // We don't have a unique stat yet, but strict-sorted implies unique.
-        if array
-            .statistics()
-            .compute_is_strict_sorted()
-            .unwrap_or(false)
-        {
+        let dict_config = config
+            .encoding_config::<DictConfig>()
+            .copied()
+            .unwrap_or_default();
+
+        // No point dictionary coding if (nearly) every value is unique.
+        let (distinct_count, valid_count, sampled) = distinct_and_valid_counts(array, config)?;
+        if distinct_count as f64 >= valid_count as f64 * dict_config.max_unique_ratio {
             return None;
         }
 
+        let max_dict_size = dict_config.max_dict_size;
+        if max_dict_size < array.len() {
+            // Only estimates close to the limit are checked by counting the distinct values. A
+            // sample can only show that there are too many distinct values, not too few.
+            let max = max_dict_size as f64;
+            let exceeds = if distinct_count as f64 > max * (1.0 + DISTINCT_COUNT_MARGIN) {
+                true
+            } else if !sampled && (distinct_count as f64) < max * (1.0 - DISTINCT_COUNT_MARGIN) {
+                false
+            } else {
+                exceeds_distinct(array, max_dict_size).ok()?
+            };
+            if exceeds {
+                return None;
+            }
+        }
+
         Some(self)
     }
 
@@ -106,6 +153,60 @@ impl<T: ToBytes> PartialEq<Self> for Value<T> {
 
 impl<T: ToBytes> Eq for Value<T> {}
 
+/// The estimated distinct and non-null counts of the array, and whether they were estimated from
+/// a sample. Large arrays are sampled unless their distinct count is already known, since
+/// estimating it reads every value.
+fn distinct_and_valid_counts(
+    array: &Array,
+    config: &CompressConfig,
+) -> Option<(usize, usize, bool)> {
+    let sample_len = config.sample_size() as usize * config.sample_count() as usize;
+    let cached = array.statistics().get_as::<usize>(Stat::DistinctCount);
+    let (array, sampled) = if cached.is_none() && array.len() > sample_len {
+        (sample(array, config).ok()?, true)
+    } else {
+        (array.clone(), false)
+    };
+
+    let distinct_count = match cached {
+        Some(distinct_count) => distinct_count,
+        None => array.statistics().compute_distinct_count()?,
+    };
+    let valid_count = array.len() - array.statistics().compute_null_count().unwrap_or(0);
+    Some((distinct_count, valid_count, sampled))
+}
+
+/// Whether a primitive or varbin array holds more than `max` distinct non-null values, stopping as
+/// soon as it finds more.
+fn exceeds_distinct(array: &Array, max: usize) -> VortexResult<bool> {
+    Ok(match array.encoding().id() {
+        Primitive::ID => {
+            let p = PrimitiveArray::try_from(array)?;
+            match_each_native_ptype!(p.ptype(), |$P| {
+                let mut distinct = HashSet::new();
+                p.maybe_null_slice::<$P>()
+                    .iter()
+                    .enumerate()
+                    .filter(|&(idx, _)| p.is_valid(idx))
+                    .any(|(_, &v)| distinct.insert(Value(v)) && distinct.len() > max)
+            })
+        }
+        VarBin::ID => {
+            let vb = VarBinArray::try_from(array)?;
+            vb.with_iterator(|iter| {
+                let mut distinct: HashSet<Vec<u8>> = HashSet::new();
+                iter.flatten().any(|v| {
+                    if !distinct.contains(v) {
+                        distinct.insert(v.to_vec());
+                    }
+                    distinct.len() > max
+                })
+            })?
+        }
+        _ => false,
+    })
+}
+
 /// Dictionary encode primitive array with given PType.
 /// Null values in the original array are encoded in the dictionary.
 pub fn dict_encode_typed_primitive<T: NativePType>(
@@ -249,13 +350,16 @@ mod test {
     use vortex::accessor::ArrayAccessor;
     use vortex::array::primitive::PrimitiveArray;
     use vortex::array::varbin::VarBinArray;
+    use vortex::compress::{CompressConfig, EncodingCompression};
     use vortex::compute::unary::scalar_at::scalar_at;
-    use vortex::ToArray;
+    use vortex::stats::{ArrayStatistics, Stat};
+    use vortex::{IntoArray, ToArray};
     use vortex_dtype::Nullability::Nullable;
     use vortex_dtype::{DType, PType};
     use vortex_scalar::Scalar;
 
-    use crate::compress::{dict_encode_typed_primitive, dict_encode_varbin};
+    use crate::compress::{dict_encode_typed_primitive, dict_encode_varbin, DictConfig};
+    use crate::DictEncoding;
 
     #[test]
     fn encode_primitive() {
@@ -357,4 +461,69 @@ mod test {
             &[0u64, 0, 1, 1, 0, 1, 0, 1]
         );
     }
+
+    #[test]
+    fn unique_values() {
+        let unique = PrimitiveArray::from((0..1000).rev().collect::<Vec<i32>>()).into_array();
+        assert!(DictEncoding
+            .can_compress(&unique, &CompressConfig::default())
+            .is_none());
+
+        let repeated =
+            PrimitiveArray::from((0..1000).map(|i| i % 500).collect::<Vec<i32>>()).into_array();
+        assert!(DictEncoding
+            .can_compress(&repeated, &CompressConfig::default())
+            .is_some());
+    }
+
+    #[test]
+    fn large_array_distinct_count() {
+        // Too large to estimate over every value, so a sample is checked instead.
+        let unique = PrimitiveArray::from((0..10_000).collect::<Vec<i32>>()).into_array();
+        assert!(DictEncoding
+            .can_compress(&unique, &CompressConfig::default())
+            .is_none());
+        assert!(unique.statistics().get(Stat::DistinctCount).is_none());
+
+        // A known distinct count is used as is.
+        unique.statistics().set(Stat::DistinctCount, 10u64.into());
+        assert!(DictEncoding
+            .can_compress(&unique, &CompressConfig::default())
+            .is_some());
+    }
+
+    #[test]
+    fn max_dict_size() {
+        let primitive = PrimitiveArray::from((0..100).map(|i| i % 10).collect::<Vec<i32>>());
+        let varbin = VarBinArray::from(
+            (0..100)
+                .map(|i| format!("v{}", i % 10))
+                .collect::<Vec<_>>()
+                .iter()
+                .map(String::as_str)
+                .collect::<Vec<_>>(),
+        );
+        for array in [primitive.into_array(), varbin.into_array()] {
+            let config = |max_dict_size| {
+                CompressConfig::default().with_encoding_config(DictConfig {
+                    max_dict_size,
+                    ..Default::default()
+                })
+            };
+            assert!(DictEncoding.can_compress(&array, &config(10)).is_some());
+            assert!(DictEncoding.can_compress(&array, &config(9)).is_none());
+        }
+
+        // Estimates far above the limit are rejected without counting the distinct values.
+        let many = PrimitiveArray::from((0..2000).map(|i| i % 1000).collect::<Vec<i32>>());
+        assert!(DictEncoding
+            .can_compress(
+                &many.into_array(),
+                &CompressConfig::default().with_encoding_config(DictConfig {
+                    max_dict_size: 100,
+                    ..Default::default()
+                })
+            )
+            .is_none());
+    }
 }
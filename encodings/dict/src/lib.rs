@@ -5,6 +5,7 @@
 pub use compress::*;
 pub use dict::*;
 
+mod arrow;
 mod compress;
 mod compute;
 mod dict;
@@ -16,15 +16,35 @@ use vortex_scalar::Scalar;
 
 use crate::{BitPackedArray, BitPackedEncoding};
 
+/// Configures bit-packing through [`CompressConfig::with_encoding_config`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BitPackedConfig {
+    /// The largest fraction of values that may be stored as patches, values that don't fit in
+    /// the packed bit width. Wider bit widths are chosen to stay within it.
+    pub max_patch_ratio: f32,
+}
+
+impl Default for BitPackedConfig {
+    fn default() -> Self {
+        Self {
+            max_patch_ratio: 1.0,
+        }
+    }
+}
+
 impl EncodingCompression for BitPackedEncoding {
     fn cost(&self) -> u8 {
         0
     }
 
+    fn decode_cost(&self) -> f32 {
+        0.5
+    }
+
     fn can_compress(
         &self,
         array: &Array,
-        _config: &CompressConfig,
+        config: &CompressConfig,
     ) -> Option<&dyn EncodingCompression> {
         // Only support primitive arrays
         let parray = PrimitiveArray::try_from(array).ok()?;
@@ -36,7 +56,11 @@ impl EncodingCompression for BitPackedEncoding {
 
         let bytes_per_exception = bytes_per_exception(parray.ptype());
         let bit_width_freq = parray.statistics().compute_bit_width_freq()?;
-        let bit_width = best_bit_width(&bit_width_freq, bytes_per_exception);
+        let bit_width = best_bit_width(
+            &bit_width_freq,
+            bytes_per_exception,
+            max_patches(config, parray.len()),
+        );
 
         // Check that the bit width is less than the type's bit width
         if bit_width == parray.ptype().bit_width() {
@@ -59,7 +83,11 @@ impl EncodingCompression for BitPackedEncoding {
             .ok_or_else(|| vortex_err!(ComputeError: "missing bit width frequency"))?;
 
         let like_bp = like.map(|l| BitPackedArray::try_from(l).unwrap());
-        let bit_width = best_bit_width(&bit_width_freq, bytes_per_exception(parray.ptype()));
+        let bit_width = best_bit_width(
+            &bit_width_freq,
+            bytes_per_exception(parray.ptype()),
+            max_patches(ctx.options(), parray.len()),
+        );
         let num_exceptions = count_exceptions(bit_width, &bit_width_freq);
 
         if bit_width == parray.ptype().bit_width() {
@@ -325,9 +353,14 @@ pub unsafe fn unpack_single_primitive<T: NativePType + BitPacking>(
     Ok(unsafe { BitPacking::unchecked_unpack_single(bit_width, packed_chunk, index_in_chunk) })
 }
 
-/// Assuming exceptions cost 1 value + 1 u32 index, figure out the best bit-width to use.
-/// We could try to be clever, but we can never really predict how the exceptions will compress.
-fn best_bit_width(bit_width_freq: &[usize], bytes_per_exception: usize) -> usize {
+/// The bit width minimising the packed size plus `bytes_per_exception` for every value that does
+/// not fit and must be patched. Widths that would need more than `max_exceptions` patches are
+/// never chosen.
+fn best_bit_width(
+    bit_width_freq: &[usize],
+    bytes_per_exception: usize,
+    max_exceptions: usize,
+) -> usize {
     let len: usize = bit_width_freq.iter().sum();
 
     if bit_width_freq.len() > u8::MAX as usize {
@@ -335,10 +368,17 @@ fn best_bit_width(bit_width_freq: &[usize], bytes_per_exception: usize) -> usize
     }
 
     let mut num_packed = 0;
-    let mut best_cost = len * bytes_per_exception;
+    let mut best_cost = if len <= max_exceptions {
+        len * bytes_per_exception
+    } else {
+        usize::MAX
+    };
     let mut best_width = 0;
     for (bit_width, freq) in bit_width_freq.iter().enumerate() {
         num_packed += *freq;
+        if len - num_packed > max_exceptions {
+            continue;
+        }
         let packed_cost = ((bit_width * len) + 7) / 8;
         let exceptions_cost = (len - num_packed) * bytes_per_exception;
         let cost = exceptions_cost + packed_cost;
@@ -351,6 +391,15 @@ fn best_bit_width(bit_width_freq: &[usize], bytes_per_exception: usize) -> usize
     best_width
 }
 
+fn max_patches(config: &CompressConfig, len: usize) -> usize {
+    let max_patch_ratio = config
+        .encoding_config::<BitPackedConfig>()
+        .copied()
+        .unwrap_or_default()
+        .max_patch_ratio;
+    (max_patch_ratio as f64 * len as f64) as usize
+}
+
 fn bytes_per_exception(ptype: PType) -> usize {
     ptype.byte_width() + 4
 }
@@ -380,7 +429,10 @@ mod test {
         // 10 1-bit values, 20 2-bit, etc.
         let freq = vec![0, 10, 20, 15, 1, 0, 0, 0];
         // 3-bits => (46 * 3) + (8 * 1 * 5) => 178 bits => 23 bytes and zero exceptions
-        assert_eq!(best_bit_width(&freq, bytes_per_exception(PType::U8)), 3);
+        assert_eq!(
+            best_bit_width(&freq, bytes_per_exception(PType::U8), usize::MAX),
+            3
+        );
     }
 
     #[test]
@@ -395,6 +447,34 @@ mod test {
         assert_eq!(BitPackedArray::try_from(compressed).unwrap().bit_width(), 6);
     }
 
+    #[test]
+    fn test_max_patch_ratio() {
+        let values = PrimitiveArray::from(Vec::from_iter((0..10_000).map(|i| {
+            if i % 20 == 0 {
+                1000u16
+            } else {
+                3
+            }
+        })));
+        let compress = |max_patch_ratio| {
+            let config =
+                CompressConfig::default().with_encoding_config(BitPackedConfig { max_patch_ratio });
+            BitPackedArray::try_from(
+                Compressor::new_with_options(&ctx(), config)
+                    .compress(values.array(), None)
+                    .unwrap(),
+            )
+            .unwrap()
+        };
+
+        let patched = compress(1.0);
+        assert_eq!(patched.bit_width(), 2);
+        assert!(patched.patches().is_some());
+        let unpatched = compress(0.01);
+        assert_eq!(unpatched.bit_width(), 10);
+        assert!(unpatched.patches().is_none());
+    }
+
     #[test]
     fn test_compression_roundtrip() {
         compression_roundtrip(125);
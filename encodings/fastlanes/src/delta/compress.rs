@@ -13,6 +13,10 @@ use vortex_error::VortexResult;
 use crate::{DeltaArray, DeltaEncoding};
 
 impl EncodingCompression for DeltaEncoding {
+    fn decode_cost(&self) -> f32 {
+        1.0
+    }
+
     fn can_compress(
         &self,
         array: &Array,
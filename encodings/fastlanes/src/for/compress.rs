@@ -17,6 +17,10 @@ impl EncodingCompression for FoREncoding {
         0
     }
 
+    fn decode_cost(&self) -> f32 {
+        0.2
+    }
+
     fn can_compress(
         &self,
         array: &Array,
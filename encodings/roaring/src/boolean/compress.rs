@@ -1,21 +1,25 @@
 use croaring::Bitmap;
-use vortex::array::bool::BoolArray;
+use vortex::array::bool::{Bool, BoolArray};
 use vortex::compress::{CompressConfig, Compressor, EncodingCompression};
 use vortex::{Array, ArrayDType, ArrayDef, ArrayTrait, IntoArray, IntoArrayVariant};
 use vortex_dtype::DType;
 use vortex_dtype::Nullability::NonNullable;
 use vortex_error::VortexResult;
 
-use crate::{RoaringBool, RoaringBoolArray, RoaringBoolEncoding};
+use crate::{RoaringBoolArray, RoaringBoolEncoding};
 
 impl EncodingCompression for RoaringBoolEncoding {
+    fn decode_cost(&self) -> f32 {
+        2.0
+    }
+
     fn can_compress(
         &self,
         array: &Array,
         _config: &CompressConfig,
     ) -> Option<&dyn EncodingCompression> {
         // Only support bool enc arrays
-        if array.encoding().id() != RoaringBool::ID {
+        if array.encoding().id() != Bool::ID {
             return None;
         }
 
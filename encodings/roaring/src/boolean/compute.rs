@@ -1,9 +1,11 @@
 use croaring::Bitmap;
 use vortex::compute::slice::SliceFn;
+use vortex::compute::take::TakeFn;
 use vortex::compute::unary::scalar_at::ScalarAtFn;
 use vortex::compute::ArrayCompute;
-use vortex::{Array, IntoArray};
-use vortex_error::VortexResult;
+use vortex::{Array, ArrayTrait, IntoArray, IntoArrayVariant};
+use vortex_dtype::match_each_integer_ptype;
+use vortex_error::{vortex_bail, VortexResult};
 use vortex_scalar::Scalar;
 
 use crate::RoaringBoolArray;
@@ -16,6 +18,10 @@ impl ArrayCompute for RoaringBoolArray {
     fn slice(&self) -> Option<&dyn SliceFn> {
         Some(self)
     }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
 }
 
 impl ScalarAtFn for RoaringBoolArray {
@@ -36,3 +42,25 @@ impl SliceFn for RoaringBoolArray {
         Self::try_new(bitmap, stop - start).map(|a| a.into_array())
     }
 }
+
+impl TakeFn for RoaringBoolArray {
+    fn take(&self, indices: &Array) -> VortexResult<Array> {
+        let indices = indices.clone().into_primitive()?;
+        let bitmap = self.bitmap();
+        let mut taken = Bitmap::new();
+        match_each_integer_ptype!(indices.ptype(), |$I| {
+            for (pos, &idx) in indices.maybe_null_slice::<$I>().iter().enumerate() {
+                let idx = idx as usize;
+                if idx >= self.len() {
+                    vortex_bail!(OutOfBounds: idx, 0, self.len());
+                }
+                if bitmap.contains(idx as u32) {
+                    taken.add(pos as u32);
+                }
+            }
+        });
+        taken.run_optimize();
+
+        Self::try_new(taken, indices.len()).map(|a| a.into_array())
+    }
+}
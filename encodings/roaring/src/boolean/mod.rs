@@ -1,10 +1,12 @@
-use arrow_buffer::BooleanBuffer;
-use arrow_buffer::Buffer as ArrowBuffer;
+use std::fmt::Formatter;
+use std::sync::OnceLock;
+
+use arrow_buffer::{bit_util, BooleanBuffer, MutableBuffer};
 use compress::roaring_encode;
 use croaring::{Bitmap, Portable};
 use serde::{Deserialize, Serialize};
 use vortex::array::bool::{Bool, BoolArray};
-use vortex::stats::ArrayStatisticsCompute;
+use vortex::stats::{ArrayStatisticsCompute, Stat};
 use vortex::validity::{ArrayValidity, LogicalValidity, Validity};
 use vortex::visitor::{AcceptArrayVisitor, ArrayVisitor};
 use vortex::{impl_encoding, ArrayDType, Canonical, IntoCanonical};
@@ -21,33 +23,59 @@ impl_encoding!("vortex.roaring_bool", RoaringBool);
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RoaringBoolMetadata {
     length: usize,
+    #[serde(skip)]
+    bitmap: BitmapCache,
+}
+
+/// The deserialized bitmap, shared between all clones of the metadata. The metadata of an array
+/// view is deserialized once per view, so the bitmap is shared between all clones of the view too.
+#[derive(Clone, Default)]
+struct BitmapCache(Arc<OnceLock<Bitmap>>);
+
+impl Debug for BitmapCache {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("BitmapCache")
+    }
 }
 
 impl RoaringBoolArray {
     pub fn try_new(bitmap: Bitmap, length: usize) -> VortexResult<Self> {
-        if length < bitmap.cardinality() as usize {
-            vortex_bail!("RoaringBoolArray length is less than bitmap cardinality")
-        } else {
-            Ok(Self {
-                typed: TypedArray::try_from_parts(
-                    DType::Bool(NonNullable),
-                    RoaringBoolMetadata { length },
-                    Some(Buffer::from(bitmap.serialize::<Portable>())),
-                    vec![].into(),
-                    StatsSet::new(),
-                )?,
-            })
+        if bitmap.maximum().map_or(false, |max| max as usize >= length) {
+            vortex_bail!("RoaringBoolArray length is less than bitmap maximum")
         }
+
+        let buffer = Buffer::from(bitmap.serialize::<Portable>());
+        let metadata = RoaringBoolMetadata {
+            length,
+            bitmap: BitmapCache::default(),
+        };
+        metadata
+            .bitmap
+            .0
+            .set(bitmap)
+            .unwrap_or_else(|_| unreachable!("bitmap cache is freshly created"));
+
+        Ok(Self {
+            typed: TypedArray::try_from_parts(
+                DType::Bool(NonNullable),
+                metadata,
+                Some(buffer),
+                vec![].into(),
+                StatsSet::new(),
+            )?,
+        })
     }
 
-    pub fn bitmap(&self) -> Bitmap {
-        //TODO(@jdcasale): figure out a way to avoid this deserialization per-call
-        Bitmap::deserialize::<Portable>(
-            self.array()
-                .buffer()
-                .expect("RoaringBoolArray buffer is missing")
-                .as_ref(),
-        )
+    /// The positions of the true values, deserialized at most once per array or array view.
+    pub fn bitmap(&self) -> &Bitmap {
+        self.metadata().bitmap.0.get_or_init(|| {
+            Bitmap::deserialize::<Portable>(
+                self.array()
+                    .buffer()
+                    .expect("RoaringBoolArray buffer is missing")
+                    .as_ref(),
+            )
+        })
     }
 
     pub fn encode(array: Array) -> VortexResult<Array> {
@@ -59,12 +87,12 @@ impl RoaringBoolArray {
     }
 }
 impl AcceptArrayVisitor for RoaringBoolArray {
-    fn accept(&self, _visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
-        // TODO(ngates): should we store a buffer in memory? Or delay serialization?
-        //  Or serialize into metadata? The only reason we support buffers is so we can write to
-        //  the wire without copying into FlatBuffers. But if we need to allocate to serialize
-        //  the bitmap anyway, then may as well shove it into metadata.
-        todo!()
+    fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        visitor.visit_buffer(
+            self.array()
+                .buffer()
+                .expect("RoaringBoolArray buffer is missing"),
+        )
     }
 }
 
@@ -74,7 +102,25 @@ impl ArrayTrait for RoaringBoolArray {
     }
 }
 
-impl ArrayStatisticsCompute for RoaringBoolArray {}
+impl ArrayStatisticsCompute for RoaringBoolArray {
+    fn compute_statistics(&self, _stat: Stat) -> VortexResult<StatsSet> {
+        // Counting is answered by the bitmap without expanding it.
+        let true_count = self.bitmap().cardinality() as usize;
+        let len = self.len();
+        let mut stats = StatsSet::new();
+        stats.set(Stat::TrueCount, true_count.into());
+        stats.set(Stat::NullCount, 0usize.into());
+        if len > 0 {
+            stats.set(Stat::Min, (true_count == len).into());
+            stats.set(Stat::Max, (true_count > 0).into());
+            stats.set(
+                Stat::IsConstant,
+                (true_count == 0 || true_count == len).into(),
+            );
+        }
+        Ok(stats)
+    }
+}
 
 impl ArrayValidity for RoaringBoolArray {
     fn logical_validity(&self) -> LogicalValidity {
@@ -88,17 +134,12 @@ impl ArrayValidity for RoaringBoolArray {
 
 impl IntoCanonical for RoaringBoolArray {
     fn into_canonical(self) -> VortexResult<Canonical> {
-        // TODO(ngates): benchmark the fastest conversion from BitMap.
-        //  Via bitset requires two copies.
-        let bitset = self
-            .bitmap()
-            .to_bitset()
-            .ok_or_else(|| vortex_err!("Failed to convert RoaringBitmap to Bitset"))?;
-
-        let bytes = &bitset.as_slice()[0..bitset.size_in_bytes()];
-        let buffer = ArrowBuffer::from_slice_ref(bytes);
+        let mut buffer = MutableBuffer::from_len_zeroed(self.len().div_ceil(8));
+        for idx in self.bitmap().iter() {
+            bit_util::set_bit(buffer.as_slice_mut(), idx as usize);
+        }
         Ok(Canonical::Bool(BoolArray::try_new(
-            BooleanBuffer::new(buffer, 0, bitset.size_in_bits()),
+            BooleanBuffer::new(buffer.into(), 0, self.len()),
             match self.dtype().nullability() {
                 NonNullable => Validity::NonNullable,
                 Nullable => Validity::AllValid,
@@ -110,8 +151,10 @@ impl IntoCanonical for RoaringBoolArray {
 #[cfg(test)]
 mod test {
     use vortex::array::bool::BoolArray;
+    use vortex::array::primitive::PrimitiveArray;
     use vortex::compute::unary::scalar_at::scalar_at;
-    use vortex::IntoArray;
+    use vortex::validity::{LogicalValidity, Validity};
+    use vortex::{IntoArray, IntoArrayVariant};
     use vortex_error::VortexResult;
     use vortex_scalar::Scalar;
 
@@ -143,4 +186,35 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    pub fn test_trailing_false_round_trip() -> VortexResult<()> {
+        let bools = vec![true, false, true, false, false];
+        let array = RoaringBoolArray::encode(BoolArray::from(bools.clone()).into_array())?;
+        let round_trip = array.into_bool()?.boolean_buffer();
+        assert_eq!(round_trip.iter().collect::<Vec<_>>(), bools);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_validity() -> VortexResult<()> {
+        let valid = (0..100).map(|i| i != 42).collect::<Vec<_>>();
+        let array = RoaringBoolArray::encode(BoolArray::from(valid).into_array())?;
+        let validity = Validity::Array(array);
+
+        assert!(validity.is_valid(41));
+        assert!(!validity.is_valid(42));
+        assert!(!validity.slice(40, 50)?.is_valid(2));
+        assert!(matches!(
+            validity.slice(50, 100)?.to_logical(50),
+            LogicalValidity::AllValid(50)
+        ));
+
+        let taken = validity.take(&PrimitiveArray::from(vec![42u32, 0]).into_array())?;
+        assert!(!taken.is_valid(0));
+        assert!(taken.is_valid(1));
+
+        Ok(())
+    }
 }
@@ -1,30 +1,34 @@
 use croaring::Bitmap;
 use log::debug;
 use num_traits::NumCast;
-use vortex::array::primitive::PrimitiveArray;
+use vortex::array::primitive::{Primitive, PrimitiveArray};
 use vortex::compress::{CompressConfig, Compressor, EncodingCompression};
-use vortex::stats::ArrayStatistics;
+use vortex::stats::{ArrayStatistics, Stat};
 use vortex::IntoArrayVariant;
 use vortex::{Array, ArrayDType, ArrayDef, IntoArray};
-use vortex_dtype::{NativePType, PType};
-use vortex_error::VortexResult;
+use vortex_dtype::{match_each_integer_ptype, NativePType};
+use vortex_error::{vortex_bail, VortexResult};
 
-use crate::{RoaringInt, RoaringIntArray, RoaringIntEncoding};
+use crate::{RoaringIntArray, RoaringIntEncoding};
 
 impl EncodingCompression for RoaringIntEncoding {
+    fn decode_cost(&self) -> f32 {
+        2.0
+    }
+
     fn can_compress(
         &self,
         array: &Array,
         _config: &CompressConfig,
     ) -> Option<&dyn EncodingCompression> {
         // Only support primitive enc arrays
-        if array.encoding().id() != RoaringInt::ID {
+        if array.encoding().id() != Primitive::ID {
             return None;
         }
 
-        // Only support non-nullable uint arrays
-        if !array.dtype().is_unsigned_int() || array.dtype().is_nullable() {
-            debug!("Skipping roaring int, not a uint");
+        // Only support non-nullable int arrays
+        if !array.dtype().is_int() || array.dtype().is_nullable() {
+            debug!("Skipping roaring int, not an int");
             return None;
         }
 
@@ -38,8 +42,9 @@ impl EncodingCompression for RoaringIntEncoding {
             return None;
         }
 
-        if array.statistics().compute_max().unwrap_or(0) > u32::MAX as usize {
-            debug!("Skipping roaring int, max is larger than {}", u32::MAX);
+        let (min, max) = min_max(array)?;
+        if offset_for_range(min, max).is_none() {
+            debug!("Skipping roaring int, range {}..={} is too wide", min, max);
             return None;
         }
 
@@ -54,24 +59,96 @@ impl EncodingCompression for RoaringIntEncoding {
         _ctx: Compressor,
     ) -> VortexResult<Array> {
         let parray = array.clone().into_primitive()?;
-        Ok(roaring_encode(parray).into_array())
+        Ok(roaring_encode(parray)?.into_array())
+    }
+}
+
+fn min_max(array: &Array) -> Option<(i128, i128)> {
+    if array.dtype().is_signed_int() {
+        let min = array.statistics().compute_as_cast::<i64>(Stat::Min)?;
+        let max = array.statistics().compute_as_cast::<i64>(Stat::Max)?;
+        Some((min as i128, max as i128))
+    } else {
+        let min = array.statistics().compute_as_cast::<u64>(Stat::Min)?;
+        let max = array.statistics().compute_as_cast::<u64>(Stat::Max)?;
+        Some((min as i128, max as i128))
     }
 }
 
-pub fn roaring_encode(parray: PrimitiveArray) -> RoaringIntArray {
-    match parray.ptype() {
-        PType::U8 => roaring_encode_primitive::<u8>(parray.maybe_null_slice()),
-        PType::U16 => roaring_encode_primitive::<u16>(parray.maybe_null_slice()),
-        PType::U32 => roaring_encode_primitive::<u32>(parray.maybe_null_slice()),
-        PType::U64 => roaring_encode_primitive::<u64>(parray.maybe_null_slice()),
-        _ => panic!("Unsupported ptype {}", parray.ptype()),
+/// Choose the offset that maps `min..=max` into the u32 domain of the bitmap.
+///
+/// Values that are already valid u32s are stored as-is, otherwise they are stored relative to the
+/// minimum. Returns `None` if the range is too wide for a single bitmap.
+fn offset_for_range(min: i128, max: i128) -> Option<i64> {
+    if min >= 0 && max <= u32::MAX as i128 {
+        Some(0)
+    } else if max - min <= u32::MAX as i128 {
+        i64::try_from(min).ok()
+    } else {
+        None
     }
 }
 
-fn roaring_encode_primitive<T: NumCast + NativePType>(values: &[T]) -> RoaringIntArray {
+pub fn roaring_encode(parray: PrimitiveArray) -> VortexResult<RoaringIntArray> {
+    match_each_integer_ptype!(parray.ptype(), |$T| {
+        roaring_encode_primitive::<$T>(parray.maybe_null_slice())
+    })
+}
+
+fn roaring_encode_primitive<T: NumCast + NativePType>(
+    values: &[T],
+) -> VortexResult<RoaringIntArray> {
+    let (min, max) = values
+        .iter()
+        .map(|v| v.to_i128().unwrap())
+        .fold((i128::MAX, i128::MIN), |(min, max), v| {
+            (min.min(v), max.max(v))
+        });
+    let offset = if values.is_empty() {
+        0
+    } else {
+        match offset_for_range(min, max) {
+            Some(offset) => offset,
+            None => vortex_bail!(
+                "RoaringInt cannot encode range {}..={} of {}",
+                min,
+                max,
+                T::PTYPE
+            ),
+        }
+    };
+
     let mut bitmap = Bitmap::new();
-    bitmap.extend(values.iter().map(|i| i.to_u32().unwrap()));
+    bitmap.extend(
+        values
+            .iter()
+            .map(|v| (v.to_i128().unwrap() - offset as i128) as u32),
+    );
     bitmap.run_optimize();
     bitmap.shrink_to_fit();
-    RoaringIntArray::new(bitmap, T::PTYPE)
+    RoaringIntArray::try_new_with_offset(bitmap, T::PTYPE, offset)
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::primitive::PrimitiveArray;
+    use vortex::compress::{CompressConfig, EncodingCompression};
+    use vortex::IntoArray;
+
+    use crate::RoaringIntEncoding;
+
+    #[test]
+    fn test_can_compress() {
+        let config = CompressConfig::default();
+        let sorted = PrimitiveArray::from(vec![-10i32, 1, 5, 100]).into_array();
+        assert!(RoaringIntEncoding.can_compress(&sorted, &config).is_some());
+
+        let unsorted = PrimitiveArray::from(vec![1u32, 5, 3]).into_array();
+        assert!(RoaringIntEncoding
+            .can_compress(&unsorted, &config)
+            .is_none());
+
+        let wide = PrimitiveArray::from(vec![0u64, u64::MAX]).into_array();
+        assert!(RoaringIntEncoding.can_compress(&wide, &config).is_none());
+    }
 }
@@ -1,28 +1,501 @@
+use std::borrow::Cow;
+use std::ops::{BitAnd, BitOr};
+
+use arrow_buffer::{BooleanBuffer, BooleanBufferBuilder};
+use croaring::Bitmap;
+use num_traits::NumCast;
+use vortex::array::bool::BoolArray;
+use vortex::array::constant::ConstantArray;
+use vortex::array::primitive::PrimitiveArray;
+use vortex::compute::compare::{compare, CompareFn};
+use vortex::compute::filter_indices::FilterIndicesFn;
+use vortex::compute::search_sorted::{SearchResult, SearchSortedFn, SearchSortedSide};
+use vortex::compute::slice::SliceFn;
+use vortex::compute::take::TakeFn;
 use vortex::compute::unary::scalar_at::ScalarAtFn;
 use vortex::compute::ArrayCompute;
-use vortex_dtype::PType;
-use vortex_error::VortexResult;
-use vortex_scalar::Scalar;
+use vortex::{Array, ArrayDType, ArrayTrait, IntoArray, IntoArrayVariant, IntoCanonical};
+use vortex_dtype::Nullability::NonNullable;
+use vortex_dtype::{match_each_float_ptype, match_each_integer_ptype, DType, PType};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_expr::{Disjunction, Operator, Predicate, Value};
+use vortex_scalar::{PrimitiveScalar, Scalar};
 
 use crate::RoaringIntArray;
 
 impl ArrayCompute for RoaringIntArray {
+    fn compare(&self) -> Option<&dyn CompareFn> {
+        Some(self)
+    }
+
+    fn filter_indices(&self) -> Option<&dyn FilterIndicesFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
+
+    fn search_sorted(&self) -> Option<&dyn SearchSortedFn> {
+        Some(self)
+    }
+
+    fn slice(&self) -> Option<&dyn SliceFn> {
+        Some(self)
+    }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
+}
+
+impl RoaringIntArray {
+    /// The number of values less than, and less than or equal to, the given value.
+    ///
+    /// Since the values are sorted and unique, these are also the bounds of the range of indices
+    /// holding the value, and both are answered by the bitmap's range cardinality.
+    ///
+    /// Integer values are read in their own type rather than cast to the array's, so values
+    /// outside of its range rank below or above every value instead of failing to cast. Values
+    /// below a float are those below its ceiling, and values up to a float are those up to its
+    /// floor, so a float between two integers ranks both counts the same. NaN ranks above every
+    /// value.
+    fn rank_range(&self, value: &Scalar) -> VortexResult<(usize, usize)> {
+        let value = PrimitiveScalar::try_from(value)?;
+        let (less_bound, less_or_equal_bound) = if value.ptype().is_int() {
+            let value = match_each_integer_ptype!(value.ptype(), |$T| {
+                value
+                    .typed_value::<$T>()
+                    .ok_or_else(|| vortex_err!("Cannot rank a null value"))? as i128
+            });
+            (value, value)
+        } else {
+            let value = f64::try_from(&value.cast(&DType::Primitive(PType::F64, NonNullable))?)?;
+            if value.is_nan() {
+                return Ok((self.len(), self.len()));
+            }
+            // Conversions saturate, so infinities rank below or above every value.
+            (value.ceil() as i128, value.floor() as i128)
+        };
+
+        let bitmap = self.bitmap();
+        let less = match less_bound.saturating_sub(self.offset() as i128) {
+            relative if relative <= 0 => 0,
+            relative if relative > u32::MAX as i128 => self.len(),
+            relative => bitmap.range_cardinality(..relative as u32) as usize,
+        };
+        let less_or_equal = match less_or_equal_bound.saturating_sub(self.offset() as i128) {
+            relative if relative < 0 => 0,
+            relative if relative > u32::MAX as i128 => self.len(),
+            relative => bitmap.range_cardinality(..=relative as u32) as usize,
+        };
+        Ok((less, less_or_equal))
+    }
+
+    /// The mask of the values matching `value op literal`, which is always a single run of indices
+    /// (or the complement of one, for `NotEq`). Null and NaN literals match no values.
+    fn operator_mask(&self, op: Operator, literal: &Scalar) -> VortexResult<BooleanBuffer> {
+        let len = self.len();
+        if literal.is_null() || is_nan(literal)? {
+            return Ok(BooleanBuffer::new_unset(len));
+        }
+
+        let (less, less_or_equal) = self.rank_range(literal)?;
+        let (start, stop, inside) = match op {
+            Operator::Eq => (less, less_or_equal, true),
+            Operator::NotEq => (less, less_or_equal, false),
+            Operator::Gt => (less_or_equal, len, true),
+            Operator::Gte => (less, len, true),
+            Operator::Lt => (0, less, true),
+            Operator::Lte => (0, less_or_equal, true),
+        };
+
+        let mut mask = BooleanBufferBuilder::new(len);
+        mask.append_n(start, !inside);
+        mask.append_n(stop - start, inside);
+        mask.append_n(len - stop, !inside);
+        Ok(mask.finish())
+    }
+
+    fn value_at(&self, stored: u32) -> i128 {
+        stored as i128 + self.offset() as i128
+    }
+}
+
+fn is_nan(value: &Scalar) -> VortexResult<bool> {
+    let value = PrimitiveScalar::try_from(value)?;
+    if !value.ptype().is_float() {
+        return Ok(false);
+    }
+    Ok(match_each_float_ptype!(value.ptype(), |$F| {
+        value.typed_value::<$F>().map_or(false, |v| v.is_nan())
+    }))
 }
 
 impl ScalarAtFn for RoaringIntArray {
     fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
-        // Unwrap since we know the index is valid
-        let bitmap_value = self.bitmap().select(index as u32).unwrap();
-        let scalar: Scalar = match self.metadata().ptype {
-            PType::U8 => (bitmap_value as u8).into(),
-            PType::U16 => (bitmap_value as u16).into(),
-            PType::U32 => bitmap_value.into(),
-            PType::U64 => (bitmap_value as u64).into(),
-            _ => unreachable!("RoaringIntArray constructor should have disallowed this type"),
+        let stored = self
+            .bitmap()
+            .select(index as u32)
+            .ok_or_else(|| vortex_err!(OutOfBounds: index, 0, self.len()))?;
+        let value = self.value_at(stored);
+        Ok(match_each_integer_ptype!(self.ptype(), |$T| {
+            <$T as NumCast>::from(value).unwrap().into()
+        }))
+    }
+}
+
+impl SliceFn for RoaringIntArray {
+    fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
+        let bitmap = if start == stop {
+            Bitmap::new()
+        } else {
+            let first = self.bitmap().select(start as u32);
+            let last = self.bitmap().select((stop - 1) as u32);
+            let (Some(first), Some(last)) = (first, last) else {
+                vortex_bail!(OutOfBounds: stop, 0, self.len());
+            };
+            self.bitmap().and(&Bitmap::from_range(first..=last))
+        };
+
+        Self::try_new_with_offset(bitmap, self.ptype(), self.offset()).map(|a| a.into_array())
+    }
+}
+
+impl TakeFn for RoaringIntArray {
+    fn take(&self, indices: &Array) -> VortexResult<Array> {
+        let indices = indices.clone().into_primitive()?;
+        let bitmap = self.bitmap();
+        match_each_integer_ptype!(indices.ptype(), |$I| {
+            let indices = indices.maybe_null_slice::<$I>();
+            match_each_integer_ptype!(self.ptype(), |$T| {
+                let values = indices
+                    .iter()
+                    .map(|&idx| {
+                        u32::try_from(idx)
+                            .ok()
+                            .and_then(|idx| bitmap.select(idx))
+                            .map(|stored| <$T as NumCast>::from(self.value_at(stored)).unwrap())
+                            .ok_or_else(|| vortex_err!(OutOfBounds: idx as usize, 0, self.len()))
+                    })
+                    .collect::<VortexResult<Vec<$T>>>()?;
+                Ok(PrimitiveArray::from(values).into_array())
+            })
+        })
+    }
+}
+
+impl SearchSortedFn for RoaringIntArray {
+    fn search_sorted(&self, value: &Scalar, side: SearchSortedSide) -> VortexResult<SearchResult> {
+        let (less, less_or_equal) = self.rank_range(value)?;
+        let index = match side {
+            SearchSortedSide::Left => less,
+            SearchSortedSide::Right => less_or_equal,
         };
-        Ok(scalar)
+        Ok(if less_or_equal > less {
+            SearchResult::Found(index)
+        } else {
+            SearchResult::NotFound(index)
+        })
+    }
+}
+
+impl CompareFn for RoaringIntArray {
+    fn compare(&self, other: &Array, predicate: Operator) -> VortexResult<Array> {
+        match ConstantArray::try_from(other) {
+            Ok(constant) => {
+                Ok(BoolArray::from(self.operator_mask(predicate, constant.scalar())?).into_array())
+            }
+            Err(_) => compare(
+                &self.clone().into_canonical()?.into_array(),
+                other,
+                predicate,
+            ),
+        }
+    }
+}
+
+impl FilterIndicesFn for RoaringIntArray {
+    fn filter_indices(&self, disjunction: &Disjunction) -> VortexResult<Array> {
+        let conjunction_indices = disjunction.iter().map(|conj| {
+            conj.iter()
+                .map(|pred| self.predicate_mask(pred))
+                .reduce(|a, b| Ok(a?.bitand(&b?)))
+                .unwrap_or_else(|| Ok(BooleanBuffer::new_set(self.len())))
+        });
+
+        let bitset: VortexResult<BooleanBuffer> = conjunction_indices
+            .reduce(|a, b| Ok(a?.bitor(&b?)))
+            .unwrap_or_else(|| Ok(BooleanBuffer::new_set(self.len())));
+
+        Ok(BoolArray::from(bitset?).into_array())
+    }
+}
+
+impl RoaringIntArray {
+    fn predicate_mask(&self, predicate: &Predicate) -> VortexResult<BooleanBuffer> {
+        if !predicate.lhs.path().is_empty() {
+            vortex_bail!("Invalid path for roaring int array")
+        }
+
+        match &predicate.rhs {
+            Value::Field(_) => {
+                vortex_bail!("Cannot apply field reference to roaring int array")
+            }
+            Value::Literal(scalar) => self.operator_mask(predicate.op, scalar),
+        }
+    }
+}
+
+/// The values present in both arrays.
+pub fn intersection(
+    left: &RoaringIntArray,
+    right: &RoaringIntArray,
+) -> VortexResult<RoaringIntArray> {
+    let offset = common_offset(left, right)?;
+    // Values shifted out of the u32 domain are larger than any value of the other array,
+    // so they can be dropped without changing the intersection.
+    let bitmap = rebased(left, offset)?.and(&*rebased(right, offset)?);
+    RoaringIntArray::try_new_with_offset(bitmap, left.ptype(), offset)
+}
+
+/// The values present in either array.
+pub fn union(left: &RoaringIntArray, right: &RoaringIntArray) -> VortexResult<RoaringIntArray> {
+    let offset = common_offset(left, right)?;
+    for array in [left, right] {
+        if let Some(max) = array.bitmap().maximum() {
+            if array.value_at(max) - offset as i128 > u32::MAX as i128 {
+                vortex_bail!("RoaringInt union spans more than {} values", u32::MAX);
+            }
+        }
+    }
+    let bitmap = rebased(left, offset)?.or(&*rebased(right, offset)?);
+    RoaringIntArray::try_new_with_offset(bitmap, left.ptype(), offset)
+}
+
+fn common_offset(left: &RoaringIntArray, right: &RoaringIntArray) -> VortexResult<i64> {
+    if left.ptype() != right.ptype() {
+        vortex_bail!(MismatchedTypes: left.dtype(), right.dtype());
+    }
+    Ok(left.offset().min(right.offset()))
+}
+
+/// The bitmap of the array, with its values stored relative to `offset` instead of its own.
+fn rebased(array: &RoaringIntArray, offset: i64) -> VortexResult<Cow<Bitmap>> {
+    let shift = array
+        .offset()
+        .checked_sub(offset)
+        .ok_or_else(|| vortex_err!("RoaringInt offset difference overflows"))?;
+    Ok(if shift == 0 {
+        Cow::Borrowed(array.bitmap())
+    } else {
+        Cow::Owned(array.bitmap().add_offset(shift))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::bool::BoolArray;
+    use vortex::array::constant::ConstantArray;
+    use vortex::array::primitive::PrimitiveArray;
+    use vortex::compute::compare::compare;
+    use vortex::compute::filter_indices::filter_indices;
+    use vortex::compute::search_sorted::{
+        search_sorted, SearchResult, SearchSortedFn, SearchSortedSide,
+    };
+    use vortex::compute::slice::slice;
+    use vortex::compute::take::take;
+    use vortex::{Array, IntoArray, IntoArrayVariant};
+    use vortex_dtype::field::FieldPath;
+    use vortex_expr::{lit, Conjunction, Disjunction, FieldPathOperations, Operator};
+
+    use crate::{intersection, union, RoaringIntArray};
+
+    fn roaring(values: Vec<i32>) -> RoaringIntArray {
+        RoaringIntArray::try_from(
+            RoaringIntArray::encode(PrimitiveArray::from(values).into_array()).unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn to_vec(array: Array) -> Vec<i32> {
+        array
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<i32>()
+            .to_vec()
+    }
+
+    fn set_indices(array: Array) -> Vec<usize> {
+        BoolArray::try_from(array)
+            .unwrap()
+            .boolean_buffer()
+            .set_indices()
+            .collect()
+    }
+
+    #[test]
+    fn test_slice() {
+        let array = roaring(vec![-7, -3, 0, 4, 10, 11]).into_array();
+        assert_eq!(to_vec(slice(&array, 1, 4).unwrap()), vec![-3, 0, 4]);
+        assert_eq!(to_vec(slice(&array, 2, 2).unwrap()), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn test_take() {
+        let array = roaring(vec![-7, -3, 0, 4, 10, 11]).into_array();
+        let indices = PrimitiveArray::from(vec![5u32, 0, 3]).into_array();
+        assert_eq!(to_vec(take(&array, &indices).unwrap()), vec![11, -7, 4]);
+
+        // Indices that don't fit the 32 bit rank of the bitmap are out of bounds.
+        let wrapping = PrimitiveArray::from(vec![(1u64 << 32) + 1]).into_array();
+        assert!(take(&array, &wrapping).is_err());
+        let negative = PrimitiveArray::from(vec![-1i64]).into_array();
+        assert!(take(&array, &negative).is_err());
+    }
+
+    #[test]
+    fn test_search_sorted() {
+        let array = roaring(vec![-7, -3, 0, 4, 10, 11]).into_array();
+        assert_eq!(
+            search_sorted(&array, 4, SearchSortedSide::Left).unwrap(),
+            SearchResult::Found(3)
+        );
+        assert_eq!(
+            search_sorted(&array, 4, SearchSortedSide::Right).unwrap(),
+            SearchResult::Found(4)
+        );
+        assert_eq!(
+            search_sorted(&array, 5, SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(4)
+        );
+        assert_eq!(
+            search_sorted(&array, -100, SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(0)
+        );
+        assert_eq!(
+            search_sorted(&array, 100, SearchSortedSide::Right).unwrap(),
+            SearchResult::NotFound(6)
+        );
+    }
+
+    #[test]
+    fn test_compare_out_of_range() {
+        let roaring = RoaringIntArray::try_from(
+            RoaringIntArray::encode(PrimitiveArray::from(vec![1u8, 7, 200]).into_array()).unwrap(),
+        )
+        .unwrap();
+        let array = roaring.clone().into_array();
+        for (value, op, expected) in [
+            (300i64, Operator::Lt, vec![0, 1, 2]),
+            (300, Operator::Gte, vec![]),
+            (-1, Operator::Gt, vec![0, 1, 2]),
+            (-1, Operator::Eq, vec![]),
+            (-1, Operator::NotEq, vec![0, 1, 2]),
+        ] {
+            let constant = ConstantArray::new(value, 3).into_array();
+            assert_eq!(
+                set_indices(compare(&array, &constant, op).unwrap()),
+                expected
+            );
+        }
+        assert_eq!(
+            SearchSortedFn::search_sorted(&roaring, &300i64.into(), SearchSortedSide::Left)
+                .unwrap(),
+            SearchResult::NotFound(3)
+        );
+    }
+
+    #[test]
+    fn test_compare_float() {
+        let array = roaring(vec![-7, -3, 0, 4, 10, 11]).into_array();
+        for (value, op, expected) in [
+            (2.5f64, Operator::Eq, vec![]),
+            (2.5, Operator::NotEq, vec![0, 1, 2, 3, 4, 5]),
+            (2.5, Operator::Lt, vec![0, 1, 2]),
+            (2.5, Operator::Lte, vec![0, 1, 2]),
+            (2.5, Operator::Gt, vec![3, 4, 5]),
+            (2.5, Operator::Gte, vec![3, 4, 5]),
+            (-2.5, Operator::Lt, vec![0, 1]),
+            (-2.5, Operator::Gt, vec![2, 3, 4, 5]),
+            (4.0, Operator::Eq, vec![3]),
+            (f64::INFINITY, Operator::Lt, vec![0, 1, 2, 3, 4, 5]),
+            (f64::NEG_INFINITY, Operator::Gt, vec![0, 1, 2, 3, 4, 5]),
+            (f64::NAN, Operator::Eq, vec![]),
+            (f64::NAN, Operator::Lt, vec![]),
+            (f64::NAN, Operator::Gt, vec![]),
+        ] {
+            let constant = ConstantArray::new(value, 6).into_array();
+            assert_eq!(
+                set_indices(compare(&array, &constant, op).unwrap()),
+                expected,
+                "{value} {op:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_compare() {
+        let array = roaring(vec![-7, -3, 0, 4, 10, 11]).into_array();
+        let constant = ConstantArray::new(0i32, 6).into_array();
+        assert_eq!(
+            set_indices(compare(&array, &constant, Operator::Gt).unwrap()),
+            vec![3, 4, 5]
+        );
+        assert_eq!(
+            set_indices(compare(&array, &constant, Operator::NotEq).unwrap()),
+            vec![0, 1, 3, 4, 5]
+        );
+
+        let other = PrimitiveArray::from(vec![-7i32, 0, 0, 5, 10, 0]).into_array();
+        assert_eq!(
+            set_indices(compare(&array, &other, Operator::Eq).unwrap()),
+            vec![0, 2, 4]
+        );
+    }
+
+    #[test]
+    fn test_filter_indices() {
+        let array = roaring(vec![-7, -3, 0, 4, 10, 11]).into_array();
+        let field = FieldPath::root();
+
+        let range = Disjunction::from_iter([Conjunction::from_iter([
+            field.gte(lit(-3)),
+            field.lt(lit(10)),
+        ])]);
+        assert_eq!(
+            set_indices(filter_indices(&array, &range).unwrap()),
+            vec![1, 2, 3]
+        );
+
+        let either = Disjunction::from_iter([
+            Conjunction::from_iter([field.equal(lit(-7))]),
+            Conjunction::from_iter([field.gt(lit(10))]),
+        ]);
+        assert_eq!(
+            set_indices(filter_indices(&array, &either).unwrap()),
+            vec![0, 5]
+        );
+
+        let empty = Disjunction::from_iter([Conjunction::from_iter([])]);
+        assert_eq!(
+            set_indices(filter_indices(&array, &empty).unwrap()),
+            vec![0, 1, 2, 3, 4, 5]
+        );
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let left = roaring(vec![-7, -3, 0, 4, 10]);
+        let right = roaring(vec![0, 4, 5, 20]);
+
+        assert_eq!(
+            to_vec(intersection(&left, &right).unwrap().into_array()),
+            vec![0, 4]
+        );
+        assert_eq!(
+            to_vec(union(&left, &right).unwrap().into_array()),
+            vec![-7, -3, 0, 4, 5, 10, 20]
+        );
     }
 }
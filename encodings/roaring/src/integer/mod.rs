@@ -1,14 +1,19 @@
+use std::fmt::Formatter;
+use std::sync::OnceLock;
+
 use compress::roaring_encode;
+pub use compute::{intersection, union};
 use croaring::{Bitmap, Portable};
+use num_traits::NumCast;
 use serde::{Deserialize, Serialize};
 use vortex::array::primitive::{Primitive, PrimitiveArray};
 use vortex::stats::ArrayStatisticsCompute;
 use vortex::validity::{ArrayValidity, LogicalValidity};
 use vortex::visitor::{AcceptArrayVisitor, ArrayVisitor};
-use vortex::{impl_encoding, Canonical, IntoCanonical};
+use vortex::{impl_encoding, ArrayDType, Canonical, IntoCanonical};
 use vortex_buffer::Buffer;
 use vortex_dtype::Nullability::NonNullable;
-use vortex_dtype::PType;
+use vortex_dtype::{match_each_integer_ptype, PType};
 use vortex_error::{vortex_bail, vortex_err};
 
 mod compress;
@@ -22,6 +27,22 @@ pub struct RoaringIntMetadata {
     // NB: this is stored because we want to avoid the overhead of deserializing the bitmap
     // on every len() call. It's CRITICAL that this is kept up-to date.
     length: usize,
+    /// The value that was subtracted from every element before inserting it into the bitmap.
+    #[serde(default)]
+    offset: i64,
+    #[serde(skip)]
+    bitmap: BitmapCache,
+}
+
+/// The deserialized bitmap, shared between all clones of the metadata. The metadata of an array
+/// view is deserialized once per view, so the bitmap is shared between all clones of the view too.
+#[derive(Clone, Default)]
+struct BitmapCache(Arc<OnceLock<Bitmap>>);
+
+impl Debug for BitmapCache {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("BitmapCache")
+    }
 }
 
 impl RoaringIntArray {
@@ -30,40 +51,72 @@ impl RoaringIntArray {
     }
 
     pub fn try_new(bitmap: Bitmap, ptype: PType) -> VortexResult<Self> {
-        if !ptype.is_unsigned_int() {
-            vortex_bail!("RoaringInt expected unsigned int");
+        Self::try_new_with_offset(bitmap, ptype, 0)
+    }
+
+    /// Create an array whose values are the members of the bitmap plus `offset`.
+    pub fn try_new_with_offset(bitmap: Bitmap, ptype: PType, offset: i64) -> VortexResult<Self> {
+        if !ptype.is_int() {
+            vortex_bail!("RoaringInt expected integer ptype, got {}", ptype);
+        }
+        if !bitmap.is_empty() {
+            let (min, max) = (bitmap.minimum().unwrap(), bitmap.maximum().unwrap());
+            match_each_integer_ptype!(ptype, |$T| {
+                if <$T as NumCast>::from(offset as i128 + min as i128).is_none()
+                    || <$T as NumCast>::from(offset as i128 + max as i128).is_none()
+                {
+                    vortex_bail!("RoaringInt values with offset {} do not fit in {}", offset, ptype);
+                }
+            })
         }
+
+        let buffer = Buffer::from(bitmap.serialize::<Portable>());
+        let metadata = RoaringIntMetadata {
+            ptype,
+            length: bitmap.cardinality() as usize,
+            offset,
+            bitmap: BitmapCache::default(),
+        };
+        metadata
+            .bitmap
+            .0
+            .set(bitmap)
+            .unwrap_or_else(|_| unreachable!("bitmap cache is freshly created"));
+
         Ok(Self {
             typed: TypedArray::try_from_parts(
-                DType::Bool(NonNullable),
-                RoaringIntMetadata {
-                    ptype,
-                    length: bitmap.statistics().cardinality as usize,
-                },
-                Some(Buffer::from(bitmap.serialize::<Portable>())),
+                DType::Primitive(ptype, NonNullable),
+                metadata,
+                Some(buffer),
                 vec![].into(),
                 StatsSet::new(),
             )?,
         })
     }
 
-    pub fn bitmap(&self) -> Bitmap {
-        //TODO(@jdcasale): figure out a way to avoid this deserialization per-call
-        Bitmap::deserialize::<Portable>(
-            self.array()
-                .buffer()
-                .expect("RoaringBoolArray buffer is missing")
-                .as_ref(),
-        )
+    /// The bitmap of the values minus [`Self::offset`], deserialized at most once per array or array view.
+    pub fn bitmap(&self) -> &Bitmap {
+        self.metadata().bitmap.0.get_or_init(|| {
+            Bitmap::deserialize::<Portable>(
+                self.array()
+                    .buffer()
+                    .expect("RoaringIntArray buffer is missing")
+                    .as_ref(),
+            )
+        })
     }
 
     pub fn ptype(&self) -> PType {
         self.metadata().ptype
     }
 
+    pub fn offset(&self) -> i64 {
+        self.metadata().offset
+    }
+
     pub fn encode(array: Array) -> VortexResult<Array> {
         if array.encoding().id() == Primitive::ID {
-            Ok(roaring_encode(PrimitiveArray::try_from(array)?).into_array())
+            Ok(roaring_encode(PrimitiveArray::try_from(array)?)?.into_array())
         } else {
             Err(vortex_err!("RoaringInt can only encode primitive arrays"))
         }
@@ -76,19 +129,33 @@ impl ArrayValidity for RoaringIntArray {
     }
 
     fn logical_validity(&self) -> LogicalValidity {
-        LogicalValidity::AllValid(self.bitmap().iter().count())
+        LogicalValidity::AllValid(self.len())
     }
 }
 
 impl IntoCanonical for RoaringIntArray {
     fn into_canonical(self) -> VortexResult<Canonical> {
-        todo!()
+        let offset = self.offset() as i128;
+        let values = self.bitmap().iter().map(|v| v as i128 + offset);
+        let primitive = match_each_integer_ptype!(self.ptype(), |$T| {
+            PrimitiveArray::from(
+                values
+                    .map(|v| <$T as NumCast>::from(v).unwrap())
+                    .collect::<Vec<$T>>(),
+            )
+        });
+        debug_assert_eq!(primitive.dtype(), self.dtype());
+        Ok(Canonical::Primitive(primitive))
     }
 }
 
 impl AcceptArrayVisitor for RoaringIntArray {
-    fn accept(&self, _visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
-        todo!()
+    fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        visitor.visit_buffer(
+            self.array()
+                .buffer()
+                .expect("RoaringIntArray buffer is missing"),
+        )
     }
 }
 
@@ -104,7 +171,7 @@ impl ArrayTrait for RoaringIntArray {
 mod test {
     use vortex::array::primitive::PrimitiveArray;
     use vortex::compute::unary::scalar_at::scalar_at;
-    use vortex::IntoArray;
+    use vortex::{IntoArray, IntoArrayVariant};
     use vortex_error::VortexResult;
 
     use crate::RoaringIntArray;
@@ -119,4 +186,19 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    pub fn test_signed_round_trip() -> VortexResult<()> {
+        let values = vec![-4_000_000_000i64, -3_000_000_000, -3, 0, 7];
+        let array = RoaringIntArray::encode(PrimitiveArray::from(values.clone()).into_array())?;
+
+        assert_eq!(
+            RoaringIntArray::try_from(&array)?.offset(),
+            -4_000_000_000i64
+        );
+        assert_eq!(scalar_at(&array, 1).unwrap(), (-3_000_000_000i64).into());
+        assert_eq!(array.into_primitive()?.maybe_null_slice::<i64>(), values);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,100 @@
+use arrow_array::types::RunEndIndexType;
+use arrow_array::{Array as ArrowArray, RunArray};
+use arrow_buffer::{ArrowNativeType, BooleanBufferBuilder};
+use vortex::array::primitive::PrimitiveArray;
+use vortex::arrow::TryFromArrowArray;
+use vortex::validity::Validity;
+use vortex::{ArrayData, IntoArray};
+use vortex_dtype::NativePType;
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::REEArray;
+
+/// Import an Arrow run-end encoded array of primitive values without decoding it.
+impl<R: RunEndIndexType> TryFromArrowArray<&RunArray<R>> for REEArray
+where
+    R::Native: NativePType,
+{
+    fn try_from_arrow(value: &RunArray<R>, nullable: bool) -> VortexResult<Self> {
+        if !value.values().data_type().is_numeric() {
+            vortex_bail!(
+                "REE can only import primitive values, found {}",
+                value.values().data_type()
+            );
+        }
+
+        // Keep only the runs that overlap the (possibly sliced) array.
+        let run_ends = value.run_ends();
+        let first = run_ends.get_start_physical_index();
+        let runs = if value.is_empty() {
+            0
+        } else {
+            run_ends.get_end_physical_index() + 1 - first
+        };
+        let ends = &run_ends.values()[first..first + runs];
+        let values = value.values().slice(first, runs);
+
+        // Arrow stores nulls in the values, whereas REE validity is per row.
+        let validity = if !nullable {
+            Validity::NonNullable
+        } else if values.null_count() == 0 {
+            Validity::AllValid
+        } else {
+            let mut validity = BooleanBufferBuilder::new(value.len());
+            let mut position = run_ends.offset();
+            for (run, end) in ends.iter().enumerate() {
+                let end = end.as_usize().min(run_ends.offset() + value.len());
+                validity.append_n(end - position, values.is_valid(run));
+                position = end;
+            }
+            Validity::from(validity.finish())
+        };
+
+        Self::with_offset_and_size(
+            PrimitiveArray::from(ends.to_vec()).into_array(),
+            ArrayData::try_from_arrow(values, nullable)?.into_array(),
+            validity,
+            value.len(),
+            run_ends.offset(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::types::Int32Type;
+    use arrow_array::{Array, Int32Array, Int64Array, RunArray};
+    use vortex::arrow::TryFromArrowArray;
+    use vortex::{IntoArray, IntoArrayVariant};
+
+    use crate::REEArray;
+
+    #[test]
+    fn test_sliced_with_nulls() {
+        let arrow = RunArray::<Int32Type>::try_new(
+            &Int32Array::from(vec![2, 5, 6, 9]),
+            &Int64Array::from(vec![Some(1), None, Some(3), Some(4)]),
+        )
+        .unwrap();
+        let sliced = arrow.slice(1, 6);
+        let array = REEArray::try_from_arrow(
+            sliced
+                .as_any()
+                .downcast_ref::<RunArray<Int32Type>>()
+                .unwrap(),
+            true,
+        )
+        .unwrap()
+        .into_array()
+        .into_primitive()
+        .unwrap();
+
+        assert_eq!(array.maybe_null_slice::<i64>()[0], 1);
+        assert_eq!(array.maybe_null_slice::<i64>()[4], 3);
+        assert_eq!(array.maybe_null_slice::<i64>()[5], 4);
+        let valid = (0..6)
+            .map(|i| array.validity().is_valid(i))
+            .collect::<Vec<_>>();
+        assert_eq!(valid, vec![true, false, false, false, true, true]);
+    }
+}
@@ -14,6 +14,10 @@ use vortex_error::VortexResult;
 use crate::{REEArray, REEEncoding};
 
 impl EncodingCompression for REEEncoding {
+    fn decode_cost(&self) -> f32 {
+        0.5
+    }
+
     fn can_compress(
         &self,
         array: &Array,
@@ -76,8 +80,8 @@ pub fn ree_encode(array: &PrimitiveArray) -> (PrimitiveArray, PrimitiveArray) {
         let mut compressed_values = PrimitiveArray::from_vec(values, validity);
         compressed_values.statistics().set(Stat::IsConstant, false.into());
         compressed_values.statistics().set(Stat::RunCount, compressed_values.len().into());
-        array.statistics().get(Stat::Min).map(|s| compressed_values.statistics().set(Stat::Min, s));
-        array.statistics().get(Stat::Max).map(|s| compressed_values.statistics().set(Stat::Max, s));
+        array.statistics().get_bound(Stat::Min).map(|b| compressed_values.statistics().set_bound(Stat::Min, b));
+        array.statistics().get_bound(Stat::Max).map(|b| compressed_values.statistics().set_bound(Stat::Max, b));
         array.statistics().get(Stat::IsSorted).map(|s| compressed_values.statistics().set(Stat::IsSorted, s));
         array.statistics().get(Stat::IsStrictSorted).map(|s| compressed_values.statistics().set(Stat::IsStrictSorted, s));
 
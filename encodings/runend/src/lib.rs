@@ -1,5 +1,6 @@
 pub use ree::*;
 
+mod arrow;
 mod compress;
 mod compute;
 mod ree;
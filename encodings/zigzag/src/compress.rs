@@ -10,6 +10,10 @@ use zigzag::ZigZag as ExternalZigZag;
 use crate::{ZigZagArray, ZigZagEncoding};
 
 impl EncodingCompression for ZigZagEncoding {
+    fn decode_cost(&self) -> f32 {
+        0.2
+    }
+
     fn can_compress(
         &self,
         array: &Array,
@@ -1,5 +1,13 @@
+use std::os::raw::c_long;
+
+use arrow::buffer::BooleanBuffer;
+use enum_iterator::all;
+use itertools::Itertools;
 use paste::paste;
+use pyo3::exceptions::{PyIndexError, PyNotImplementedError, PyValueError};
 use pyo3::prelude::*;
+use pyo3::pyclass::CompareOp;
+use pyo3::types::{PyCapsule, PyDict, PySlice, PyTuple};
 use vortex::array::bool::{Bool, BoolArray, BoolEncoding};
 use vortex::array::chunked::{Chunked, ChunkedArray, ChunkedEncoding};
 use vortex::array::constant::{Constant, ConstantArray, ConstantEncoding};
@@ -8,13 +16,22 @@ use vortex::array::sparse::{Sparse, SparseArray, SparseEncoding};
 use vortex::array::struct_::{Struct, StructArray, StructEncoding};
 use vortex::array::varbin::{VarBin, VarBinArray, VarBinEncoding};
 use vortex::array::varbinview::{VarBinView, VarBinViewArray, VarBinViewEncoding};
+use vortex::compute::compare::compare;
+use vortex::compute::search_sorted::{search_sorted, SearchSortedSide};
+use vortex::compute::slice::slice;
 use vortex::compute::take::take;
+use vortex::compute::unary::cast::try_cast;
+use vortex::compute::unary::fill_forward::fill_forward;
+use vortex::compute::unary::scalar_at::scalar_at;
 use vortex::encoding::EncodingRef;
-use vortex::ToArray;
-use vortex::{Array, ArrayDType, ArrayData, IntoArray};
+use vortex::stats::{ArrayStatistics, Stat};
+use vortex::{Array, ArrayDType, ArrayData, IntoArray, IntoArrayVariant};
 use vortex::{ArrayDef, IntoArrayData};
+use vortex::{ArrayTrait, ToArray};
 use vortex_alp::{ALPArray, ALPEncoding, ALP};
 use vortex_dict::{Dict, DictArray, DictEncoding};
+use vortex_error::VortexResult;
+use vortex_expr::Operator;
 use vortex_fastlanes::{
     BitPacked, BitPackedArray, BitPackedEncoding, Delta, DeltaArray, DeltaEncoding, FoR, FoRArray,
     FoREncoding,
@@ -28,7 +45,9 @@ use vortex_zigzag::{ZigZag, ZigZagArray, ZigZagEncoding};
 
 use crate::dtype::PyDType;
 use crate::error::PyVortexError;
-use crate::vortex_arrow;
+use crate::io::cast_literal;
+use crate::scalar::{scalar_from_py, scalar_into_py};
+use crate::{numpy, vortex_arrow};
 
 #[pyclass(name = "Array", module = "vortex", sequence, subclass)]
 pub struct PyArray {
@@ -154,14 +173,14 @@ impl PyArray {
                 ALPArray::try_from(inner.into_array()).map_err(PyVortexError::map_err)?,
             )?
             .extract(py),
-            RoaringBool::ID => PyBitPackedArray::wrap(
+            RoaringBool::ID => PyRoaringBoolArray::wrap(
                 py,
-                BitPackedArray::try_from(inner.into_array()).map_err(PyVortexError::map_err)?,
+                RoaringBoolArray::try_from(inner.into_array()).map_err(PyVortexError::map_err)?,
             )?
             .extract(py),
-            RoaringInt::ID => PyBitPackedArray::wrap(
+            RoaringInt::ID => PyRoaringIntArray::wrap(
                 py,
-                BitPackedArray::try_from(inner.into_array()).map_err(PyVortexError::map_err)?,
+                RoaringIntArray::try_from(inner.into_array()).map_err(PyVortexError::map_err)?,
             )?
             .extract(py),
             ZigZag::ID => PyZigZagArray::wrap(
@@ -212,6 +231,50 @@ impl PyArray {
         vortex_arrow::export_array(self_.py(), &self_.inner)
     }
 
+    /// Export the array through the Arrow PyCapsule interface.
+    ///
+    /// The array is decompressed into its canonical Arrow form. Requested schemas are not supported.
+    #[pyo3(signature = (requested_schema = None))]
+    fn __arrow_c_array__<'py>(
+        self_: PyRef<'py, Self>,
+        requested_schema: Option<Bound<'py, PyAny>>,
+    ) -> PyResult<Bound<'py, PyTuple>> {
+        if requested_schema.is_some() {
+            return Err(PyNotImplementedError::new_err(
+                "Requested schemas are not supported",
+            ));
+        }
+        vortex_arrow::export_array_capsules(self_.py(), &self_.inner)
+    }
+
+    /// Export a struct array through the Arrow PyCapsule interface as a stream of record batches.
+    #[pyo3(signature = (requested_schema = None))]
+    fn __arrow_c_stream__<'py>(
+        self_: PyRef<'py, Self>,
+        requested_schema: Option<Bound<'py, PyAny>>,
+    ) -> PyResult<Bound<'py, PyCapsule>> {
+        if requested_schema.is_some() {
+            return Err(PyNotImplementedError::new_err(
+                "Requested schemas are not supported",
+            ));
+        }
+        vortex_arrow::export_stream_capsule(self_.py(), &self_.inner)
+    }
+
+    /// Convert to a NumPy array, or a masked array if there are nulls.
+    ///
+    /// Non-null primitive arrays are returned without copying once canonicalized. Pass
+    /// `zero_copy_only=True` to raise instead of copying.
+    #[pyo3(signature = (*, zero_copy_only = false))]
+    fn to_numpy(self_: PyRef<'_, Self>, zero_copy_only: bool) -> PyResult<Bound<PyAny>> {
+        numpy::export_numpy(self_.py(), &self_.inner, zero_copy_only)
+    }
+
+    /// Convert to a pandas `DataFrame` for struct arrays, or a `Series` otherwise.
+    fn to_pandas(self_: PyRef<'_, Self>) -> PyResult<Bound<PyAny>> {
+        numpy::export_pandas(self_.py(), &self_.inner)
+    }
+
     fn __len__(&self) -> usize {
         self.inner.len()
     }
@@ -240,34 +303,196 @@ impl PyArray {
             .map_err(PyVortexError::map_err)
             .and_then(|arr| Self::wrap(indices.py(), arr.into_array_data()))
     }
+
+    /// Index with an integer to get a Python scalar, or with a slice to get an array.
+    fn __getitem__(&self, key: &Bound<PyAny>) -> PyResult<PyObject> {
+        let py = key.py();
+        if let Ok(slice_key) = key.downcast::<PySlice>() {
+            let indices = slice_key.indices(self.inner.len() as c_long)?;
+            let sliced = if indices.step == 1 {
+                let start = indices.start as usize;
+                slice(&self.inner, start, start + indices.slicelength as usize)
+            } else {
+                let positions = (0..indices.slicelength)
+                    .map(|i| (indices.start + i * indices.step) as u64)
+                    .collect_vec();
+                take(&self.inner, &positions.into_array())
+            }
+            .map_err(PyVortexError::map_err)?;
+            return Self::wrap(py, sliced.into_array_data()).map(|arr| arr.into_py(py));
+        }
+
+        let index: isize = key.extract()?;
+        let len = self.inner.len() as isize;
+        let position = if index < 0 { index + len } else { index };
+        if !(0..len).contains(&position) {
+            return Err(PyIndexError::new_err(format!(
+                "index {index} out of bounds for array of length {len}"
+            )));
+        }
+        scalar_at(&self.inner, position as usize)
+            .map(|scalar| scalar_into_py(py, &scalar))
+            .map_err(PyVortexError::map_err)
+    }
+
+    /// Compare elementwise against another array or a Python scalar, producing a boolean array.
+    fn __richcmp__(&self, other: &Bound<PyAny>, op: CompareOp) -> PyResult<Py<Self>> {
+        let operator = match op {
+            CompareOp::Eq => Operator::Eq,
+            CompareOp::Ne => Operator::NotEq,
+            CompareOp::Lt => Operator::Lt,
+            CompareOp::Le => Operator::Lte,
+            CompareOp::Gt => Operator::Gt,
+            CompareOp::Ge => Operator::Gte,
+        };
+        let py = other.py();
+        let other = match other.downcast::<PyArray>() {
+            Ok(other) => other.borrow().inner.clone(),
+            Err(_) => {
+                let scalar = cast_literal(&scalar_from_py(other)?, self.inner.dtype())
+                    .map_err(PyVortexError::map_err)?;
+                ConstantArray::new(scalar, self.inner.len()).into_array()
+            }
+        };
+        compare(&self.inner, &other, operator)
+            .map_err(PyVortexError::map_err)
+            .and_then(|arr| Self::wrap(py, arr.into_array_data()))
+    }
+
+    /// Keep the elements where `mask` is true.
+    ///
+    /// The mask is either a boolean array, or a callable that is given this array and returns
+    /// one, e.g. `arr.filter(lambda a: a > 3)`. Null mask entries are treated as false.
+    fn filter(self_: &Bound<'_, Self>, mask: &Bound<PyAny>) -> PyResult<Py<Self>> {
+        let mask = if mask.is_callable() {
+            mask.call1((self_,))?
+        } else {
+            mask.clone()
+        };
+        let mask = mask.downcast::<PyArray>()?.borrow();
+        filter(&self_.borrow().inner, &mask.inner)
+            .map_err(PyVortexError::map_err)
+            .and_then(|arr| Self::wrap(mask.py(), arr.into_array_data()))
+    }
+
+    /// Find the index at which `value` would be inserted to keep this sorted array sorted.
+    #[pyo3(signature = (value, side = "left"))]
+    fn search_sorted(&self, value: &Bound<PyAny>, side: &str) -> PyResult<usize> {
+        let side = match side {
+            "left" => SearchSortedSide::Left,
+            "right" => SearchSortedSide::Right,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "side must be 'left' or 'right', got {side}"
+                )))
+            }
+        };
+        search_sorted(&self.inner, scalar_from_py(value)?, side)
+            .map(|result| result.to_index())
+            .map_err(PyVortexError::map_err)
+    }
+
+    /// Replace each null with the last valid value that precedes it.
+    fn fill_forward(self_: PyRef<'_, Self>) -> PyResult<Py<Self>> {
+        fill_forward(&self_.inner)
+            .map_err(PyVortexError::map_err)
+            .and_then(|arr| Self::wrap(self_.py(), arr.into_array_data()))
+    }
+
+    fn cast(self_: PyRef<'_, Self>, dtype: PyRef<'_, PyDType>) -> PyResult<Py<Self>> {
+        try_cast(&self_.inner, dtype.unwrap())
+            .map_err(PyVortexError::map_err)
+            .and_then(|arr| Self::wrap(self_.py(), arr.into_array_data()))
+    }
+
+    /// Render the tree of encodings that make up this array.
+    fn tree_display(&self) -> String {
+        format!("{}", self.inner.tree_display())
+    }
+
+    /// The statistics of this array, keyed by statistic name.
+    ///
+    /// Statistics that are computed together in a single pass over the values are computed if
+    /// missing. Others, like the distinct count, are only included if they are already known.
+    #[getter]
+    fn statistics(self_: PyRef<'_, Self>) -> PyResult<Bound<'_, PyDict>> {
+        let py = self_.py();
+        let statistics = self_.inner.statistics();
+        let stats = PyDict::new_bound(py);
+        for stat in SINGLE_PASS_STATS {
+            if let Some(value) = statistics.compute(stat) {
+                stats.set_item(stat.to_string(), scalar_into_py(py, &value))?;
+            }
+        }
+        // Sketches are left out, they are only meaningful to Vortex itself.
+        for stat in all::<Stat>().filter(|stat| {
+            !SINGLE_PASS_STATS.contains(stat)
+                && !matches!(stat, Stat::DistinctCountSketch | Stat::BloomFilter)
+        }) {
+            if let Some(value) = statistics.get(stat) {
+                stats.set_item(stat.to_string(), scalar_into_py(py, &value))?;
+            }
+        }
+        Ok(stats)
+    }
+}
+
+/// The statistics computed together in one pass over the values of an array, which also caches
+/// the others it computes alongside, like the bit width frequencies.
+const SINGLE_PASS_STATS: [Stat; 8] = [
+    Stat::Min,
+    Stat::Max,
+    Stat::NullCount,
+    Stat::TrueCount,
+    Stat::IsConstant,
+    Stat::IsSorted,
+    Stat::IsStrictSorted,
+    Stat::RunCount,
+];
+
+/// Keep the elements of `array` where the boolean `mask` is valid and true.
+pub(crate) fn filter(array: &Array, mask: &Array) -> VortexResult<Array> {
+    let mask = mask.clone().into_bool()?;
+    let valid = mask
+        .validity()
+        .to_logical(mask.len())
+        .to_present_null_buffer()?
+        .into_inner();
+    take_mask(array, &(&mask.boolean_buffer() & &valid))
+}
+
+/// Take the positions of `array` that are set in `mask`.
+pub(crate) fn take_mask(array: &Array, mask: &BooleanBuffer) -> VortexResult<Array> {
+    let indices = mask.set_indices().map(|i| i as u64).collect_vec();
+    take(array, &indices.into_array())
+}
+
+#[pymethods]
+impl PyRoaringBoolArray {
+    #[staticmethod]
+    fn encode(array: PyRef<'_, PyArray>) -> PyResult<Py<PyArray>> {
+        RoaringBoolArray::encode(array.unwrap().clone())
+            .map_err(PyVortexError::map_err)
+            .and_then(|zarray| PyArray::wrap(array.py(), zarray.into_array_data()))
+    }
+}
+
+#[pymethods]
+impl PyRoaringIntArray {
+    #[staticmethod]
+    fn encode(array: PyRef<'_, PyArray>) -> PyResult<Py<PyArray>> {
+        RoaringIntArray::encode(array.unwrap().clone())
+            .map_err(PyVortexError::map_err)
+            .and_then(|zarray| PyArray::wrap(array.py(), zarray.into_array_data()))
+    }
+}
+
+#[pymethods]
+impl PyZigZagArray {
+    #[staticmethod]
+    fn encode(array: PyRef<'_, PyArray>) -> PyResult<Py<PyArray>> {
+        ZigZagArray::encode(array.unwrap())
+            .map_err(PyVortexError::map_err)
+            .and_then(|zarray| PyArray::wrap(array.py(), zarray.into_array_data()))
+    }
 }
-//
-// #[pymethods]
-// impl PyRoaringBoolArray {
-//     #[staticmethod]
-//     fn encode(array: PyRef<'_, PyArray>) -> PyResult<Py<PyArray>> {
-//         RoaringBoolArray::encode(array.unwrap())
-//             .map_err(PyVortexError::map_err)
-//             .and_then(|zarray| PyArray::wrap(array.py(), zarray.into_array()))
-//     }
-// }
-//
-// #[pymethods]
-// impl PyRoaringIntArray {
-//     #[staticmethod]
-//     fn encode(array: PyRef<'_, PyArray>) -> PyResult<Py<PyArray>> {
-//         RoaringIntArray::encode(array.unwrap())
-//             .map_err(PyVortexError::map_err)
-//             .and_then(|zarray| PyArray::wrap(array.py(), zarray.into_array()))
-//     }
-// }
-//
-// #[pymethods]
-// impl PyZigZagArray {
-//     #[staticmethod]
-//     fn encode(array: PyRef<'_, PyArray>) -> PyResult<Py<PyArray>> {
-//         ZigZagArray::encode(array.unwrap())
-//             .map_err(PyVortexError::map_err)
-//             .and_then(|zarray| PyArray::wrap(array.py(), zarray))
-//     }
-// }
@@ -1,45 +1,240 @@
-use std::sync::Arc;
+use std::collections::HashSet;
 
-use pyo3::types::PyType;
-use pyo3::{pyclass, pyfunction, pymethods, Py, PyResult, Python};
-use vortex::compress::{CompressConfig, CompressCtx};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyType};
+use vortex::array::chunked::ChunkedArray;
+use vortex::compress::{CompressConfig, Compressor};
+use vortex::encoding::EncodingRef;
+use vortex::visitor::ArrayVisitor;
+use vortex::{Array, ArrayDType, Context, IntoArrayData, IntoArrayVariant};
+use vortex_dtype::DType;
+use vortex_error::{vortex_err, VortexResult};
 
 use crate::array::PyArray;
 use crate::error::PyVortexError;
+use crate::CTX;
 
-#[derive(Clone)]
+/// Options for [`compress`], mirroring [`CompressConfig`] plus the set of encodings that may be
+/// chosen.
+#[derive(Clone, Default)]
 #[pyclass(name = "CompressConfig", module = "vortex")]
 pub struct PyCompressConfig {
     inner: CompressConfig,
+    include_encodings: Option<HashSet<String>>,
+    exclude_encodings: HashSet<String>,
 }
 
 #[pymethods]
 impl PyCompressConfig {
+    #[new]
+    #[pyo3(signature = (
+        *,
+        sample_size = None,
+        sample_count = None,
+        max_depth = None,
+        ree_average_run_threshold = None,
+        include_encodings = None,
+        exclude_encodings = None,
+    ))]
+    fn new(
+        sample_size: Option<u16>,
+        sample_count: Option<u16>,
+        max_depth: Option<u8>,
+        ree_average_run_threshold: Option<f32>,
+        include_encodings: Option<HashSet<String>>,
+        exclude_encodings: Option<HashSet<String>>,
+    ) -> PyResult<Self> {
+        let mut inner = CompressConfig::default();
+        if let Some(sample_size) = sample_size {
+            inner = inner.with_sample_size(sample_size);
+        }
+        if let Some(sample_count) = sample_count {
+            inner = inner.with_sample_count(sample_count);
+        }
+        if let Some(max_depth) = max_depth {
+            inner = inner.with_max_depth(max_depth);
+        }
+        if let Some(threshold) = ree_average_run_threshold {
+            inner = inner.with_ree_average_run_threshold(threshold);
+        }
+
+        let exclude_encodings = exclude_encodings.unwrap_or_default();
+        let available = Self::available_encodings();
+        for encoding in include_encodings.iter().flatten().chain(&exclude_encodings) {
+            if !available.contains(encoding) {
+                return Err(PyValueError::new_err(format!(
+                    "Unknown encoding {encoding}, expected one of {available:?}"
+                )));
+            }
+        }
+
+        Ok(Self {
+            inner,
+            include_encodings,
+            exclude_encodings,
+        })
+    }
+
     #[classmethod]
-    pub fn default(cls: &PyType) -> PyResult<Py<PyCompressConfig>> {
+    pub fn default(cls: &Bound<PyType>) -> PyResult<Py<PyCompressConfig>> {
         Py::new(cls.py(), <Self as Default>::default())
     }
+
+    /// The IDs of the encodings the compressor can choose between.
+    #[staticmethod]
+    pub fn available_encodings() -> Vec<String> {
+        let mut encodings = compression_encodings()
+            .map(|encoding| encoding.id().to_string())
+            .collect::<Vec<_>>();
+        encodings.sort();
+        encodings
+    }
+
+    #[getter]
+    fn sample_size(&self) -> u16 {
+        self.inner.sample_size()
+    }
+
+    #[getter]
+    fn sample_count(&self) -> u16 {
+        self.inner.sample_count()
+    }
+
+    #[getter]
+    fn max_depth(&self) -> u8 {
+        self.inner.max_depth()
+    }
+
+    #[getter]
+    fn ree_average_run_threshold(&self) -> f32 {
+        self.inner.ree_average_run_threshold
+    }
 }
 
-impl Default for PyCompressConfig {
-    fn default() -> Self {
-        Self {
-            inner: CompressConfig::new().with_enabled(ENCODINGS.iter().cloned()),
-        }
+impl PyCompressConfig {
+    /// The canonical encodings plus whichever compression encodings this config allows.
+    fn context(&self) -> Context {
+        Context::default().with_encodings(compression_encodings().filter(|encoding| {
+            let id = encoding.id().to_string();
+            self.include_encodings
+                .as_ref()
+                .map_or(true, |include| include.contains(&id))
+                && !self.exclude_encodings.contains(&id)
+        }))
     }
 }
 
+/// The encodings registered on top of the canonical ones.
+fn compression_encodings() -> impl Iterator<Item = EncodingRef> {
+    let canonical = Context::default();
+    CTX.encodings()
+        .filter(move |encoding| canonical.lookup_encoding(encoding.id().as_ref()).is_none())
+}
+
+/// Compress an array by sampling each of the allowed encodings.
 #[pyfunction]
-#[pyo3(signature = (arr, opts = None))]
+#[pyo3(signature = (array, config = None))]
 pub fn compress(
     py: Python<'_>,
-    arr: &PyArray,
-    opts: Option<PyCompressConfig>,
+    array: PyRef<'_, PyArray>,
+    config: Option<PyCompressConfig>,
 ) -> PyResult<Py<PyArray>> {
-    let compress_opts = opts.unwrap_or_default().inner;
-    let ctx = CompressCtx::new(Arc::new(compress_opts));
-    let compressed = py
-        .allow_threads(|| ctx.compress(arr.unwrap(), None))
-        .map_err(PyVortexError::map_err)?;
-    PyArray::wrap(py, compressed)
+    let compressed = compress_array(py, array.unwrap(), config.unwrap_or_default())?;
+    PyArray::wrap(py, compressed.into_array_data())
+}
+
+/// Compress an array as [`compress`] does, additionally returning one report entry per column.
+///
+/// Each entry is a dict with the column name, the encodings chosen for it, and its size before and
+/// after compression. Non-struct arrays are reported as a single column with an empty name.
+#[pyfunction]
+#[pyo3(signature = (array, config = None))]
+pub fn compress_with_report(
+    py: Python<'_>,
+    array: PyRef<'_, PyArray>,
+    config: Option<PyCompressConfig>,
+) -> PyResult<(Py<PyArray>, Vec<Py<PyDict>>)> {
+    let compressed = compress_array(py, array.unwrap(), config.unwrap_or_default())?;
+
+    let original_columns = columns(array.unwrap()).map_err(PyVortexError::map_err)?;
+    let compressed_columns = columns(&compressed).map_err(PyVortexError::map_err)?;
+    let report = original_columns
+        .into_iter()
+        .zip(compressed_columns)
+        .map(|((name, original), (_, compressed))| {
+            let nbytes = original.iter().map(|c| c.nbytes()).sum::<usize>();
+            let compressed_nbytes = compressed.iter().map(|c| c.nbytes()).sum::<usize>();
+
+            let mut encodings = EncodingCollector::default();
+            for chunk in &compressed {
+                encodings
+                    .visit_child(&name, chunk)
+                    .map_err(PyVortexError::map_err)?;
+            }
+
+            let entry = PyDict::new_bound(py);
+            entry.set_item("column", name)?;
+            entry.set_item("encodings", encodings.0)?;
+            entry.set_item("nbytes", nbytes)?;
+            entry.set_item("compressed_nbytes", compressed_nbytes)?;
+            entry.set_item("ratio", compressed_nbytes as f64 / nbytes as f64)?;
+            Ok(entry.unbind())
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+
+    Ok((PyArray::wrap(py, compressed.into_array_data())?, report))
+}
+
+fn compress_array(py: Python<'_>, array: &Array, config: PyCompressConfig) -> PyResult<Array> {
+    let ctx = config.context();
+    py.allow_threads(|| Compressor::new_with_options(&ctx, config.inner).compress(array, None))
+        .map_err(PyVortexError::map_err)
+}
+
+/// Split an array into its top-level columns, each as the list of its chunks.
+fn columns(array: &Array) -> VortexResult<Vec<(String, Vec<Array>)>> {
+    let chunks = match ChunkedArray::try_from(array) {
+        Ok(chunked) => chunked.chunks().collect(),
+        Err(_) => vec![array.clone()],
+    };
+
+    let DType::Struct(struct_dtype, _) = array.dtype() else {
+        return Ok(vec![(String::new(), chunks)]);
+    };
+
+    let chunks = chunks
+        .into_iter()
+        .map(|chunk| chunk.into_struct())
+        .collect::<VortexResult<Vec<_>>>()?;
+    struct_dtype
+        .names()
+        .iter()
+        .enumerate()
+        .map(|(idx, name)| {
+            let fields = chunks
+                .iter()
+                .map(|chunk| {
+                    chunk
+                        .field(idx)
+                        .ok_or_else(|| vortex_err!("Missing field {name}"))
+                })
+                .collect::<VortexResult<Vec<_>>>()?;
+            Ok((name.to_string(), fields))
+        })
+        .collect()
+}
+
+/// Collects the distinct encoding IDs of an array tree in depth-first order.
+#[derive(Default)]
+struct EncodingCollector(Vec<String>);
+
+impl ArrayVisitor for EncodingCollector {
+    fn visit_child(&mut self, _name: &str, array: &Array) -> VortexResult<()> {
+        let id = array.encoding().id().to_string();
+        if !self.0.contains(&id) {
+            self.0.push(id);
+        }
+        array.with_dyn(|a| a.accept(self))
+    }
 }
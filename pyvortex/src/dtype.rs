@@ -2,9 +2,11 @@ use arrow::datatypes::{DataType, Field};
 use arrow::pyarrow::FromPyArrow;
 use pyo3::types::PyType;
 use pyo3::{pyclass, pymethods, Bound, Py, PyAny, PyResult, Python};
-use vortex::arrow::FromArrowType;
+use vortex::arrow::TryFromArrowType;
 use vortex_dtype::DType;
 
+use crate::error::PyVortexError;
+
 #[pyclass(name = "DType", module = "vortex", subclass)]
 pub struct PyDType {
     inner: DType,
@@ -32,10 +34,9 @@ impl PyDType {
         #[pyo3(from_py_with = "import_arrow_dtype")] arrow_dtype: DataType,
         nullable: bool,
     ) -> PyResult<Py<Self>> {
-        Self::wrap(
-            cls.py(),
-            DType::from_arrow(&Field::new("_", arrow_dtype, nullable)),
-        )
+        let dtype = DType::try_from_arrow(&Field::new("_", arrow_dtype, nullable))
+            .map_err(PyVortexError::map_err)?;
+        Self::wrap(cls.py(), dtype)
     }
 }
 
@@ -5,56 +5,116 @@ use arrow::pyarrow::FromPyArrow;
 use arrow::record_batch::RecordBatchReader;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::types::IntoPyDict;
 use vortex::array::chunked::ChunkedArray;
-use vortex::arrow::{FromArrowArray, FromArrowType};
-use vortex::{ArrayData, IntoArray, IntoArrayData, ToArrayData};
+use vortex::arrow::TryFromArrowType;
+use vortex::IntoArrayData;
 use vortex_dtype::DType;
 
 use crate::array::PyArray;
 use crate::error::PyVortexError;
-use crate::vortex_arrow::map_arrow_err;
+use crate::vortex_arrow::{import_array, import_record_batch, map_arrow_err};
 
 /// The main entry point for creating enc arrays from other Python objects.
 ///
+/// Accepts pyarrow arrays, chunked arrays and tables, NumPy arrays (including masked arrays), and
+/// pandas Series and DataFrames.
+///
+/// pyarrow has no notion of a non-nullable array, so arrays and chunked arrays are only encoded
+/// with a nullable dtype when `nullable` is set or they hold nulls. Masked NumPy arrays are always
+/// nullable. Tables take their nullability from the schema.
 #[pyfunction]
-pub fn encode(obj: &Bound<PyAny>) -> PyResult<Py<PyArray>> {
+#[pyo3(signature = (obj, *, nullable = false))]
+pub fn encode(obj: &Bound<PyAny>, nullable: bool) -> PyResult<Py<PyArray>> {
     let pa = obj.py().import_bound("pyarrow")?;
     let pa_array = pa.getattr("Array")?;
     let chunked_array = pa.getattr("ChunkedArray")?;
     let table = pa.getattr("Table")?;
+    let np = obj.py().import_bound("numpy")?;
+
+    if obj.is_instance(&np.getattr("ma")?.getattr("MaskedArray")?)? {
+        let mask = np.getattr("ma")?.call_method1("getmaskarray", (obj,))?;
+        encode(
+            &pa.call_method(
+                "array",
+                (obj.getattr("data")?,),
+                Some(&[("mask", mask)].into_py_dict_bound(obj.py())),
+            )?,
+            true,
+        )
+    } else if obj.is_instance(&np.getattr("ndarray")?)? {
+        // pyarrow wraps numeric NumPy buffers without copying.
+        encode(&pa.call_method1("array", (obj,))?, nullable)
+    } else if let Some(pd) = imported_module(obj.py(), "pandas")? {
+        if obj.is_instance(&pd.getattr("DataFrame")?)? {
+            let kwargs = [("preserve_index", false)].into_py_dict_bound(obj.py());
+            encode(
+                &table.call_method("from_pandas", (obj,), Some(&kwargs))?,
+                nullable,
+            )
+        } else if obj.is_instance(&pd.getattr("Series")?)? {
+            encode(&pa_array.call_method1("from_pandas", (obj,))?, nullable)
+        } else {
+            encode_arrow(obj, nullable, &pa_array, &chunked_array, &table)
+        }
+    } else {
+        encode_arrow(obj, nullable, &pa_array, &chunked_array, &table)
+    }
+}
+
+/// Look up a module only if the user has already imported it, since no object can be an instance
+/// of one of its types otherwise.
+fn imported_module<'py>(py: Python<'py>, name: &str) -> PyResult<Option<Bound<'py, PyAny>>> {
+    py.import_bound("sys")?
+        .getattr("modules")?
+        .call_method1("get", (name,))
+        .map(|module| (!module.is_none()).then_some(module))
+}
 
-    if obj.is_instance(&pa_array)? {
+fn encode_arrow(
+    obj: &Bound<PyAny>,
+    nullable: bool,
+    pa_array: &Bound<PyAny>,
+    chunked_array: &Bound<PyAny>,
+    table: &Bound<PyAny>,
+) -> PyResult<Py<PyArray>> {
+    // A non-nullable dtype can't represent the nulls of an array that holds any.
+    let nullable = nullable
+        || ((obj.is_instance(pa_array)? || obj.is_instance(chunked_array)?)
+            && obj.getattr("null_count")?.extract::<usize>()? > 0);
+    if obj.is_instance(pa_array)? {
         let arrow_array = ArrowArrayData::from_pyarrow_bound(obj).map(make_array)?;
-        let enc_array = ArrayData::from_arrow(arrow_array, false);
-        PyArray::wrap(obj.py(), enc_array)
-    } else if obj.is_instance(&chunked_array)? {
+        PyArray::wrap(
+            obj.py(),
+            import_array(arrow_array, nullable)?.into_array_data(),
+        )
+    } else if obj.is_instance(chunked_array)? {
         let chunks: Vec<Bound<PyAny>> = obj.getattr("chunks")?.extract()?;
-        let encoded_chunks = chunks
+        let arrow_chunks = chunks
             .iter()
-            .map(|a| {
-                ArrowArrayData::from_pyarrow_bound(a)
-                    .map(make_array)
-                    .map(|a| ArrayData::from_arrow(a, false).into_array())
-            })
+            .map(|a| ArrowArrayData::from_pyarrow_bound(a).map(make_array))
+            .collect::<PyResult<Vec<_>>>()?;
+        let encoded_chunks = arrow_chunks
+            .into_iter()
+            .map(|a| import_array(a, nullable))
             .collect::<PyResult<Vec<_>>>()?;
-        let dtype: DType = obj
-            .getattr("type")
-            .and_then(|v| DataType::from_pyarrow_bound(&v))
-            .map(|dt| DType::from_arrow(&Field::new("_", dt, false)))?;
+        let data_type = DataType::from_pyarrow_bound(&obj.getattr("type")?)?;
+        let dtype = DType::try_from_arrow(&Field::new("_", data_type, nullable))
+            .map_err(PyVortexError::map_err)?;
         PyArray::wrap(
             obj.py(),
             ChunkedArray::try_new(encoded_chunks, dtype)
                 .map_err(PyVortexError::map_err)?
                 .into_array_data(),
         )
-    } else if obj.is_instance(&table)? {
+    } else if obj.is_instance(table)? {
         let array_stream = ArrowArrayStreamReader::from_pyarrow_bound(obj)?;
-        let dtype = DType::from_arrow(array_stream.schema());
+        let dtype = DType::try_from_arrow(array_stream.schema()).map_err(PyVortexError::map_err)?;
         let chunks = array_stream
             .into_iter()
             .map(|b| {
-                b.map(|bb| bb.to_array_data().into_array())
-                    .map_err(map_arrow_err)
+                b.map_err(map_arrow_err)
+                    .and_then(|b| import_record_batch(&b))
             })
             .collect::<PyResult<Vec<_>>>()?;
         PyArray::wrap(
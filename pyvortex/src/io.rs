@@ -0,0 +1,381 @@
+use std::fs::File;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use arrow::array::RecordBatchReader;
+use arrow::buffer::BooleanBuffer;
+use arrow::pyarrow::IntoPyArrow;
+use futures_executor::block_on;
+use futures_util::io::AllowStdIo;
+use itertools::Itertools;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyList, PyTuple};
+use vortex::array::chunked::ChunkedArray;
+use vortex::array::constant::ConstantArray;
+use vortex::array::struct_::StructArray;
+use vortex::arrow::DEFAULT_BATCH_SIZE;
+use vortex::compute::compare::compare;
+use vortex::iter::{ArrayIterator, ArrayIteratorExt};
+use vortex::stats::can_skip_filter;
+use vortex::validity::Validity;
+use vortex::{
+    Array, ArrayDType, ArrayTrait, IntoArray, IntoArrayData, IntoArrayVariant, ViewContext,
+};
+use vortex_dtype::field::FieldPath;
+use vortex_dtype::{DType, StructDType};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_expr::{Conjunction, Disjunction, Operator, Predicate, Value};
+use vortex_ipc::io::{FuturesAdapter, VortexWrite};
+use vortex_ipc::writer::ArrayWriter;
+use vortex_ipc::MessageReader;
+use vortex_scalar::Scalar;
+
+use crate::array::{take_mask, PyArray};
+use crate::encode::encode;
+use crate::error::PyVortexError;
+use crate::scalar::scalar_from_py;
+use crate::CTX;
+
+/// Write an array to a Vortex file.
+///
+/// Anything accepted by `encode` may be passed in place of a Vortex array.
+#[pyfunction]
+pub fn write(py: Python<'_>, array: &Bound<PyAny>, path: PathBuf) -> PyResult<()> {
+    let array = match array.downcast::<PyArray>() {
+        Ok(py_array) => py_array.borrow().unwrap().clone(),
+        Err(_) => encode(array, false)?.borrow(py).unwrap().clone(),
+    };
+
+    py.allow_threads(|| {
+        block_on(async {
+            let file = File::create(path)?;
+            let mut write = ArrayWriter::new(
+                FuturesAdapter(AllowStdIo::new(file)),
+                ViewContext::from(&CTX.clone()),
+            )
+            .write_context()
+            .await?
+            .write_array(array)
+            .await?
+            .into_inner();
+            write.flush().await?;
+            VortexResult::Ok(())
+        })
+    })
+    .map_err(PyVortexError::map_err)
+}
+
+/// Read a Vortex file into a Vortex array.
+///
+/// `columns` restricts the top-level fields that are returned, and `filter` selects rows using
+/// the same disjunctive normal form as `pyarrow.parquet.read_table`, e.g.
+/// `[("a", ">", 3), ("b", "==", True)]` or a list of such lists.
+#[pyfunction]
+#[pyo3(signature = (path, columns = None, filter = None))]
+pub fn read(
+    py: Python<'_>,
+    path: PathBuf,
+    columns: Option<Vec<String>>,
+    filter: Option<&Bound<PyAny>>,
+) -> PyResult<Py<PyArray>> {
+    let options = scan_options(columns, filter)?;
+    let array = py
+        .allow_threads(|| {
+            let mut reader = VortexFileReader::try_open(path, options)?;
+            let mut chunks = vec![];
+            while let Some(chunk) = reader.next_chunk()? {
+                chunks.push(chunk);
+            }
+
+            if chunks.len() == 1 {
+                Ok(chunks.remove(0))
+            } else {
+                ChunkedArray::try_new(chunks, reader.dtype().clone()).map(IntoArray::into_array)
+            }
+        })
+        .map_err(PyVortexError::map_err)?;
+    PyArray::wrap(py, array.into_array_data())
+}
+
+/// Read a Vortex file of struct arrays into a `pyarrow.Table`.
+#[pyfunction]
+#[pyo3(signature = (path, columns = None, filter = None))]
+pub fn read_table<'py>(
+    py: Python<'py>,
+    path: PathBuf,
+    columns: Option<Vec<String>>,
+    filter: Option<&Bound<'py, PyAny>>,
+) -> PyResult<Bound<'py, PyAny>> {
+    read_batches(py, path, columns, filter)?.call_method0("read_all")
+}
+
+/// Open a Vortex file of struct arrays as a `pyarrow.RecordBatchReader`.
+///
+/// Chunks are read from disk and decoded one at a time as the reader is consumed, and are
+/// re-sliced into batches of at most `DEFAULT_BATCH_SIZE` rows. The Arrow schema is inferred
+/// from the file's dtype, so it is known before any chunk has been read.
+#[pyfunction]
+#[pyo3(signature = (path, columns = None, filter = None))]
+pub fn read_batches<'py>(
+    py: Python<'py>,
+    path: PathBuf,
+    columns: Option<Vec<String>>,
+    filter: Option<&Bound<'py, PyAny>>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let options = scan_options(columns, filter)?;
+    let reader = py
+        .allow_threads(|| {
+            VortexFileReader::try_open(path, options)
+                .and_then(|reader| reader.into_record_batch_reader(DEFAULT_BATCH_SIZE))
+        })
+        .map_err(PyVortexError::map_err)?;
+    let reader: Box<dyn RecordBatchReader + Send> = Box::new(reader);
+    Ok(reader.into_pyarrow(py)?.into_bound(py))
+}
+
+struct ScanOptions {
+    columns: Option<Vec<FieldPath>>,
+    filter: Option<Disjunction>,
+}
+
+fn scan_options(
+    columns: Option<Vec<String>>,
+    filter: Option<&Bound<PyAny>>,
+) -> PyResult<ScanOptions> {
+    Ok(ScanOptions {
+        columns: columns.map(|names| {
+            names
+                .iter()
+                .map(|name| FieldPath::from_name(name))
+                .collect()
+        }),
+        filter: filter.map(parse_filter).transpose()?,
+    })
+}
+
+/// Parse a filter in disjunctive normal form: a list of predicate tuples is a conjunction, and a
+/// list of such lists is a disjunction of conjunctions.
+fn parse_filter(filter: &Bound<PyAny>) -> PyResult<Disjunction> {
+    let terms = filter.downcast::<PyList>()?;
+    if terms.iter().all(|term| term.is_instance_of::<PyTuple>()) {
+        return parse_conjunction(terms).map(Disjunction::from);
+    }
+
+    terms
+        .iter()
+        .map(|term| parse_conjunction(term.downcast::<PyList>()?))
+        .collect()
+}
+
+fn parse_conjunction(terms: &Bound<PyList>) -> PyResult<Conjunction> {
+    terms
+        .iter()
+        .map(|term| {
+            let (name, op, value): (String, String, Bound<PyAny>) = term.extract()?;
+            Ok(Predicate {
+                lhs: FieldPath::from_name(&name),
+                op: parse_operator(&op)?,
+                rhs: Value::Literal(scalar_from_py(&value)?),
+            })
+        })
+        .collect()
+}
+
+fn parse_operator(op: &str) -> PyResult<Operator> {
+    Ok(match op {
+        "=" | "==" => Operator::Eq,
+        "!=" => Operator::NotEq,
+        ">" => Operator::Gt,
+        ">=" => Operator::Gte,
+        "<" => Operator::Lt,
+        "<=" => Operator::Lte,
+        _ => return Err(PyValueError::new_err(format!("Unsupported operator {op}"))),
+    })
+}
+
+/// Reads the chunks of the first array in a Vortex file, applying the scan options to each.
+struct VortexFileReader {
+    msgs: MessageReader<FuturesAdapter<AllowStdIo<File>>>,
+    view_ctx: Arc<ViewContext>,
+    file_dtype: DType,
+    /// The dtype of the chunks after the scan options have been applied.
+    dtype: DType,
+    options: ScanOptions,
+}
+
+impl VortexFileReader {
+    fn try_open(path: PathBuf, options: ScanOptions) -> VortexResult<Self> {
+        block_on(async {
+            let file = File::open(path)?;
+            let mut msgs = MessageReader::try_new(FuturesAdapter(AllowStdIo::new(file))).await?;
+            let view_ctx = msgs.read_view_context(&CTX).await?;
+            let file_dtype = msgs.read_dtype().await?;
+            let dtype = project_dtype(&file_dtype, options.columns.as_deref())?;
+            Ok(Self {
+                msgs,
+                view_ctx,
+                file_dtype,
+                dtype,
+                options,
+            })
+        })
+    }
+
+    /// Read the next chunk, skipping those whose statistics prove that no row matches the filter.
+    fn next_chunk(&mut self) -> VortexResult<Option<Array>> {
+        loop {
+            let Some(chunk) = block_on(
+                self.msgs
+                    .maybe_read_chunk(self.view_ctx.clone(), self.file_dtype.clone()),
+            )?
+            else {
+                return Ok(None);
+            };
+            if let Some(chunk) = self.scan_chunk(chunk)? {
+                return Ok(Some(chunk));
+            }
+        }
+    }
+
+    fn scan_chunk(&self, chunk: Array) -> VortexResult<Option<Array>> {
+        if self.options.columns.is_none() && self.options.filter.is_none() {
+            return Ok(Some(chunk));
+        }
+
+        let mut chunk = chunk.into_struct()?;
+        let Some(filter) = &self.options.filter else {
+            let columns = self.options.columns.as_deref().expect("columns or filter");
+            return Ok(Some(chunk.project_paths(columns)?.into_array()));
+        };
+
+        if can_skip_filter(&chunk, filter)? {
+            return Ok(None);
+        }
+        // Take the filtered rows from the requested and filter columns only, then drop the latter.
+        if let Some(columns) = &self.options.columns {
+            let paths = columns
+                .iter()
+                .chain(filter_paths(filter))
+                .cloned()
+                .collect_vec();
+            chunk = chunk.project_paths(&paths)?;
+        }
+        let mask = filter_mask(&chunk, filter)?;
+        chunk = StructArray::try_from(take_mask(chunk.array(), &mask)?)?;
+        if let Some(columns) = &self.options.columns {
+            chunk = chunk.project_paths(columns)?;
+        }
+        Ok(Some(chunk.into_array()))
+    }
+}
+
+impl Iterator for VortexFileReader {
+    type Item = VortexResult<Array>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_chunk().transpose()
+    }
+}
+
+impl ArrayIterator for VortexFileReader {
+    fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+}
+
+fn project_dtype(dtype: &DType, columns: Option<&[FieldPath]>) -> VortexResult<DType> {
+    let Some(columns) = columns else {
+        return Ok(dtype.clone());
+    };
+    let DType::Struct(struct_dtype, nullability) = dtype else {
+        vortex_bail!("Cannot select columns from {}", dtype);
+    };
+    let indices = columns
+        .iter()
+        .map(|column| {
+            struct_dtype
+                .find_name(column.to_name())
+                .ok_or_else(|| vortex_err!("Unknown column {}", column.to_name()))
+        })
+        .collect::<VortexResult<Vec<_>>>()?;
+    Ok(DType::Struct(
+        StructDType::new(
+            indices
+                .iter()
+                .map(|&idx| struct_dtype.names()[idx].clone())
+                .collect(),
+            indices
+                .iter()
+                .map(|&idx| struct_dtype.dtypes()[idx].clone())
+                .collect(),
+        ),
+        *nullability,
+    ))
+}
+
+/// The field paths referenced by the predicates of a filter.
+fn filter_paths(filter: &Disjunction) -> impl Iterator<Item = &FieldPath> {
+    filter
+        .iter()
+        .flat_map(|conjunction| conjunction.iter())
+        .flat_map(|predicate| {
+            let rhs = match &predicate.rhs {
+                Value::Field(path) => Some(path),
+                Value::Literal(_) => None,
+            };
+            std::iter::once(&predicate.lhs).chain(rhs)
+        })
+}
+
+fn filter_mask(array: &StructArray, filter: &Disjunction) -> VortexResult<BooleanBuffer> {
+    filter
+        .iter()
+        .map(|conjunction| {
+            conjunction
+                .iter()
+                .map(|predicate| predicate_mask(array, predicate))
+                .reduce(|a, b| Ok(&a? & &b?))
+                .unwrap_or_else(|| Ok(BooleanBuffer::new_set(array.len())))
+        })
+        .reduce(|a, b| Ok(&a? | &b?))
+        .unwrap_or_else(|| Ok(BooleanBuffer::new_set(array.len())))
+}
+
+fn predicate_mask(array: &StructArray, predicate: &Predicate) -> VortexResult<BooleanBuffer> {
+    let (lhs, lhs_validity) = array.field_and_validity_by_path(&predicate.lhs)?;
+    let (rhs, rhs_validity) = match &predicate.rhs {
+        Value::Field(path) => array.field_and_validity_by_path(path)?,
+        Value::Literal(scalar) => (
+            ConstantArray::new(cast_literal(scalar, lhs.dtype())?, lhs.len()).into_array(),
+            Validity::NonNullable,
+        ),
+    };
+    let result = compare(&lhs, &rhs, predicate.op)?.into_bool()?;
+
+    // As in pyarrow, rows where the comparison is null, either because a value is null or because
+    // one of the structs above it is, never match.
+    let validity = result.validity().and(&lhs_validity)?.and(&rhs_validity)?;
+    Ok(&result.boolean_buffer()
+        & validity
+            .to_logical(result.len())
+            .to_present_null_buffer()?
+            .inner())
+}
+
+/// Cast a filter literal to the dtype of the field it is compared with, rejecting casts that would
+/// change its value, e.g. `3.5` compared with an integer field.
+pub(crate) fn cast_literal(literal: &Scalar, dtype: &DType) -> VortexResult<Scalar> {
+    if literal.dtype().eq_ignore_nullability(dtype) {
+        return Ok(Scalar::new(dtype.clone(), literal.value().clone()));
+    }
+    let cast = literal.cast(dtype)?;
+    if cast.cast(literal.dtype())? != *literal {
+        vortex_bail!(
+            "Cannot compare {} with a {} field without losing precision",
+            literal,
+            dtype
+        );
+    }
+    Ok(cast)
+}
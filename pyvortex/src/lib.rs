@@ -1,23 +1,60 @@
 use dtype::PyDType;
+use lazy_static::lazy_static;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use vortex::encoding::EncodingRef;
+use vortex::Context;
+use vortex_alp::ALPEncoding;
+use vortex_datetime_parts::DateTimePartsEncoding;
+use vortex_dict::DictEncoding;
 use vortex_dtype::{DType, PType};
+use vortex_fastlanes::{BitPackedEncoding, DeltaEncoding, FoREncoding};
+use vortex_ree::REEEncoding;
+use vortex_roaring::{RoaringBoolEncoding, RoaringIntEncoding};
+use vortex_zigzag::ZigZagEncoding;
 
 use crate::array::*;
 
 mod array;
+mod compress;
 mod dtype;
 mod encode;
 mod error;
+mod io;
+mod numpy;
+mod scalar;
 mod vortex_arrow;
 
+lazy_static! {
+    /// Every encoding the Python bindings know how to read.
+    pub(crate) static ref CTX: Context = Context::default().with_encodings([
+        &ALPEncoding as EncodingRef,
+        &BitPackedEncoding,
+        &DateTimePartsEncoding,
+        &DeltaEncoding,
+        &DictEncoding,
+        &FoREncoding,
+        &REEEncoding,
+        &RoaringBoolEncoding,
+        &RoaringIntEncoding,
+        &ZigZagEncoding,
+    ]);
+}
+
 /// A Python module implemented in Rust.
 #[pymodule]
 fn _lib(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     pyo3_log::init();
 
     m.add_function(wrap_pyfunction!(encode::encode, m)?)?;
-    // m.add_function(wrap_pyfunction!(compress::compress, m)?)?;
+    m.add_function(wrap_pyfunction!(compress::compress, m)?)?;
+    m.add_function(wrap_pyfunction!(compress::compress_with_report, m)?)?;
+    m.add_class::<compress::PyCompressConfig>()?;
+
+    m.add_function(wrap_pyfunction!(io::write, m)?)?;
+    m.add_function(wrap_pyfunction!(io::read, m)?)?;
+    m.add_function(wrap_pyfunction!(io::read_table, m)?)?;
+    m.add_function(wrap_pyfunction!(io::read_batches, m)?)?;
 
     m.add_class::<PyArray>()?;
     m.add_class::<PyBoolArray>()?;
@@ -0,0 +1,78 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{IntoPyDict, PyDict};
+use vortex::{Array, ArrayDType};
+use vortex_dtype::DType;
+
+use crate::vortex_arrow;
+
+/// Export an array to NumPy by way of the Arrow C Data Interface.
+///
+/// Non-null primitive arrays with a single chunk are returned as a view over the Vortex buffer.
+/// Nullable arrays are returned as a `numpy.ma.MaskedArray`, whose values are still a view when the
+/// array is primitive.
+pub fn export_numpy<'py>(
+    py: Python<'py>,
+    array: &Array,
+    zero_copy_only: bool,
+) -> PyResult<Bound<'py, PyAny>> {
+    let chunked = vortex_arrow::export_array(py, array)?;
+    let num_chunks = chunked.getattr("num_chunks")?.extract::<usize>()?;
+    let arrow_array = if num_chunks == 1 {
+        chunked.call_method1("chunk", (0,))?
+    } else if zero_copy_only {
+        return Err(PyValueError::new_err(format!(
+            "Cannot export an array of {num_chunks} chunks to NumPy without copying"
+        )));
+    } else {
+        chunked.call_method0("combine_chunks")?
+    };
+
+    let to_numpy_kwargs = [("zero_copy_only", zero_copy_only)].into_py_dict_bound(py);
+    if arrow_array.getattr("null_count")?.extract::<usize>()? == 0 {
+        return arrow_array.call_method("to_numpy", (), Some(&to_numpy_kwargs));
+    }
+
+    let np = py.import_bound("numpy")?;
+    let values = if let DType::Primitive(ptype, _) = array.dtype() {
+        // pyarrow would copy nullable integers into floats, so view the values buffer directly.
+        let offset: usize = arrow_array.getattr("offset")?.extract()?;
+        let kwargs = PyDict::new_bound(py);
+        kwargs.set_item(
+            "dtype",
+            arrow_array
+                .getattr("type")?
+                .call_method0("to_pandas_dtype")?,
+        )?;
+        kwargs.set_item("count", arrow_array.len()?)?;
+        kwargs.set_item("offset", offset * ptype.byte_width())?;
+        let buffer = arrow_array.call_method0("buffers")?.get_item(1)?;
+        np.call_method("frombuffer", (buffer,), Some(&kwargs))?
+    } else {
+        arrow_array.call_method("to_numpy", (), Some(&to_numpy_kwargs))?
+    };
+    let mask = arrow_array.call_method0("is_null")?.call_method(
+        "to_numpy",
+        (),
+        Some(&[("zero_copy_only", false)].into_py_dict_bound(py)),
+    )?;
+
+    np.getattr("ma")?.call_method(
+        "masked_array",
+        (values,),
+        Some(&[("mask", mask)].into_py_dict_bound(py)),
+    )
+}
+
+/// Export an array to pandas, as a `DataFrame` for struct arrays and a `Series` otherwise.
+pub fn export_pandas<'py>(py: Python<'py>, array: &Array) -> PyResult<Bound<'py, PyAny>> {
+    let chunked = vortex_arrow::export_array(py, array)?;
+    if matches!(array.dtype(), DType::Struct(..)) {
+        py.import_bound("pyarrow")?
+            .getattr("Table")?
+            .call_method1("from_struct_array", (chunked,))?
+            .call_method0("to_pandas")
+    } else {
+        chunked.call_method0("to_pandas")
+    }
+}
@@ -0,0 +1,54 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyList};
+use vortex_scalar::{PValue, Scalar, ScalarValue};
+
+/// Convert a Python object into a Vortex scalar of the narrowest matching type.
+///
+/// The result is usually cast to the dtype of the array it is used with.
+pub fn scalar_from_py(value: &Bound<PyAny>) -> PyResult<Scalar> {
+    // Python bools are also ints, so they must be checked first.
+    if let Ok(value) = value.extract::<bool>() {
+        Ok(value.into())
+    } else if let Ok(value) = value.extract::<i64>() {
+        Ok(value.into())
+    } else if let Ok(value) = value.extract::<f64>() {
+        Ok(value.into())
+    } else if let Ok(value) = value.extract::<String>() {
+        Ok(value.as_str().into())
+    } else {
+        Err(PyValueError::new_err(format!(
+            "Cannot convert {value} to a Vortex scalar"
+        )))
+    }
+}
+
+/// Convert a Vortex scalar into the equivalent Python object.
+pub fn scalar_into_py(py: Python<'_>, scalar: &Scalar) -> PyObject {
+    value_into_py(py, scalar.value())
+}
+
+fn value_into_py(py: Python<'_>, value: &ScalarValue) -> PyObject {
+    match value {
+        ScalarValue::Null => py.None(),
+        ScalarValue::Bool(b) => b.into_py(py),
+        ScalarValue::Primitive(pvalue) => match *pvalue {
+            PValue::U8(v) => v.into_py(py),
+            PValue::U16(v) => v.into_py(py),
+            PValue::U32(v) => v.into_py(py),
+            PValue::U64(v) => v.into_py(py),
+            PValue::I8(v) => v.into_py(py),
+            PValue::I16(v) => v.into_py(py),
+            PValue::I32(v) => v.into_py(py),
+            PValue::I64(v) => v.into_py(py),
+            PValue::F16(v) => v.to_f32().into_py(py),
+            PValue::F32(v) => v.into_py(py),
+            PValue::F64(v) => v.into_py(py),
+        },
+        ScalarValue::Buffer(buffer) => PyBytes::new_bound(py, buffer.as_ref()).into_py(py),
+        ScalarValue::BufferString(string) => string.as_str().into_py(py),
+        ScalarValue::List(values) => {
+            PyList::new_bound(py, values.iter().map(|v| value_into_py(py, v))).into_py(py)
+        }
+    }
+}
@@ -1,11 +1,28 @@
-use arrow::array::{Array as ArrowArray, ArrayRef};
+use std::ffi::CString;
+
+use arrow::array::{
+    as_run_array, Array as ArrowArray, ArrayRef, AsArray, DictionaryArray, RecordBatch, RunArray,
+};
+use arrow::datatypes::{
+    ArrowDictionaryKeyType, DataType, Int16Type, Int32Type, Int64Type, Int8Type, RunEndIndexType,
+    UInt16Type, UInt32Type, UInt64Type, UInt8Type,
+};
 use arrow::error::ArrowError;
 use arrow::pyarrow::ToPyArrow;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::{IntoPyDict, PyList};
+use pyo3::types::{IntoPyDict, PyCapsule, PyList, PyTuple};
 use vortex::array::chunked::ChunkedArray;
-use vortex::{Array, IntoCanonical};
+use vortex::array::struct_::StructArray;
+use vortex::arrow::{ffi, try_struct_from_arrow, TryFromArrowArray, DEFAULT_BATCH_SIZE};
+use vortex::validity::Validity;
+use vortex::{Array, ArrayDType, ArrayData, IntoArray, IntoCanonical};
+use vortex_dict::DictArray;
+use vortex_dtype::NativePType;
+use vortex_error::VortexResult;
+use vortex_ree::REEArray;
+
+use crate::error::PyVortexError;
 
 pub fn map_arrow_err(error: ArrowError) -> PyErr {
     PyValueError::new_err(error.to_string())
@@ -46,3 +63,100 @@ pub fn export_array<'py>(py: Python<'py>, array: &Array) -> PyResult<Bound<'py,
         Some(&[("type", pa_data_type)].into_py_dict_bound(py)),
     )
 }
+
+/// Export an array through the Arrow PyCapsule interface, as a pair of `arrow_schema` and
+/// `arrow_array` capsules.
+pub fn export_array_capsules<'py>(py: Python<'py>, array: &Array) -> PyResult<Bound<'py, PyTuple>> {
+    let (ffi_array, ffi_schema) =
+        ffi::export_array(array.clone()).map_err(PyVortexError::map_err)?;
+    let schema = PyCapsule::new_bound(py, ffi_schema, Some(CString::new("arrow_schema")?))?;
+    let array = PyCapsule::new_bound(py, ffi_array, Some(CString::new("arrow_array")?))?;
+    Ok(PyTuple::new_bound(py, [schema, array]))
+}
+
+/// Export a struct array through the Arrow PyCapsule interface as an `arrow_array_stream` capsule
+/// of record batches, one or more per chunk.
+pub fn export_stream_capsule<'py>(
+    py: Python<'py>,
+    array: &Array,
+) -> PyResult<Bound<'py, PyCapsule>> {
+    let chunked = match ChunkedArray::try_from(array) {
+        Ok(chunked) => chunked,
+        Err(_) => ChunkedArray::try_new(vec![array.clone()], array.dtype().clone())
+            .map_err(PyVortexError::map_err)?,
+    };
+    let ffi_stream = ffi::export_array_iterator(chunked.into_array_iterator(), DEFAULT_BATCH_SIZE)
+        .map_err(PyVortexError::map_err)?;
+    PyCapsule::new_bound(py, ffi_stream, Some(CString::new("arrow_array_stream")?))
+}
+
+/// Import an Arrow array, keeping dictionary and run-end encodings that Vortex can represent.
+pub fn import_array(array: ArrayRef, nullable: bool) -> PyResult<Array> {
+    try_import_array(array, nullable).map_err(PyVortexError::map_err)
+}
+
+fn try_import_array(array: ArrayRef, nullable: bool) -> VortexResult<Array> {
+    match array.data_type() {
+        DataType::Dictionary(keys, _) => match keys.as_ref() {
+            DataType::Int8 => import_dictionary(array.as_dictionary::<Int8Type>(), nullable),
+            DataType::Int16 => import_dictionary(array.as_dictionary::<Int16Type>(), nullable),
+            DataType::Int32 => import_dictionary(array.as_dictionary::<Int32Type>(), nullable),
+            DataType::Int64 => import_dictionary(array.as_dictionary::<Int64Type>(), nullable),
+            DataType::UInt8 => import_dictionary(array.as_dictionary::<UInt8Type>(), nullable),
+            DataType::UInt16 => import_dictionary(array.as_dictionary::<UInt16Type>(), nullable),
+            DataType::UInt32 => import_dictionary(array.as_dictionary::<UInt32Type>(), nullable),
+            DataType::UInt64 => import_dictionary(array.as_dictionary::<UInt64Type>(), nullable),
+            _ => ArrayData::try_from_arrow(array, nullable).map(IntoArray::into_array),
+        },
+        DataType::RunEndEncoded(run_ends, values) if values.data_type().is_numeric() => {
+            match run_ends.data_type() {
+                DataType::Int16 => import_run_array(as_run_array::<Int16Type>(&array), nullable),
+                DataType::Int32 => import_run_array(as_run_array::<Int32Type>(&array), nullable),
+                DataType::Int64 => import_run_array(as_run_array::<Int64Type>(&array), nullable),
+                _ => ArrayData::try_from_arrow(array, nullable).map(IntoArray::into_array),
+            }
+        }
+        // The columns of a struct may be encoded too.
+        DataType::Struct(_) => try_struct_from_arrow(array.as_struct(), nullable, try_import_array)
+            .map(IntoArray::into_array),
+        _ => ArrayData::try_from_arrow(array, nullable).map(IntoArray::into_array),
+    }
+}
+
+fn import_dictionary<K: ArrowDictionaryKeyType>(
+    array: &DictionaryArray<K>,
+    nullable: bool,
+) -> VortexResult<Array> {
+    DictArray::try_from_arrow(array, nullable).map(IntoArray::into_array)
+}
+
+fn import_run_array<R: RunEndIndexType>(array: &RunArray<R>, nullable: bool) -> VortexResult<Array>
+where
+    R::Native: NativePType,
+{
+    REEArray::try_from_arrow(array, nullable).map(IntoArray::into_array)
+}
+
+/// Import a record batch as a struct array, keeping the encodings of its columns.
+pub fn import_record_batch(batch: &RecordBatch) -> PyResult<Array> {
+    let columns = batch
+        .columns()
+        .iter()
+        .zip(batch.schema().fields())
+        .map(|(column, field)| import_array(column.clone(), field.is_nullable()))
+        .collect::<PyResult<Vec<_>>>()?;
+    let names = batch
+        .schema()
+        .fields()
+        .iter()
+        .map(|f| f.name().as_str().into())
+        .collect::<Vec<_>>();
+    StructArray::try_new(
+        names.into(),
+        columns,
+        batch.num_rows(),
+        Validity::NonNullable,
+    )
+    .map(IntoArray::into_array)
+    .map_err(PyVortexError::map_err)
+}
@@ -1,4 +1,6 @@
+import numpy as np
 import pyarrow as pa
+import pytest
 import vortex
 
 
@@ -28,3 +30,126 @@ def test_empty_array():
     a = pa.array([], type=pa.uint8())
     primitive = vortex.encode(a)
     assert primitive.to_pyarrow().type == pa.uint8()
+
+
+def test_getitem():
+    a = vortex.encode(pa.array([10, 20, 30, 40, 50]))
+    assert a[0] == 10
+    assert a[-1] == 50
+    assert a[1:3].to_pyarrow().combine_chunks() == pa.array([20, 30])
+    assert a[::2].to_pyarrow().combine_chunks() == pa.array([10, 30, 50])
+    with pytest.raises(IndexError):
+        a[5]
+
+
+def test_compare():
+    a = vortex.encode(pa.array([1, 2, 3, 4]))
+    assert (a > 2).to_pyarrow().combine_chunks() == pa.array([False, False, True, True])
+    assert (a == vortex.encode(pa.array([1, 0, 3, 0]))).to_pyarrow().combine_chunks() == pa.array(
+        [True, False, True, False]
+    )
+    with pytest.raises(ValueError):
+        a > 2.5
+
+
+def test_filter():
+    a = vortex.encode(pa.array([1, 2, 3, 4]))
+    assert a.filter(a >= 3).to_pyarrow().combine_chunks() == pa.array([3, 4])
+    assert a.filter(lambda arr: arr < 2).to_pyarrow().combine_chunks() == pa.array([1])
+
+
+def test_search_sorted():
+    a = vortex.encode(pa.array([1, 2, 2, 3]))
+    assert a.search_sorted(2) == 1
+    assert a.search_sorted(2, side="right") == 3
+
+
+def test_fill_forward():
+    a = vortex.encode(pa.array([1, None, None, 4, None]))
+    assert a.fill_forward().to_pyarrow().combine_chunks() == pa.array([1, 1, 1, 4, 4])
+
+
+def test_cast():
+    a = vortex.encode(pa.array([1, 2, 3], type=pa.int32()))
+    assert a.cast(vortex.int(64)).to_pyarrow().combine_chunks() == pa.array([1, 2, 3], type=pa.int64())
+
+
+def test_statistics():
+    a = vortex.encode(pa.array([3, 1, 2]))
+    stats = a.statistics
+    assert stats["min"] == 1
+    assert stats["max"] == 3
+    assert stats["is_sorted"] is False
+    assert "distinct_count_sketch" not in stats
+    assert "bloom_filter" not in stats
+    assert a.tree_display().startswith("root: ")
+
+
+def test_to_numpy():
+    a = vortex.encode(np.arange(5, dtype=np.int32))
+    assert isinstance(a, vortex.PrimitiveArray)
+    np.testing.assert_array_equal(a.to_numpy(zero_copy_only=True), np.arange(5, dtype=np.int32))
+
+
+def test_to_numpy_nullable():
+    a = vortex.encode(pa.array([1, None, 3], type=pa.int64()), nullable=True)
+    masked = a.to_numpy()
+    assert isinstance(masked, np.ma.MaskedArray)
+    assert masked.dtype == np.int64
+    assert masked.mask.tolist() == [False, True, False]
+    assert masked[0] == 1 and masked[2] == 3
+
+
+def test_to_numpy_chunked():
+    a = vortex.encode(pa.chunked_array([[1, 2], [3]]))
+    np.testing.assert_array_equal(a.to_numpy(), np.array([1, 2, 3]))
+    with pytest.raises(ValueError):
+        a.to_numpy(zero_copy_only=True)
+
+
+def test_encode_masked_numpy():
+    a = vortex.encode(np.ma.masked_array([1, 2, 3], mask=[False, True, False]))
+    assert str(a.dtype) == "i64?"
+    assert a.to_pyarrow().combine_chunks() == pa.array([1, None, 3])
+
+
+def test_encode_nulls_nullable():
+    a = vortex.encode(pa.array([1, None, 3]))
+    assert str(a.dtype) == "i64?"
+    assert a.to_pyarrow().combine_chunks() == pa.array([1, None, 3])
+    assert str(vortex.encode(pa.array([1, 2, 3])).dtype) == "i64"
+
+
+def test_pandas_round_trip():
+    pd = pytest.importorskip("pandas")
+    df = pd.DataFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+    encoded = vortex.encode(df)
+    pd.testing.assert_frame_equal(encoded.to_pandas(), df)
+
+    series = pd.Series([1.0, 2.0, 3.0])
+    pd.testing.assert_series_equal(vortex.encode(series).to_pandas(), series, check_names=False)
+
+
+def test_arrow_c_array():
+    a = pa.array([0, None, 2])
+    arr = vortex.encode(a, nullable=True)
+    assert pa.array(arr) == a
+
+
+def test_arrow_c_stream():
+    table = pa.table({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+    arr = vortex.encode(table)
+    assert pa.RecordBatchReader.from_stream(arr).read_all() == table
+
+
+def test_dictionary_array():
+    a = pa.array(["a", None, "b", "a"]).dictionary_encode()
+    arr = vortex.encode(a, nullable=True)
+    assert isinstance(arr, vortex.DictArray)
+    assert arr.to_pyarrow().combine_chunks() == a.cast(pa.string())
+
+
+def test_nested_dictionary_array():
+    a = pa.StructArray.from_arrays([pa.array(["a", "b", "a"]).dictionary_encode()], names=["x"])
+    arr = vortex.encode(a)
+    assert "vortex.dict" in arr.tree_display()
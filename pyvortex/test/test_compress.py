@@ -16,7 +16,6 @@ def test_primitive_compress():
     assert arr_compressed.nbytes < a.nbytes
 
 
-@pytest.mark.xfail(reason="Not yet implemented")
 def test_for_compress():
     a = pa.array(np.arange(10_000) + 10_000_000)
     arr_compressed = vortex.compress(vortex.encode(a))
@@ -48,7 +47,6 @@ def test_arange_encode():
     assert compressed.nbytes < a.nbytes
 
 
-@pytest.mark.xfail(reason="Not yet implemented")
 def test_zigzag_encode():
     a = vortex.encode(pa.array([-1, -1, 0, -1, 1, -1]))
     zarr = vortex.ZigZagArray.encode(a)
@@ -56,6 +54,40 @@ def test_zigzag_encode():
     # TODO(ngates): support decoding once we have decompressor.
 
 
+def test_compress_config():
+    config = vortex.CompressConfig(sample_size=256, sample_count=4, max_depth=2)
+    assert config.sample_size == 256
+    assert config.sample_count == 4
+    assert config.max_depth == 2
+    assert config.ree_average_run_threshold == vortex.CompressConfig.default().ree_average_run_threshold
+    assert "fastlanes.for" in vortex.CompressConfig.available_encodings()
+
+    with pytest.raises(ValueError):
+        vortex.CompressConfig(include_encodings=["not.an.encoding"])
+
+
+def test_compress_excluded_encoding():
+    a = vortex.encode(pa.array(np.arange(10_000) + 10_000_000))
+    compressed = vortex.compress(a, vortex.CompressConfig(include_encodings=[]))
+    assert isinstance(compressed, vortex.PrimitiveArray)
+
+
+def test_compress_with_report():
+    table = pa.table(
+        {
+            "id": pa.array(np.arange(10_000) + 10_000_000),
+            "flag": pa.array([True] * 10_000),
+        }
+    )
+    compressed, report = vortex.compress_with_report(vortex.encode(table))
+    assert len(compressed) == 10_000
+    assert [entry["column"] for entry in report] == ["id", "flag"]
+    for entry in report:
+        assert entry["compressed_nbytes"] < entry["nbytes"]
+        assert entry["ratio"] == entry["compressed_nbytes"] / entry["nbytes"]
+        assert entry["encodings"]
+
+
 def test_chunked_encode():
     chunked = pa.chunked_array([pa.array([0, 1, 2]), pa.array([3, 4, 5])])
     encoded = vortex.encode(chunked)
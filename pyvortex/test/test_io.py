@@ -0,0 +1,78 @@
+import pyarrow as pa
+import pytest
+import vortex
+
+
+@pytest.fixture
+def table():
+    return pa.table(
+        {
+            "id": pa.array([1, 2, 3, 4, 5], type=pa.int64()),
+            "score": pa.array([0.5, 1.5, 2.5, 3.5, 4.5], type=pa.float64()),
+            "name": pa.array(["a", "b", "c", "d", "e"]),
+        }
+    )
+
+
+def test_write_read_round_trip(tmp_path, table):
+    path = tmp_path / "table.vortex"
+    vortex.write(vortex.encode(table), str(path))
+
+    arr = vortex.read(str(path))
+    assert len(arr) == 5
+    assert arr.to_pyarrow().combine_chunks() == vortex.encode(table).to_pyarrow().combine_chunks()
+
+
+def test_read_table(tmp_path, table):
+    path = tmp_path / "table.vortex"
+    vortex.write(table, str(path))
+
+    assert vortex.read_table(str(path)) == table
+
+
+def test_read_columns(tmp_path, table):
+    path = tmp_path / "table.vortex"
+    vortex.write(table, str(path))
+
+    assert vortex.read_table(str(path), columns=["name", "id"]) == table.select(["name", "id"])
+
+
+def test_read_filter(tmp_path, table):
+    path = tmp_path / "table.vortex"
+    vortex.write(table, str(path))
+
+    assert vortex.read_table(str(path), filter=[("id", ">", 1), ("score", "<", 4.0)]) == table.slice(1, 3)
+    assert vortex.read_table(str(path), filter=[[("id", "==", 1)], [("id", ">=", 5)]]) == table.take([0, 4])
+    assert vortex.read_table(str(path), columns=["name"], filter=[("id", ">", 3)]) == table.select(["name"]).slice(3)
+
+
+def test_read_filter_lossy_literal(tmp_path, table):
+    path = tmp_path / "table.vortex"
+    vortex.write(table, str(path))
+
+    assert vortex.read_table(str(path), filter=[("id", ">", 3.0)]) == table.slice(3)
+    with pytest.raises(ValueError):
+        vortex.read_table(str(path), filter=[("id", ">", 3.5)])
+
+
+def test_read_filter_nulls(tmp_path):
+    table = pa.table(
+        {
+            "id": pa.array([1, 2, 3, 4], type=pa.int64()),
+            "score": pa.array([0.5, None, 2.5, None], type=pa.float64()),
+        }
+    )
+    path = tmp_path / "table.vortex"
+    vortex.write(table, str(path))
+
+    assert vortex.read_table(str(path), filter=[("score", ">", 0.0)]) == table.take([0, 2])
+    assert vortex.read_table(str(path), filter=[("score", "!=", 2.5)]) == table.take([0])
+
+
+def test_read_batches(tmp_path, table):
+    path = tmp_path / "table.vortex"
+    vortex.write(table, str(path))
+
+    reader = vortex.read_batches(str(path), columns=["id"])
+    assert isinstance(reader, pa.RecordBatchReader)
+    assert reader.read_all() == table.select(["id"])
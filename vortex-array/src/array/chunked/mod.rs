@@ -10,6 +10,7 @@ use vortex_scalar::Scalar;
 
 use crate::array::primitive::PrimitiveArray;
 use crate::compute::search_sorted::{search_sorted, SearchResult, SearchSortedSide};
+use crate::compute::slice::slice;
 use crate::compute::unary::scalar_at::scalar_at;
 use crate::compute::unary::scalar_subtract::{subtract_scalar, SubtractScalarFn};
 use crate::iter::{ArrayIterator, ArrayIteratorAdapter};
@@ -17,7 +18,7 @@ use crate::stream::{ArrayStream, ArrayStreamAdapter};
 use crate::validity::Validity::NonNullable;
 use crate::validity::{ArrayValidity, LogicalValidity};
 use crate::visitor::{AcceptArrayVisitor, ArrayVisitor};
-use crate::{impl_encoding, ArrayDType};
+use crate::{impl_encoding, ArrayDType, IntoCanonical};
 
 mod canonical;
 mod compute;
@@ -96,9 +97,58 @@ impl ChunkedArray {
         ArrayIteratorAdapter::new(self.dtype().clone(), self.chunks().map(Ok))
     }
 
+    pub fn into_array_iterator(self) -> impl ArrayIterator {
+        let chunks = self.chunks().collect::<Vec<_>>();
+        ArrayIteratorAdapter::new(self.dtype().clone(), chunks.into_iter().map(Ok))
+    }
+
     pub fn array_stream(&self) -> impl ArrayStream + '_ {
         ArrayStreamAdapter::new(self.dtype().clone(), stream::iter(self.chunks().map(Ok)))
     }
+
+    /// Split big chunks and merge small ones so that every chunk but the last holds `chunk_len`
+    /// rows. Chunks that already fit are kept as they are, merged chunks are canonicalized.
+    pub fn rechunk(&self, chunk_len: usize) -> VortexResult<Self> {
+        if chunk_len == 0 {
+            vortex_bail!("Chunks must hold at least one row");
+        }
+
+        let mut chunks = Vec::new();
+        let mut pending = Vec::new();
+        let mut pending_len = 0;
+        for chunk in self.chunks() {
+            let mut offset = 0;
+            while offset < chunk.len() {
+                let end = chunk.len().min(offset + chunk_len - pending_len);
+                pending.push(if offset == 0 && end == chunk.len() {
+                    chunk.clone()
+                } else {
+                    slice(&chunk, offset, end)?
+                });
+                pending_len += end - offset;
+                offset = end;
+
+                if pending_len == chunk_len {
+                    chunks.push(self.merge(std::mem::take(&mut pending))?);
+                    pending_len = 0;
+                }
+            }
+        }
+        if !pending.is_empty() {
+            chunks.push(self.merge(pending)?);
+        }
+
+        Self::try_new(chunks, self.dtype().clone())
+    }
+
+    fn merge(&self, mut chunks: Vec<Array>) -> VortexResult<Array> {
+        if chunks.len() == 1 {
+            return Ok(chunks.remove(0));
+        }
+        Ok(Self::try_new(chunks, self.dtype().clone())?
+            .into_canonical()?
+            .into_array())
+    }
 }
 
 impl FromIterator<Array> for ChunkedArray {
@@ -159,9 +209,10 @@ mod test {
     use vortex_dtype::{NativePType, PType};
 
     use crate::array::chunked::ChunkedArray;
+    use crate::array::primitive::Primitive;
     use crate::compute::slice::slice;
     use crate::compute::unary::scalar_subtract::subtract_scalar;
-    use crate::{Array, IntoArray, IntoArrayVariant, IntoCanonical, ToArray};
+    use crate::{Array, ArrayDef, IntoArray, IntoArrayVariant, IntoCanonical, ToArray};
 
     fn chunked_array() -> ChunkedArray {
         ChunkedArray::try_new(
@@ -213,6 +264,21 @@ mod test {
         assert_equal_slices(slice(chunked_array().array(), 7, 8).unwrap(), &[8u64]);
     }
 
+    #[test]
+    fn rechunk() {
+        let lens = |chunked: ChunkedArray| chunked.chunks().map(|c| c.len()).collect::<Vec<_>>();
+        let array = chunked_array();
+        assert_eq!(lens(array.rechunk(2).unwrap()), vec![2, 2, 2, 2, 1]);
+        assert_eq!(lens(array.rechunk(4).unwrap()), vec![4, 4, 1]);
+        assert_eq!(lens(array.rechunk(3).unwrap()), vec![3, 3, 3]);
+        assert_eq!(lens(array.rechunk(100).unwrap()), vec![9]);
+        assert!(array.rechunk(0).is_err());
+
+        let rechunked = array.rechunk(4).unwrap();
+        assert_eq!(rechunked.chunk(0).unwrap().encoding().id(), Primitive::ID);
+        assert_equal_slices(rechunked.into_array(), &[1u64, 2, 3, 4, 5, 6, 7, 8, 9]);
+    }
+
     #[test]
     fn test_scalar_subtract() {
         let chunked = chunked_array();
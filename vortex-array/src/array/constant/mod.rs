@@ -76,4 +76,8 @@ impl ArrayTrait for ConstantArray {
     }
 }
 
-impl EncodingCompression for ConstantEncoding {}
+impl EncodingCompression for ConstantEncoding {
+    fn decode_cost(&self) -> f32 {
+        0.1
+    }
+}
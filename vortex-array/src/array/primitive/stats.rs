@@ -4,7 +4,7 @@ use std::mem::size_of;
 use arrow_buffer::buffer::BooleanBuffer;
 use num_traits::PrimInt;
 use vortex_dtype::half::f16;
-use vortex_dtype::{match_each_native_ptype, NativePType};
+use vortex_dtype::{match_each_native_ptype, NativePType, ToBytes};
 use vortex_error::VortexResult;
 use vortex_scalar::Scalar;
 
@@ -35,10 +35,15 @@ impl ArrayStatisticsCompute for PrimitiveArray {
 }
 
 impl<T: PStatsType> ArrayStatisticsCompute for &[T] {
-    fn compute_statistics(&self, _stat: Stat) -> VortexResult<StatsSet> {
+    fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
         if self.is_empty() {
             return Ok(StatsSet::new());
         }
+        if is_distinct_count(stat) {
+            return Ok(StatsSet::distinct_count(
+                self.iter().map(ToBytes::to_le_bytes).collect(),
+            ));
+        }
         let mut stats = StatsAccumulator::new(self[0]);
         self.iter().skip(1).for_each(|next| stats.next(*next));
         Ok(stats.into_map())
@@ -48,11 +53,21 @@ impl<T: PStatsType> ArrayStatisticsCompute for &[T] {
 struct NullableValues<'a, T: PStatsType>(&'a [T], &'a BooleanBuffer);
 
 impl<'a, T: PStatsType> ArrayStatisticsCompute for NullableValues<'a, T> {
-    fn compute_statistics(&self, _stat: Stat) -> VortexResult<StatsSet> {
+    fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
         let values = self.0;
         if values.is_empty() {
             return Ok(StatsSet::new());
         }
+        if is_distinct_count(stat) {
+            return Ok(StatsSet::distinct_count(
+                values
+                    .iter()
+                    .zip(self.1.iter())
+                    .filter(|(_, valid)| *valid)
+                    .map(|(value, _)| value.to_le_bytes())
+                    .collect(),
+            ));
+        }
 
         let first_non_null_idx = self
             .1
@@ -77,6 +92,12 @@ impl<'a, T: PStatsType> ArrayStatisticsCompute for NullableValues<'a, T> {
     }
 }
 
+/// Hashing every value is comparatively expensive, so the distinct count is only computed when
+/// it is explicitly requested.
+fn is_distinct_count(stat: Stat) -> bool {
+    matches!(stat, Stat::DistinctCount | Stat::DistinctCountSketch)
+}
+
 trait BitWidth {
     fn bit_width(self) -> u32;
     fn trailing_zeros(self) -> u32;
@@ -276,6 +297,16 @@ mod test {
         assert!(is_strict_sorted);
     }
 
+    #[test]
+    fn distinct_count() {
+        let arr = PrimitiveArray::from((0..1000).map(|i| i % 100).collect::<Vec<i64>>());
+        let distinct_count = arr.statistics().compute_distinct_count().unwrap();
+        assert!((90..=110).contains(&distinct_count), "{distinct_count}");
+
+        let arr = PrimitiveArray::from_nullable_vec(vec![None, Some(1i32), Some(1), None, Some(2)]);
+        assert_eq!(arr.statistics().compute_distinct_count(), Some(2));
+    }
+
     #[test]
     fn all_null() {
         let arr = PrimitiveArray::from_nullable_vec(vec![Option::<i32>::None, None, None]);
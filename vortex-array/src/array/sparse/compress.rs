@@ -9,6 +9,10 @@ impl EncodingCompression for SparseEncoding {
         0
     }
 
+    fn decode_cost(&self) -> f32 {
+        0.5
+    }
+
     fn can_compress(
         &self,
         array: &Array,
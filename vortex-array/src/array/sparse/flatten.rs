@@ -53,13 +53,16 @@ fn canonicalize_sparse_bools(
         fill_value.try_into()?
     };
     let mut flat_bools = vec![fill_bool; len];
-    for idx in indices {
-        flat_bools[*idx] = values.value(*idx);
+    for (value, idx) in values.iter().zip_eq(indices) {
+        flat_bools[*idx] = value;
         validity.set_bit(*idx, true);
     }
 
-    let validity = Validity::from(validity.finish());
-    let bool_values = BoolArray::from_vec(flat_bools, validity);
+    let bool_values = if fill_value.is_null() {
+        BoolArray::from_vec(flat_bools, Validity::from(validity.finish()))
+    } else {
+        BoolArray::from(flat_bools)
+    };
 
     Ok(Canonical::Bool(bool_values))
 }
@@ -99,6 +102,7 @@ mod test {
     use vortex_dtype::{DType, Nullability};
 
     use crate::array::bool::BoolArray;
+    use crate::array::constant::ConstantArray;
     use crate::array::sparse::SparseArray;
     use crate::validity::Validity;
     use crate::{ArrayDType, Canonical, IntoArray, IntoCanonical};
@@ -112,4 +116,17 @@ mod test {
         let flat_bools = sparse_bools.into_canonical().unwrap();
         assert!(matches!(flat_bools, Canonical::Bool(_)));
     }
+
+    #[test]
+    fn test_sparse_bool_constant_values() {
+        let indices = vec![2u64, 7].into_array();
+        let values = ConstantArray::new(false, 2).into_array();
+        let sparse_bools = SparseArray::try_new(indices, values, 10, true.into()).unwrap();
+        let flat_bools = sparse_bools.into_canonical().unwrap().into_bool().unwrap();
+        assert_eq!(flat_bools.dtype(), &DType::Bool(Nullability::NonNullable));
+        assert_eq!(
+            flat_bools.boolean_buffer().iter().collect::<Vec<_>>(),
+            (0..10).map(|i| i != 2 && i != 7).collect::<Vec<_>>()
+        );
+    }
 }
@@ -1,12 +1,14 @@
+use itertools::Itertools;
 use serde::{Deserialize, Serialize};
+use vortex_dtype::field::{Field, FieldPath};
 use vortex_dtype::{FieldName, FieldNames, Nullability, StructDType};
-use vortex_error::vortex_bail;
+use vortex_error::{vortex_bail, vortex_err};
 
 use crate::stats::ArrayStatisticsCompute;
 use crate::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
 use crate::visitor::{AcceptArrayVisitor, ArrayVisitor};
 use crate::{impl_encoding, ArrayDType};
-use crate::{Canonical, IntoCanonical};
+use crate::{Canonical, IntoArrayVariant, IntoCanonical};
 
 mod compute;
 
@@ -120,8 +122,6 @@ impl StructArray {
 }
 
 impl StructArray {
-    // TODO(aduffy): Add equivalent function to support field masks for nested column access.
-
     /// Return a new StructArray with the given projection applied.
     ///
     /// Projection does not copy data arrays. Projection is defined by an ordinal array slice
@@ -151,6 +151,105 @@ impl StructArray {
             self.validity(),
         )
     }
+
+    /// Return a new StructArray containing only the fields referenced by the given field paths.
+    ///
+    /// Unlike [`project`](Self::project), nesting is preserved: a path into a nested struct
+    /// field keeps that field, pruned down to the referenced children, so unreferenced sibling
+    /// fields are never touched. A path that ends at a field keeps that field in its entirety.
+    /// Top-level fields appear in the order they are first referenced.
+    ///
+    /// The projected fields keep their encodings: the validity of each struct is carried by the
+    /// corresponding struct of the projection rather than applied to its fields.
+    pub fn project_paths(&self, paths: &[FieldPath]) -> VortexResult<Self> {
+        if paths.iter().any(|path| path.path().is_empty()) {
+            return Ok(self.clone());
+        }
+
+        let resolved = paths
+            .iter()
+            .map(|path| {
+                let (head, tail) = path.path().split_first().expect("non-empty path");
+                self.field_index(head).map(|idx| (idx, tail))
+            })
+            .collect::<VortexResult<Vec<_>>>()?;
+        let field_order = resolved.iter().map(|(idx, _)| *idx).unique().collect_vec();
+        let grouped = resolved.into_iter().into_group_map();
+
+        let mut names = Vec::with_capacity(field_order.len());
+        let mut children = Vec::with_capacity(field_order.len());
+        for field_idx in field_order {
+            let field = self.field(field_idx).expect("field index in bounds");
+            let subpaths: Vec<FieldPath> = grouped[&field_idx]
+                .iter()
+                .map(|tail| FieldPath::from(tail.to_vec()))
+                .collect();
+
+            // A path ending at this field references all of its children.
+            let child = if subpaths.iter().any(|path| path.path().is_empty()) {
+                field
+            } else {
+                field.into_struct()?.project_paths(&subpaths)?.into_array()
+            };
+
+            names.push(self.names()[field_idx].clone());
+            children.push(child);
+        }
+
+        StructArray::try_new(
+            FieldNames::from(names.as_slice()),
+            children,
+            self.len(),
+            self.validity(),
+        )
+    }
+
+    /// Return the (possibly nested) field referenced by the given field path.
+    ///
+    /// The returned field is null wherever any of the structs along the path is null. Applying
+    /// the validity of nullable structs decodes the field, see
+    /// [`field_and_validity_by_path`](Self::field_and_validity_by_path) to keep it encoded.
+    pub fn field_by_path(&self, path: &FieldPath) -> VortexResult<Array> {
+        let (field, validity) = self.field_and_validity_by_path(path)?;
+        validity.mask(field)
+    }
+
+    /// Return the (possibly nested) field referenced by the given field path as stored, together
+    /// with the combined validity of the structs along the path.
+    ///
+    /// The field keeps its encoding and statistics. Logically it is null wherever the returned
+    /// validity is, which [`Validity::mask`] applies once the field is decoded.
+    pub fn field_and_validity_by_path(&self, path: &FieldPath) -> VortexResult<(Array, Validity)> {
+        let Some((head, tail)) = path.path().split_first() else {
+            return Ok((self.clone().into_array(), Validity::NonNullable));
+        };
+
+        let field = self
+            .field(self.field_index(head)?)
+            .expect("field index in bounds");
+        if tail.is_empty() {
+            return Ok((field, self.validity()));
+        }
+
+        let (field, validity) = field
+            .into_struct()?
+            .field_and_validity_by_path(&FieldPath::from(tail.to_vec()))?;
+        Ok((field, self.validity().and(&validity)?))
+    }
+
+    fn field_index(&self, field: &Field) -> VortexResult<usize> {
+        match field {
+            Field::Name(name) => self
+                .names()
+                .iter()
+                .position(|field_name| field_name.as_ref() == name)
+                .ok_or_else(|| vortex_err!("Struct has no field named {}", name)),
+            Field::Index(idx) => usize::try_from(*idx)
+                .ok()
+                .filter(|idx| *idx < self.nfields())
+                .ok_or_else(|| vortex_err!("Struct field index {} out of bounds", idx)),
+        }
+    }
 }
 
 impl IntoCanonical for StructArray {
@@ -192,14 +291,16 @@ impl EncodingCompression for StructEncoding {}
 
 #[cfg(test)]
 mod test {
+    use vortex_dtype::field::{Field, FieldPath};
     use vortex_dtype::{DType, FieldName, FieldNames, Nullability};
 
     use crate::array::bool::BoolArray;
+    use crate::array::constant::{Constant, ConstantArray};
     use crate::array::primitive::PrimitiveArray;
     use crate::array::struct_::StructArray;
     use crate::array::varbin::VarBinArray;
-    use crate::validity::Validity;
-    use crate::{ArrayTrait, IntoArray};
+    use crate::validity::{ArrayValidity, Validity};
+    use crate::{ArrayTrait, IntoArray, IntoArrayVariant};
 
     #[test]
     fn test_project() {
@@ -238,4 +339,129 @@ mod test {
             vec![0i64, 1, 2, 3, 4]
         );
     }
+
+    #[test]
+    fn test_project_paths() {
+        let xs = PrimitiveArray::from_vec(vec![0i64, 1, 2], Validity::NonNullable);
+        let ys = BoolArray::from_vec(vec![true, false, true], Validity::NonNullable);
+        let zs = PrimitiveArray::from_vec(vec![10u32, 11, 12], Validity::NonNullable);
+
+        let inner = StructArray::from_fields(&[("x", xs.into_array()), ("y", ys.into_array())]);
+        let outer = StructArray::from_fields(&[("a", inner.into_array()), ("z", zs.into_array())]);
+
+        let projected = outer
+            .project_paths(&[FieldPath::from(vec![Field::from("a"), Field::from("x")])])
+            .unwrap();
+        assert_eq!(projected.names().to_vec(), vec![FieldName::from("a")]);
+        let nested = projected.field(0).unwrap().into_struct().unwrap();
+        assert_eq!(nested.names().to_vec(), vec![FieldName::from("x")]);
+        assert_eq!(nested.len(), 3);
+
+        // A path ending at a struct field keeps all of its children.
+        let projected = outer
+            .project_paths(&[
+                FieldPath::from_name("z"),
+                FieldPath::from(vec![Field::from("a"), Field::from("y")]),
+                FieldPath::from_name("a"),
+            ])
+            .unwrap();
+        assert_eq!(
+            projected.names().to_vec(),
+            vec![FieldName::from("z"), FieldName::from("a")]
+        );
+        assert_eq!(
+            projected.field(1).unwrap().into_struct().unwrap().nfields(),
+            2
+        );
+
+        let ys = outer
+            .field_by_path(&FieldPath::from(vec![Field::from("a"), Field::from(1)]))
+            .unwrap()
+            .into_bool()
+            .unwrap();
+        assert_eq!(
+            ys.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![true, false, true]
+        );
+
+        assert!(outer.project_paths(&[FieldPath::from_name("w")]).is_err());
+    }
+
+    #[test]
+    fn test_nested_fields_of_null_structs() {
+        let xs = PrimitiveArray::from_vec(vec![0i64, 1, 2], Validity::NonNullable);
+        let inner = StructArray::try_new(
+            FieldNames::from([FieldName::from("x")]),
+            vec![xs.into_array()],
+            3,
+            Validity::from(vec![true, false, true]),
+        )
+        .unwrap();
+        let outer = StructArray::try_new(
+            FieldNames::from([FieldName::from("a")]),
+            vec![inner.into_array()],
+            3,
+            Validity::from(vec![true, true, false]),
+        )
+        .unwrap();
+        let path = FieldPath::from(vec![Field::from("a"), Field::from("x")]);
+
+        let xs = outer.field_by_path(&path).unwrap();
+        assert!(xs.with_dyn(|a| a.dtype().is_nullable()));
+        assert_eq!(
+            (0..3)
+                .map(|i| xs.with_dyn(|a| a.is_valid(i)))
+                .collect::<Vec<_>>(),
+            vec![true, false, false]
+        );
+
+        // Projections keep the validity of each struct with the struct itself.
+        let projected = outer.project_paths(&[path]).unwrap();
+        assert_eq!(
+            (0..3).map(|i| projected.is_valid(i)).collect::<Vec<_>>(),
+            vec![true, true, false]
+        );
+        let inner = projected.field(0).unwrap().into_struct().unwrap();
+        assert_eq!(
+            (0..3).map(|i| inner.is_valid(i)).collect::<Vec<_>>(),
+            vec![true, false, true]
+        );
+    }
+
+    #[test]
+    fn test_nested_fields_of_null_structs_stay_encoded() {
+        let xs = ConstantArray::new(5i64, 3).into_array();
+        let inner = StructArray::try_new(
+            FieldNames::from([FieldName::from("x")]),
+            vec![xs],
+            3,
+            Validity::from(vec![true, false, true]),
+        )
+        .unwrap();
+        let outer = StructArray::try_new(
+            FieldNames::from([FieldName::from("a")]),
+            vec![inner.into_array()],
+            3,
+            Validity::AllValid,
+        )
+        .unwrap();
+        let path = FieldPath::from(vec![Field::from("a"), Field::from("x")]);
+
+        let projected = outer.project_paths(&[path.clone()]).unwrap();
+        let xs = projected
+            .field(0)
+            .unwrap()
+            .into_struct()
+            .unwrap()
+            .field(0)
+            .unwrap();
+        assert_eq!(xs.encoding().id(), Constant::ID);
+
+        let (xs, validity) = outer.field_and_validity_by_path(&path).unwrap();
+        assert_eq!(xs.encoding().id(), Constant::ID);
+        assert_eq!(
+            (0..3).map(|i| validity.is_valid(i)).collect::<Vec<_>>(),
+            vec![true, false, true]
+        );
+    }
 }
@@ -1,6 +1,6 @@
 use num_traits::AsPrimitive;
 use serde::{Deserialize, Serialize};
-pub use stats::compute_stats;
+pub use stats::{compute_stats, lower_bound, upper_bound, MAX_BOUND_LENGTH};
 use vortex_buffer::Buffer;
 use vortex_dtype::Nullability;
 use vortex_dtype::{match_each_native_ptype, NativePType};
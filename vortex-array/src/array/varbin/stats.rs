@@ -10,14 +10,21 @@ use crate::stats::{ArrayStatisticsCompute, Stat, StatsSet};
 use crate::{ArrayDType, ArrayTrait};
 
 impl ArrayStatisticsCompute for VarBinArray {
-    fn compute_statistics(&self, _stat: Stat) -> VortexResult<StatsSet> {
+    fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
         if self.is_empty() {
             return Ok(StatsSet::new());
         }
+        if matches!(stat, Stat::DistinctCount | Stat::DistinctCountSketch) {
+            return self.with_iterator(|iter| StatsSet::distinct_count(iter.flatten().collect()));
+        }
         self.with_iterator(|iter| compute_stats(iter, self.dtype()))
     }
 }
 
+/// Min and max values longer than this many bytes are stored as bounds of at most this length,
+/// flagged by [`Stat::IsMinTruncated`] and [`Stat::IsMaxTruncated`].
+pub const MAX_BOUND_LENGTH: usize = 64;
+
 pub fn compute_stats(iter: &mut dyn Iterator<Item = Option<&[u8]>>, dtype: &DType) -> StatsSet {
     let mut leading_nulls: usize = 0;
     let mut first_value: Option<&[u8]> = None;
@@ -84,7 +91,10 @@ impl<'a> VarBinAccumulator<'a> {
         }
 
         match val.cmp(self.last_value) {
-            Ordering::Less => self.is_sorted = false,
+            Ordering::Less => {
+                self.is_sorted = false;
+                self.is_strict_sorted = false;
+            }
             Ordering::Equal => {
                 self.is_strict_sorted = false;
                 return;
@@ -97,9 +107,13 @@ impl<'a> VarBinAccumulator<'a> {
     }
 
     pub fn finish(&self, dtype: &DType) -> StatsSet {
+        let (min, is_min_truncated) = lower_bound(self.min, dtype);
+        let (max, is_max_truncated) = upper_bound(self.max, dtype);
         StatsSet::from(HashMap::from([
-            (Stat::Min, varbin_scalar(self.min.to_vec(), dtype)),
-            (Stat::Max, varbin_scalar(self.max.to_vec(), dtype)),
+            (Stat::Min, varbin_scalar(min, dtype)),
+            (Stat::Max, varbin_scalar(max, dtype)),
+            (Stat::IsMinTruncated, is_min_truncated.into()),
+            (Stat::IsMaxTruncated, is_max_truncated.into()),
             (Stat::RunCount, self.runs.into()),
             (Stat::IsSorted, self.is_sorted.into()),
             (Stat::IsStrictSorted, self.is_strict_sorted.into()),
@@ -109,6 +123,65 @@ impl<'a> VarBinAccumulator<'a> {
     }
 }
 
+/// A prefix of at most [`MAX_BOUND_LENGTH`] bytes of the value, and whether it was truncated.
+pub fn lower_bound(value: &[u8], dtype: &DType) -> (Vec<u8>, bool) {
+    if value.len() <= MAX_BOUND_LENGTH {
+        return (value.to_vec(), false);
+    }
+    (value[..prefix_length(value, dtype)].to_vec(), true)
+}
+
+/// The smallest value of at most [`MAX_BOUND_LENGTH`] bytes that is greater than all values
+/// starting with the prefix of the value, and whether it was truncated. Values without such a
+/// bound, e.g. those consisting of `0xFF` bytes, are kept as they are.
+pub fn upper_bound(value: &[u8], dtype: &DType) -> (Vec<u8>, bool) {
+    if value.len() <= MAX_BOUND_LENGTH {
+        return (value.to_vec(), false);
+    }
+    match increment(&value[..prefix_length(value, dtype)], dtype) {
+        Some(bound) => (bound, true),
+        None => (value.to_vec(), false),
+    }
+}
+
+/// The length of the prefix used as a bound, which must not split a UTF-8 character.
+fn prefix_length(value: &[u8], dtype: &DType) -> usize {
+    let mut length = MAX_BOUND_LENGTH;
+    if matches!(dtype, DType::Utf8(_)) {
+        // Continuation bytes of multi-byte characters are of the form 0b10xxxxxx.
+        while length > 0 && value[length] & 0xC0 == 0x80 {
+            length -= 1;
+        }
+    }
+    length
+}
+
+/// Increment the last character or byte of the prefix that can be incremented, dropping any
+/// after it.
+fn increment(prefix: &[u8], dtype: &DType) -> Option<Vec<u8>> {
+    if matches!(dtype, DType::Utf8(_)) {
+        let mut prefix = String::from_utf8(prefix.to_vec()).ok()?;
+        while let Some(c) = prefix.pop() {
+            // Skip over the surrogate code points, which aren't valid characters.
+            let next =
+                char::from_u32(c as u32 + 1).or_else(|| (c == '\u{D7FF}').then_some('\u{E000}'));
+            if let Some(next) = next {
+                prefix.push(next);
+                return Some(prefix.into_bytes());
+            }
+        }
+    } else {
+        let mut prefix = prefix.to_vec();
+        while let Some(b) = prefix.pop() {
+            if b < u8::MAX {
+                prefix.push(b + 1);
+                return Some(prefix);
+            }
+        }
+    }
+    None
+}
+
 #[cfg(test)]
 mod test {
     use std::ops::Deref;
@@ -188,4 +261,73 @@ mod test {
         assert!(array.statistics().get(Stat::Min).is_none());
         assert!(array.statistics().get(Stat::Max).is_none());
     }
+
+    #[test]
+    fn distinct_count() {
+        let array = VarBinArray::from_iter(
+            vec![Some("a"), None, Some("b"), Some("a"), None],
+            DType::Utf8(Nullability::Nullable),
+        );
+        assert_eq!(array.statistics().compute_distinct_count(), Some(2));
+        assert!(array.statistics().get(Stat::DistinctCountSketch).is_some());
+    }
+
+    #[test]
+    fn truncated_bounds() {
+        let array = VarBinArray::from_vec(
+            vec![
+                "b".repeat(100),
+                format!("{}{}", "c".repeat(63), "é".repeat(10)),
+            ],
+            DType::Utf8(Nullability::NonNullable),
+        );
+        assert_eq!(
+            array.statistics().compute_min::<BufferString>().unwrap(),
+            BufferString::from("b".repeat(64))
+        );
+        // The bound doesn't split the two bytes of the first "é".
+        assert_eq!(
+            array.statistics().compute_max::<BufferString>().unwrap(),
+            BufferString::from(format!("{}d", "c".repeat(62)))
+        );
+        assert_eq!(
+            array.statistics().get_as::<bool>(Stat::IsMinTruncated),
+            Some(true)
+        );
+        assert_eq!(
+            array.statistics().get_as::<bool>(Stat::IsMaxTruncated),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn untruncatable_max() {
+        let array = VarBinArray::from_vec(
+            vec![vec![0xFFu8; 100]],
+            DType::Binary(Nullability::NonNullable),
+        );
+        assert_eq!(
+            array.statistics().compute_min::<Buffer>().unwrap().deref(),
+            &[0xFF; 64]
+        );
+        assert_eq!(
+            array.statistics().compute_max::<Buffer>().unwrap().deref(),
+            &[0xFF; 100]
+        );
+        assert_eq!(
+            array.statistics().get_as::<bool>(Stat::IsMinTruncated),
+            Some(true)
+        );
+        assert_eq!(
+            array.statistics().get_as::<bool>(Stat::IsMaxTruncated),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn unsorted() {
+        let array = VarBinArray::from(vec!["b", "a"]);
+        assert!(!array.statistics().compute_is_sorted().unwrap());
+        assert!(!array.statistics().compute_is_strict_sorted().unwrap());
+    }
 }
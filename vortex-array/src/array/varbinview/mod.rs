@@ -13,7 +13,7 @@ use vortex_error::vortex_bail;
 use crate::array::primitive::PrimitiveArray;
 use crate::array::varbin::VarBinArray;
 use crate::array::varbinview::builder::VarBinViewBuilder;
-use crate::arrow::FromArrowArray;
+use crate::arrow::TryFromArrowArray;
 use crate::compute::slice::slice;
 use crate::validity::Validity;
 use crate::validity::{ArrayValidity, LogicalValidity, ValidityMetadata};
@@ -231,7 +231,7 @@ impl IntoCanonical for VarBinViewArray {
         let arrow_self = as_arrow(self);
         let arrow_varbin = arrow_cast::cast(arrow_self.deref(), &DataType::Utf8)
             .expect("Utf8View must cast to Ut8f");
-        let vortex_array = ArrayData::from_arrow(arrow_varbin, nullable).into_array();
+        let vortex_array = ArrayData::try_from_arrow(arrow_varbin, nullable)?.into_array();
 
         Ok(Canonical::VarBin(VarBinArray::try_from(&vortex_array)?))
     }
@@ -360,7 +360,11 @@ impl<'a> FromIterator<Option<&'a str>> for VarBinViewArray {
     }
 }
 
-impl EncodingCompression for VarBinViewEncoding {}
+impl EncodingCompression for VarBinViewEncoding {
+    fn decode_cost(&self) -> f32 {
+        0.5
+    }
+}
 
 #[cfg(test)]
 mod test {
@@ -7,10 +7,13 @@ use crate::stats::{ArrayStatisticsCompute, Stat, StatsSet};
 use crate::{ArrayDType, ArrayTrait};
 
 impl ArrayStatisticsCompute for VarBinViewArray {
-    fn compute_statistics(&self, _stat: Stat) -> VortexResult<StatsSet> {
+    fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
         if self.is_empty() {
             return Ok(StatsSet::new());
         }
+        if matches!(stat, Stat::DistinctCount | Stat::DistinctCountSketch) {
+            return self.with_iterator(|iter| StatsSet::distinct_count(iter.flatten().collect()));
+        }
         self.with_iterator(|iter| compute_stats(iter, self.dtype()))
     }
 }
@@ -4,36 +4,39 @@ use arrow_array::array::{
     StructArray as ArrowStructArray,
 };
 use arrow_array::array::{ArrowPrimitiveType, OffsetSizeTrait};
-use arrow_array::cast::{as_null_array, AsArray};
+use arrow_array::cast::{as_null_array, as_run_array, AsArray};
+use arrow_array::types::RunEndIndexType;
 use arrow_array::types::{
-    ByteArrayType, ByteViewType, Date32Type, Date64Type, DurationMicrosecondType,
-    DurationMillisecondType, DurationNanosecondType, DurationSecondType, Time32MillisecondType,
-    Time32SecondType, Time64MicrosecondType, Time64NanosecondType, TimestampMicrosecondType,
-    TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType,
+    ByteArrayType, ByteViewType, TimestampMicrosecondType, TimestampMillisecondType,
+    TimestampNanosecondType, TimestampSecondType,
 };
 use arrow_array::types::{
     Float16Type, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type, UInt16Type,
     UInt32Type, UInt64Type, UInt8Type,
 };
-use arrow_array::{BinaryViewArray, GenericByteViewArray, StringViewArray};
+use arrow_array::{FixedSizeBinaryArray, GenericByteViewArray, RunArray};
 use arrow_buffer::buffer::{NullBuffer, OffsetBuffer};
 use arrow_buffer::{ArrowNativeType, Buffer, ScalarBuffer};
-use arrow_schema::{DataType, TimeUnit};
+use arrow_schema::{DataType, Field, TimeUnit};
 use itertools::Itertools;
 use vortex_dtype::DType;
 use vortex_dtype::NativePType;
+use vortex_error::{vortex_bail, VortexResult};
 
 use crate::array::bool::BoolArray;
 use crate::array::datetime::LocalDateTimeArray;
+use crate::array::extension::ExtensionArray;
 use crate::array::null::NullArray;
 use crate::array::primitive::PrimitiveArray;
 use crate::array::struct_::StructArray;
 use crate::array::varbin::VarBinArray;
 use crate::array::varbinview::VarBinViewArray;
-use crate::arrow::FromArrowArray;
+use crate::arrow::dtype::extension_dtype;
+use crate::arrow::TryFromArrowArray;
+use crate::compute::take::take;
 use crate::stats::{Stat, Statistics};
 use crate::validity::Validity;
-use crate::{ArrayData, IntoArray, IntoArrayData};
+use crate::{Array, ArrayData, IntoArray, IntoArrayData};
 
 impl IntoArrayData for Buffer {
     fn into_array_data(self) -> ArrayData {
@@ -82,68 +85,61 @@ impl<O: NativePType + OffsetSizeTrait> IntoArrayData for OffsetBuffer<O> {
     }
 }
 
-impl<T: ArrowPrimitiveType> FromArrowArray<&ArrowPrimitiveArray<T>> for ArrayData
+impl<T: ArrowPrimitiveType> TryFromArrowArray<&ArrowPrimitiveArray<T>> for ArrayData
 where
     <T as ArrowPrimitiveType>::Native: NativePType,
 {
-    fn from_arrow(value: &ArrowPrimitiveArray<T>, nullable: bool) -> Self {
-        let arr = PrimitiveArray::try_new(value.values().clone(), nulls(value.nulls(), nullable))
-            .unwrap()
+    fn try_from_arrow(value: &ArrowPrimitiveArray<T>, nullable: bool) -> VortexResult<Self> {
+        let arr = PrimitiveArray::try_new(value.values().clone(), nulls(value.nulls(), nullable)?)?
             .into_array_data();
 
         if T::DATA_TYPE.is_numeric() {
-            return arr;
+            return Ok(arr);
         }
 
         match T::DATA_TYPE {
             DataType::Timestamp(time_unit, tz) => match tz {
                 // A timestamp with no timezone is the equivalent of an "unknown" timezone.
                 // Therefore, we must treat it as a LocalDateTime and not an Instant.
-                None => LocalDateTimeArray::try_new((&time_unit).into(), arr.into_array())
-                    .expect("Invalid LocalDateTimeArray")
-                    .into_array_data(),
-                Some(_tz) => todo!(),
+                None => Ok(
+                    LocalDateTimeArray::try_new((&time_unit).into(), arr.into_array())?
+                        .into_array_data(),
+                ),
+                Some(tz) => vortex_bail!("Timestamps with timezone {} are not supported", tz),
             },
-            DataType::Date32 => todo!(),
-            DataType::Date64 => todo!(),
-            DataType::Time32(_) => todo!(),
-            DataType::Time64(_) => todo!(),
-            DataType::Duration(_) => todo!(),
-            DataType::Interval(_) => todo!(),
-            _ => panic!("Invalid data type for PrimitiveArray"),
+            dt => vortex_bail!("Arrow data type {} is not supported", dt),
         }
     }
 }
 
-impl<T: ByteArrayType> FromArrowArray<&GenericByteArray<T>> for ArrayData
+impl<T: ByteArrayType> TryFromArrowArray<&GenericByteArray<T>> for ArrayData
 where
     <T as ByteArrayType>::Offset: NativePType,
 {
-    fn from_arrow(value: &GenericByteArray<T>, nullable: bool) -> Self {
+    fn try_from_arrow(value: &GenericByteArray<T>, nullable: bool) -> VortexResult<Self> {
         let dtype = match T::DATA_TYPE {
             DataType::Binary | DataType::LargeBinary => DType::Binary(nullable.into()),
             DataType::Utf8 | DataType::LargeUtf8 => DType::Utf8(nullable.into()),
-            _ => panic!("Invalid data type for ByteArray"),
+            dt => vortex_bail!("Invalid data type {} for ByteArray", dt),
         };
-        VarBinArray::try_new(
+        Ok(VarBinArray::try_new(
             value.offsets().clone().into_array_data().into_array(),
             value.values().clone().into_array_data().into_array(),
             dtype,
-            nulls(value.nulls(), nullable),
-        )
-        .unwrap()
-        .into_array_data()
+            nulls(value.nulls(), nullable)?,
+        )?
+        .into_array_data())
     }
 }
 
-impl<T: ByteViewType> FromArrowArray<&GenericByteViewArray<T>> for ArrayData {
-    fn from_arrow(value: &GenericByteViewArray<T>, nullable: bool) -> Self {
+impl<T: ByteViewType> TryFromArrowArray<&GenericByteViewArray<T>> for ArrayData {
+    fn try_from_arrow(value: &GenericByteViewArray<T>, nullable: bool) -> VortexResult<Self> {
         let dtype = match T::DATA_TYPE {
             DataType::BinaryView => DType::Binary(nullable.into()),
             DataType::Utf8View => DType::Utf8(nullable.into()),
-            _ => panic!("Invalid data type for ByteViewArray"),
+            dt => vortex_bail!("Invalid data type {} for ByteViewArray", dt),
         };
-        VarBinViewArray::try_new(
+        Ok(VarBinViewArray::try_new(
             value.views().inner().clone().into_array_data().into_array(),
             value
                 .data_buffers()
@@ -151,56 +147,122 @@ impl<T: ByteViewType> FromArrowArray<&GenericByteViewArray<T>> for ArrayData {
                 .map(|b| b.clone().into_array_data().into_array())
                 .collect::<Vec<_>>(),
             dtype,
-            nulls(value.nulls(), nullable),
-        )
-        .unwrap()
-        .into_array_data()
+            nulls(value.nulls(), nullable)?,
+        )?
+        .into_array_data())
     }
 }
 
-impl FromArrowArray<&ArrowBooleanArray> for ArrayData {
-    fn from_arrow(value: &ArrowBooleanArray, nullable: bool) -> Self {
-        BoolArray::try_new(value.values().clone(), nulls(value.nulls(), nullable))
-            .unwrap()
-            .into_array_data()
+impl TryFromArrowArray<&FixedSizeBinaryArray> for ArrayData {
+    fn try_from_arrow(value: &FixedSizeBinaryArray, nullable: bool) -> VortexResult<Self> {
+        // The values of a sliced array start at the first element, so offsets always start at 0.
+        let width = value.value_length() as i64;
+        let offsets =
+            PrimitiveArray::from((0..=value.len() as i64).map(|i| i * width).collect_vec());
+        Ok(VarBinArray::try_new(
+            offsets.into_array(),
+            value.values().clone().into_array_data().into_array(),
+            DType::Binary(nullable.into()),
+            nulls(value.nulls(), nullable)?,
+        )?
+        .into_array_data())
     }
 }
 
-impl FromArrowArray<&ArrowStructArray> for ArrayData {
-    fn from_arrow(value: &ArrowStructArray, nullable: bool) -> Self {
-        // TODO(ngates): how should we deal with Arrow "logical nulls"?
-        assert!(!nullable);
-        StructArray::try_new(
-            value
-                .column_names()
-                .iter()
-                .map(|s| (*s).into())
-                .collect_vec()
-                .into(),
-            value
-                .columns()
-                .iter()
-                .zip(value.fields())
-                .map(|(c, field)| Self::from_arrow(c.clone(), field.is_nullable()).into_array())
-                .collect(),
-            value.len(),
-            nulls(value.nulls(), nullable),
+impl TryFromArrowArray<&ArrowBooleanArray> for ArrayData {
+    fn try_from_arrow(value: &ArrowBooleanArray, nullable: bool) -> VortexResult<Self> {
+        Ok(
+            BoolArray::try_new(value.values().clone(), nulls(value.nulls(), nullable)?)?
+                .into_array_data(),
         )
-        .unwrap()
-        .into_array_data()
     }
 }
 
-impl FromArrowArray<&ArrowNullArray> for ArrayData {
-    fn from_arrow(value: &ArrowNullArray, nullable: bool) -> Self {
-        assert!(nullable);
-        NullArray::new(value.len()).into_array_data()
+impl TryFromArrowArray<&ArrowStructArray> for ArrayData {
+    fn try_from_arrow(value: &ArrowStructArray, nullable: bool) -> VortexResult<Self> {
+        try_struct_from_arrow(value, nullable, |child, nullable| {
+            Self::try_from_arrow(child, nullable).map(IntoArray::into_array)
+        })
+        .map(IntoArrayData::into_array_data)
+    }
+}
+
+/// Convert an Arrow struct array, importing each of its columns with `import_child`.
+///
+/// This lets callers that can represent more encodings, e.g. dictionaries, keep them for nested
+/// columns too.
+pub fn try_struct_from_arrow(
+    value: &ArrowStructArray,
+    nullable: bool,
+    import_child: impl Fn(ArrowArrayRef, bool) -> VortexResult<Array>,
+) -> VortexResult<StructArray> {
+    StructArray::try_new(
+        value
+            .column_names()
+            .iter()
+            .map(|s| (*s).into())
+            .collect_vec()
+            .into(),
+        value
+            .columns()
+            .iter()
+            .zip(value.fields())
+            .map(|(c, field)| {
+                import_child(c.clone(), field.is_nullable())
+                    .map(|array| with_extension(field, array.into_array_data()).into_array())
+            })
+            .collect::<VortexResult<Vec<_>>>()?,
+        value.len(),
+        nulls(value.nulls(), nullable)?,
+    )
+}
+
+impl TryFromArrowArray<&ArrowNullArray> for ArrayData {
+    fn try_from_arrow(value: &ArrowNullArray, nullable: bool) -> VortexResult<Self> {
+        if !nullable {
+            vortex_bail!("Arrow null arrays must be nullable");
+        }
+        Ok(NullArray::new(value.len()).into_array_data())
     }
 }
 
-fn nulls(nulls: Option<&NullBuffer>, nullable: bool) -> Validity {
+/// Decode a run-end encoded array into its values, since there is no run-end array in this crate.
+///
+/// Use `REEArray` from the `vortex-ree` crate, in `encodings/runend`, to preserve the encoding.
+impl<R: RunEndIndexType> TryFromArrowArray<&RunArray<R>> for ArrayData {
+    fn try_from_arrow(value: &RunArray<R>, nullable: bool) -> VortexResult<Self> {
+        let run_ends = value.run_ends();
+        let mut indices = Vec::with_capacity(value.len());
+        let mut position = run_ends.offset();
+        for (run, end) in run_ends
+            .values()
+            .iter()
+            .enumerate()
+            .skip(run_ends.get_start_physical_index())
+        {
+            let end = end.as_usize().min(run_ends.offset() + run_ends.len());
+            indices.extend(std::iter::repeat(run as u64).take(end - position));
+            position = end;
+        }
+
+        let values = Self::try_from_arrow(value.values().clone(), nullable)?.into_array();
+        Ok(take(&values, &PrimitiveArray::from(indices).into_array())?.into_array_data())
+    }
+}
+
+/// Wrap an imported array in the extension dtype recorded in the metadata of its field, if any.
+pub(crate) fn with_extension(field: &Field, array: ArrayData) -> ArrayData {
+    match extension_dtype(field) {
+        Some(ext_dtype) if !matches!(array.dtype(), DType::Extension(ext, _) if ext == &ext_dtype) => {
+            ExtensionArray::new(ext_dtype, array.into_array()).into_array_data()
+        }
+        _ => array,
+    }
+}
+
+fn nulls(nulls: Option<&NullBuffer>, nullable: bool) -> VortexResult<Validity> {
     if nullable {
-        nulls
+        Ok(nulls
             .map(|nulls| {
                 if nulls.null_count() == nulls.len() {
                     Validity::AllInvalid
@@ -208,94 +270,180 @@ fn nulls(nulls: Option<&NullBuffer>, nullable: bool) -> Validity {
                     Validity::from(nulls.inner().clone())
                 }
             })
-            .unwrap_or_else(|| Validity::AllValid)
+            .unwrap_or_else(|| Validity::AllValid))
+    } else if nulls.map_or(false, |n| n.null_count() > 0) {
+        vortex_bail!("Non-nullable Arrow array contains nulls")
     } else {
-        assert!(nulls.is_none());
-        Validity::NonNullable
+        Ok(Validity::NonNullable)
     }
 }
 
-impl FromArrowArray<ArrowArrayRef> for ArrayData {
-    fn from_arrow(array: ArrowArrayRef, nullable: bool) -> Self {
+impl TryFromArrowArray<ArrowArrayRef> for ArrayData {
+    fn try_from_arrow(array: ArrowArrayRef, nullable: bool) -> VortexResult<Self> {
         match array.data_type() {
-            DataType::Boolean => Self::from_arrow(array.as_boolean(), nullable),
-            DataType::UInt8 => Self::from_arrow(array.as_primitive::<UInt8Type>(), nullable),
-            DataType::UInt16 => Self::from_arrow(array.as_primitive::<UInt16Type>(), nullable),
-            DataType::UInt32 => Self::from_arrow(array.as_primitive::<UInt32Type>(), nullable),
-            DataType::UInt64 => Self::from_arrow(array.as_primitive::<UInt64Type>(), nullable),
-            DataType::Int8 => Self::from_arrow(array.as_primitive::<Int8Type>(), nullable),
-            DataType::Int16 => Self::from_arrow(array.as_primitive::<Int16Type>(), nullable),
-            DataType::Int32 => Self::from_arrow(array.as_primitive::<Int32Type>(), nullable),
-            DataType::Int64 => Self::from_arrow(array.as_primitive::<Int64Type>(), nullable),
-            DataType::Float16 => Self::from_arrow(array.as_primitive::<Float16Type>(), nullable),
-            DataType::Float32 => Self::from_arrow(array.as_primitive::<Float32Type>(), nullable),
-            DataType::Float64 => Self::from_arrow(array.as_primitive::<Float64Type>(), nullable),
-            DataType::Utf8 => Self::from_arrow(array.as_string::<i32>(), nullable),
-            DataType::LargeUtf8 => Self::from_arrow(array.as_string::<i64>(), nullable),
-            DataType::Binary => Self::from_arrow(array.as_binary::<i32>(), nullable),
-            DataType::LargeBinary => Self::from_arrow(array.as_binary::<i64>(), nullable),
-            DataType::BinaryView => Self::from_arrow(
-                array.as_any().downcast_ref::<BinaryViewArray>().unwrap(),
-                nullable,
-            ),
-            DataType::Utf8View => Self::from_arrow(
-                array.as_any().downcast_ref::<StringViewArray>().unwrap(),
-                nullable,
-            ),
-            DataType::Struct(_) => Self::from_arrow(array.as_struct(), nullable),
-            DataType::Null => Self::from_arrow(as_null_array(&array), nullable),
+            DataType::Boolean => Self::try_from_arrow(array.as_boolean(), nullable),
+            DataType::UInt8 => Self::try_from_arrow(array.as_primitive::<UInt8Type>(), nullable),
+            DataType::UInt16 => Self::try_from_arrow(array.as_primitive::<UInt16Type>(), nullable),
+            DataType::UInt32 => Self::try_from_arrow(array.as_primitive::<UInt32Type>(), nullable),
+            DataType::UInt64 => Self::try_from_arrow(array.as_primitive::<UInt64Type>(), nullable),
+            DataType::Int8 => Self::try_from_arrow(array.as_primitive::<Int8Type>(), nullable),
+            DataType::Int16 => Self::try_from_arrow(array.as_primitive::<Int16Type>(), nullable),
+            DataType::Int32 => Self::try_from_arrow(array.as_primitive::<Int32Type>(), nullable),
+            DataType::Int64 => Self::try_from_arrow(array.as_primitive::<Int64Type>(), nullable),
+            DataType::Float16 => {
+                Self::try_from_arrow(array.as_primitive::<Float16Type>(), nullable)
+            }
+            DataType::Float32 => {
+                Self::try_from_arrow(array.as_primitive::<Float32Type>(), nullable)
+            }
+            DataType::Float64 => {
+                Self::try_from_arrow(array.as_primitive::<Float64Type>(), nullable)
+            }
+            DataType::Utf8 => Self::try_from_arrow(array.as_string::<i32>(), nullable),
+            DataType::LargeUtf8 => Self::try_from_arrow(array.as_string::<i64>(), nullable),
+            DataType::Binary => Self::try_from_arrow(array.as_binary::<i32>(), nullable),
+            DataType::LargeBinary => Self::try_from_arrow(array.as_binary::<i64>(), nullable),
+            DataType::BinaryView => Self::try_from_arrow(array.as_binary_view(), nullable),
+            DataType::Utf8View => Self::try_from_arrow(array.as_string_view(), nullable),
+            DataType::FixedSizeBinary(_) => {
+                Self::try_from_arrow(array.as_fixed_size_binary(), nullable)
+            }
+            DataType::Struct(_) => Self::try_from_arrow(array.as_struct(), nullable),
+            DataType::Null => Self::try_from_arrow(as_null_array(&array), nullable),
             DataType::Timestamp(u, _) => match u {
                 TimeUnit::Second => {
-                    Self::from_arrow(array.as_primitive::<TimestampSecondType>(), nullable)
+                    Self::try_from_arrow(array.as_primitive::<TimestampSecondType>(), nullable)
                 }
                 TimeUnit::Millisecond => {
-                    Self::from_arrow(array.as_primitive::<TimestampMillisecondType>(), nullable)
+                    Self::try_from_arrow(array.as_primitive::<TimestampMillisecondType>(), nullable)
                 }
                 TimeUnit::Microsecond => {
-                    Self::from_arrow(array.as_primitive::<TimestampMicrosecondType>(), nullable)
+                    Self::try_from_arrow(array.as_primitive::<TimestampMicrosecondType>(), nullable)
                 }
                 TimeUnit::Nanosecond => {
-                    Self::from_arrow(array.as_primitive::<TimestampNanosecondType>(), nullable)
+                    Self::try_from_arrow(array.as_primitive::<TimestampNanosecondType>(), nullable)
                 }
             },
-            DataType::Date32 => Self::from_arrow(array.as_primitive::<Date32Type>(), nullable),
-            DataType::Date64 => Self::from_arrow(array.as_primitive::<Date64Type>(), nullable),
-            DataType::Time32(u) => match u {
-                TimeUnit::Second => {
-                    Self::from_arrow(array.as_primitive::<Time32SecondType>(), nullable)
+            // There is no dictionary array in this crate, so dictionaries are decoded into their
+            // values. Use `DictArray` from `vortex-dict` to preserve the encoding.
+            DataType::Dictionary(_, values) => {
+                Self::try_from_arrow(arrow_cast::cast(&array, values)?, nullable)
+            }
+            DataType::RunEndEncoded(run_ends, _) => match run_ends.data_type() {
+                DataType::Int16 => {
+                    Self::try_from_arrow(as_run_array::<Int16Type>(&array), nullable)
                 }
-                TimeUnit::Millisecond => {
-                    Self::from_arrow(array.as_primitive::<Time32MillisecondType>(), nullable)
+                DataType::Int32 => {
+                    Self::try_from_arrow(as_run_array::<Int32Type>(&array), nullable)
                 }
-                _ => unreachable!(),
-            },
-            DataType::Time64(u) => match u {
-                TimeUnit::Microsecond => {
-                    Self::from_arrow(array.as_primitive::<Time64MicrosecondType>(), nullable)
+                DataType::Int64 => {
+                    Self::try_from_arrow(as_run_array::<Int64Type>(&array), nullable)
                 }
-                TimeUnit::Nanosecond => {
-                    Self::from_arrow(array.as_primitive::<Time64NanosecondType>(), nullable)
-                }
-                _ => unreachable!(),
+                dt => vortex_bail!("Invalid run end type {}", dt),
             },
-            DataType::Duration(u) => match u {
-                TimeUnit::Second => {
-                    Self::from_arrow(array.as_primitive::<DurationSecondType>(), nullable)
-                }
-                TimeUnit::Millisecond => {
-                    Self::from_arrow(array.as_primitive::<DurationMillisecondType>(), nullable)
-                }
-                TimeUnit::Microsecond => {
-                    Self::from_arrow(array.as_primitive::<DurationMicrosecondType>(), nullable)
-                }
-                TimeUnit::Nanosecond => {
-                    Self::from_arrow(array.as_primitive::<DurationNanosecondType>(), nullable)
-                }
-            },
-            _ => panic!(
-                "TODO(robert): Missing array encoding for dtype {}",
-                array.data_type().clone()
-            ),
+            // Importing lists and maps is left to a follow-up, as there is no Vortex list array yet.
+            DataType::List(_)
+            | DataType::LargeList(_)
+            | DataType::FixedSizeList(..)
+            | DataType::Map(..) => {
+                vortex_bail!(
+                    "Arrow list arrays are not supported yet, found {}",
+                    array.data_type()
+                )
+            }
+            dt => vortex_bail!("Arrow data type {} is not supported", dt),
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Int32Type, Int8Type};
+    use arrow_array::{
+        ArrayRef, DictionaryArray, FixedSizeBinaryArray, Int32Array, ListArray, RunArray,
+        StringArray, StructArray as ArrowStructArray,
+    };
+    use arrow_schema::{DataType, Field};
+    use vortex_dtype::{DType, ExtDType, ExtID, Nullability};
+
+    use crate::arrow::{TryFromArrowArray, EXTENSION_NAME_KEY};
+    use crate::{ArrayDType, ArrayData, IntoArray, IntoArrayVariant, IntoCanonical};
+
+    #[test]
+    fn test_fixed_size_binary() {
+        let arrow = FixedSizeBinaryArray::from(vec![Some(b"ab".as_slice()), None, Some(b"cd")]);
+        let array = ArrayData::try_from_arrow(Arc::new(arrow.slice(1, 2)) as ArrayRef, true)
+            .unwrap()
+            .into_array()
+            .into_varbin()
+            .unwrap();
+        assert_eq!(array.bytes_at(1).unwrap().as_slice(), b"cd");
+        assert!(array.dtype().is_nullable());
+    }
+
+    #[test]
+    fn test_dictionary() {
+        let arrow: DictionaryArray<Int8Type> = vec!["a", "b", "a"].into_iter().collect();
+        let array = ArrayData::try_from_arrow(Arc::new(arrow) as ArrayRef, false)
+            .unwrap()
+            .into_array()
+            .into_varbin()
+            .unwrap();
+        assert_eq!(array.bytes_at(2).unwrap().as_slice(), b"a");
+    }
+
+    #[test]
+    fn test_run_end_sliced() {
+        let arrow = RunArray::<Int32Type>::try_new(
+            &Int32Array::from(vec![2, 5, 6]),
+            &StringArray::from(vec!["a", "b", "c"]),
+        )
+        .unwrap();
+        let array = ArrayData::try_from_arrow(Arc::new(arrow.slice(1, 4)) as ArrayRef, false)
+            .unwrap()
+            .into_array()
+            .into_varbin()
+            .unwrap();
+        assert_eq!(array.array().len(), 4);
+        assert_eq!(array.bytes_at(0).unwrap().as_slice(), b"a");
+        assert_eq!(array.bytes_at(3).unwrap().as_slice(), b"b");
+    }
+
+    #[test]
+    fn test_foreign_extension_round_trip() {
+        let field = Field::new("json", DataType::Utf8, false)
+            .with_metadata([(EXTENSION_NAME_KEY.to_string(), "arrow.json".to_string())].into());
+        let arrow = ArrowStructArray::new(
+            vec![field].into(),
+            vec![Arc::new(StringArray::from(vec!["{}", "[1]"])) as ArrayRef],
+            None,
+        );
+        let array = ArrayData::try_from_arrow(&arrow, false)
+            .unwrap()
+            .into_array()
+            .into_struct()
+            .unwrap();
+        assert_eq!(
+            array.field(0).unwrap().dtype(),
+            &DType::Extension(
+                ExtDType::new(ExtID::from("arrow.json"), None),
+                Nullability::NonNullable
+            )
+        );
+
+        let exported = array.into_canonical().unwrap().into_arrow();
+        assert_eq!(exported.data_type(), arrow.data_type());
+        assert_eq!(exported.as_struct().column(0), arrow.column(0));
+    }
+
+    #[test]
+    fn test_unsupported() {
+        let arrow = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![Some(1)])]);
+        assert!(ArrayData::try_from_arrow(Arc::new(arrow) as ArrayRef, false).is_err());
+        let nulls = Int32Array::from(vec![Some(1), None]);
+        assert!(ArrayData::try_from_arrow(Arc::new(nulls) as ArrayRef, false).is_err());
+    }
+}
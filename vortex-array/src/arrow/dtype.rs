@@ -1,14 +1,15 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use arrow_schema::TimeUnit as ArrowTimeUnit;
-use arrow_schema::{DataType, Field, SchemaRef};
+use arrow_schema::{DataType, Field, FieldRef, Fields, Schema, SchemaRef};
 use itertools::Itertools;
-use vortex_dtype::{DType, Nullability};
+use vortex_dtype::{DType, ExtDType, ExtID, ExtMetadata, Nullability};
 use vortex_dtype::{PType, StructDType};
-use vortex_error::{vortex_err, VortexResult};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
 
-use crate::array::datetime::{LocalDateTimeArray, TimeUnit};
-use crate::arrow::{FromArrowType, TryFromArrowType};
+use crate::array::datetime::{try_parse_time_unit, LocalDateTimeArray, TimeUnit};
+use crate::arrow::TryFromArrowType;
 
 impl TryFromArrowType<&DataType> for PType {
     fn try_from_arrow(value: &DataType) -> VortexResult<Self> {
@@ -32,9 +33,9 @@ impl TryFromArrowType<&DataType> for PType {
     }
 }
 
-impl FromArrowType<SchemaRef> for DType {
-    fn from_arrow(value: SchemaRef) -> Self {
-        Self::Struct(
+impl TryFromArrowType<SchemaRef> for DType {
+    fn try_from_arrow(value: SchemaRef) -> VortexResult<Self> {
+        Ok(Self::Struct(
             StructDType::new(
                 value
                     .fields()
@@ -45,39 +46,43 @@ impl FromArrowType<SchemaRef> for DType {
                 value
                     .fields()
                     .iter()
-                    .map(|f| Self::from_arrow(f.as_ref()))
-                    .collect_vec(),
+                    .map(|f| Self::try_from_arrow(f.as_ref()))
+                    .collect::<VortexResult<Vec<_>>>()?,
             ),
             Nullability::NonNullable,
-        )
+        ))
     }
 }
 
-impl FromArrowType<&Field> for DType {
-    fn from_arrow(field: &Field) -> Self {
+impl TryFromArrowType<&Field> for DType {
+    fn try_from_arrow(field: &Field) -> VortexResult<Self> {
         use vortex_dtype::DType::*;
 
         let nullability: Nullability = field.is_nullable().into();
 
+        if let Some(ext_dtype) = extension_dtype(field) {
+            return Ok(Extension(ext_dtype, nullability));
+        }
+
         if let Ok(ptype) = PType::try_from_arrow(field.data_type()) {
-            return Primitive(ptype, nullability);
+            return Ok(Primitive(ptype, nullability));
         }
 
-        match field.data_type() {
+        Ok(match field.data_type() {
             DataType::Null => Null,
             DataType::Boolean => Bool(nullability),
-            DataType::Utf8 | DataType::LargeUtf8 => Utf8(nullability),
-            DataType::Binary | DataType::LargeBinary => Binary(nullability),
+            DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => Utf8(nullability),
+            DataType::Binary
+            | DataType::LargeBinary
+            | DataType::BinaryView
+            | DataType::FixedSizeBinary(_) => Binary(nullability),
             DataType::Timestamp(time_unit, tz) => match tz {
                 None => Extension(LocalDateTimeArray::ext_dtype(time_unit.into()), nullability),
-                Some(_) => unimplemented!("Timezone not yet supported"),
+                Some(tz) => vortex_bail!("Timestamps with timezone {} are not supported", tz),
             },
-            // DataType::Date32 => localdate(IntWidth::_32, nullability),
-            // DataType::Date64 => localdate(IntWidth::_64, nullability),
-            // DataType::Time32(u) => localtime(u.into(), IntWidth::_32, nullability),
-            // DataType::Time64(u) => localtime(u.into(), IntWidth::_64, nullability),
+            // Fixed size lists and maps are rejected, as their arrays can't be imported either.
             DataType::List(e) | DataType::LargeList(e) => {
-                List(Arc::new(Self::from_arrow(e.as_ref())), nullability)
+                List(Arc::new(Self::try_from_arrow(e.as_ref())?), nullability)
             }
             DataType::Struct(f) => Struct(
                 StructDType::new(
@@ -85,15 +90,40 @@ impl FromArrowType<&Field> for DType {
                         .map(|f| f.name().as_str().into())
                         .collect_vec()
                         .into(),
-                    f.iter().map(|f| Self::from_arrow(f.as_ref())).collect_vec(),
+                    f.iter()
+                        .map(|f| Self::try_from_arrow(f.as_ref()))
+                        .collect::<VortexResult<Vec<_>>>()?,
                 ),
                 nullability,
             ),
-            _ => unimplemented!("Arrow data type not yet supported: {:?}", field.data_type()),
-        }
+            // Dictionary and run-end encoding do not change the logical type of the values.
+            DataType::Dictionary(_, values) => Self::try_from_arrow(&Field::new(
+                field.name(),
+                values.as_ref().clone(),
+                field.is_nullable(),
+            ))?,
+            DataType::RunEndEncoded(_, values) => Self::try_from_arrow(&Field::new(
+                field.name(),
+                values.data_type().clone(),
+                field.is_nullable(),
+            ))?,
+            dt => vortex_bail!("Arrow data type {} is not supported", dt),
+        })
     }
 }
 
+/// The extension dtype recorded in the metadata of an Arrow field, if any.
+///
+/// Any extension type is recovered, e.g. `arrow.uuid`, with the field's array as its storage.
+pub(crate) fn extension_dtype(field: &Field) -> Option<ExtDType> {
+    let id = field.metadata().get(EXTENSION_NAME_KEY)?;
+    let metadata = field
+        .metadata()
+        .get(EXTENSION_METADATA_KEY)
+        .map(|m| ExtMetadata::from(m.as_bytes()));
+    Some(ExtDType::new(ExtID::from(id.as_str()), metadata))
+}
+
 impl From<&ArrowTimeUnit> for TimeUnit {
     fn from(value: &ArrowTimeUnit) -> Self {
         match value {
@@ -115,3 +145,284 @@ impl From<TimeUnit> for ArrowTimeUnit {
         }
     }
 }
+
+/// The Arrow field metadata key holding the ID of an extension type.
+pub const EXTENSION_NAME_KEY: &str = "ARROW:extension:name";
+/// The Arrow field metadata key holding the metadata of an extension type.
+pub const EXTENSION_METADATA_KEY: &str = "ARROW:extension:metadata";
+
+/// Convert a Vortex [struct DType][DType] to an Arrow [Schema].
+///
+/// Every logical type is mapped to its simplest corresponding Arrow [DataType], which is what most
+/// compute engines expect, even where the canonical Arrow array of a chunk uses a wider type.
+///
+/// Returns an error if the provided `dtype` is not a non-nullable StructDType, or if any field
+/// cannot be represented in Arrow.
+pub fn infer_schema(dtype: &DType) -> VortexResult<Schema> {
+    let DType::Struct(struct_dtype, nullable) = dtype else {
+        vortex_bail!(
+            "only DType::Struct can be converted to arrow schema, found {}",
+            dtype
+        );
+    };
+
+    if *nullable != Nullability::NonNullable {
+        vortex_bail!("top-level struct in Schema must be NonNullable");
+    }
+
+    Ok(Schema::new(infer_fields(struct_dtype)?))
+}
+
+/// Convert a Vortex [DType] to an Arrow [Field].
+///
+/// Extension dtypes record their ID and metadata in the field metadata, following the Arrow
+/// extension type convention, so that they can be recovered by [`DType::try_from_arrow`].
+pub fn infer_field(name: &str, dtype: &DType) -> VortexResult<Field> {
+    let field = Field::new(name, infer_data_type(dtype)?, dtype.is_nullable());
+    match dtype {
+        DType::Extension(ext_dtype, _) => Ok(field.with_metadata(extension_metadata(ext_dtype)?)),
+        _ => Ok(field),
+    }
+}
+
+/// The Arrow field metadata recording an extension dtype.
+pub(crate) fn extension_metadata(ext_dtype: &ExtDType) -> VortexResult<HashMap<String, String>> {
+    let mut metadata =
+        HashMap::from([(EXTENSION_NAME_KEY.to_string(), ext_dtype.id().to_string())]);
+    if let Some(ext_metadata) = ext_dtype.metadata() {
+        let ext_metadata = String::from_utf8(ext_metadata.as_ref().to_vec()).map_err(|_| {
+            vortex_err!(
+                "Metadata of extension dtype {} is not valid UTF-8",
+                ext_dtype.id()
+            )
+        })?;
+        metadata.insert(EXTENSION_METADATA_KEY.to_string(), ext_metadata);
+    }
+    Ok(metadata)
+}
+
+fn infer_fields(struct_dtype: &StructDType) -> VortexResult<Fields> {
+    struct_dtype
+        .names()
+        .iter()
+        .zip(struct_dtype.dtypes().iter())
+        .map(|(name, dtype)| infer_field(name, dtype).map(FieldRef::from))
+        .collect()
+}
+
+/// Convert a Vortex [DType] to the Arrow [DataType] of its canonical array.
+///
+/// Extension dtypes carry no storage type, so only those known to Vortex can be converted.
+pub fn infer_data_type(dtype: &DType) -> VortexResult<DataType> {
+    Ok(match dtype {
+        DType::Null => DataType::Null,
+        DType::Bool(_) => DataType::Boolean,
+        DType::Primitive(ptype, _) => match ptype {
+            PType::U8 => DataType::UInt8,
+            PType::U16 => DataType::UInt16,
+            PType::U32 => DataType::UInt32,
+            PType::U64 => DataType::UInt64,
+            PType::I8 => DataType::Int8,
+            PType::I16 => DataType::Int16,
+            PType::I32 => DataType::Int32,
+            PType::I64 => DataType::Int64,
+            PType::F16 => DataType::Float16,
+            PType::F32 => DataType::Float32,
+            PType::F64 => DataType::Float64,
+        },
+        DType::Utf8(_) => DataType::Utf8,
+        DType::Binary(_) => DataType::Binary,
+        DType::Struct(struct_dtype, _) => DataType::Struct(infer_fields(struct_dtype)?),
+        DType::List(list_dt, _) => DataType::List(FieldRef::from(infer_field("element", list_dt)?)),
+        DType::Extension(ext_dtype, _) => match ext_dtype.id().as_ref() {
+            LocalDateTimeArray::ID => {
+                DataType::Timestamp(try_parse_time_unit(ext_dtype)?.into(), None)
+            }
+            _ => vortex_bail!(
+                "Extension dtype {} cannot be converted to Arrow",
+                ext_dtype.id()
+            ),
+        },
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_schema::TimeUnit as ArrowTimeUnit;
+    use arrow_schema::{DataType, Field, FieldRef, Fields, Schema};
+    use vortex_dtype::{
+        DType, ExtDType, ExtID, ExtMetadata, FieldName, FieldNames, Nullability, PType, StructDType,
+    };
+
+    use crate::array::datetime::{LocalDateTimeArray, TimeUnit};
+    use crate::arrow::{
+        infer_data_type, infer_schema, TryFromArrowType, EXTENSION_METADATA_KEY, EXTENSION_NAME_KEY,
+    };
+
+    #[test]
+    fn test_dtype_conversion_success() {
+        assert_eq!(infer_data_type(&DType::Null).unwrap(), DataType::Null);
+
+        assert_eq!(
+            infer_data_type(&DType::Bool(Nullability::NonNullable)).unwrap(),
+            DataType::Boolean
+        );
+
+        assert_eq!(
+            infer_data_type(&DType::Primitive(PType::U64, Nullability::NonNullable)).unwrap(),
+            DataType::UInt64
+        );
+
+        assert_eq!(
+            infer_data_type(&DType::Utf8(Nullability::NonNullable)).unwrap(),
+            DataType::Utf8
+        );
+
+        assert_eq!(
+            infer_data_type(&DType::Binary(Nullability::NonNullable)).unwrap(),
+            DataType::Binary
+        );
+
+        assert_eq!(
+            infer_data_type(&DType::List(
+                Arc::new(DType::Bool(Nullability::NonNullable)),
+                Nullability::Nullable,
+            ))
+            .unwrap(),
+            DataType::List(FieldRef::from(Field::new(
+                "element".to_string(),
+                DataType::Boolean,
+                false,
+            )))
+        );
+
+        assert_eq!(
+            infer_data_type(&DType::Struct(
+                StructDType::new(
+                    FieldNames::from(vec![FieldName::from("field_a"), FieldName::from("field_b")]),
+                    vec![DType::Bool(false.into()), DType::Utf8(true.into())],
+                ),
+                Nullability::NonNullable,
+            ))
+            .unwrap(),
+            DataType::Struct(Fields::from(vec![
+                FieldRef::from(Field::new("field_a", DataType::Boolean, false)),
+                FieldRef::from(Field::new("field_b", DataType::Utf8, true)),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_dtype_conversion_fails() {
+        assert!(infer_data_type(&DType::Extension(
+            ExtDType::new(ExtID::from("my-fake-ext-dtype"), None),
+            Nullability::NonNullable,
+        ))
+        .is_err());
+    }
+
+    #[test]
+    fn test_unsupported_arrow_types() {
+        let entries = Fields::from(vec![
+            Field::new("key", DataType::Utf8, false),
+            Field::new("value", DataType::Int32, true),
+        ]);
+        for data_type in [
+            DataType::FixedSizeList(Arc::new(Field::new("item", DataType::Int32, true)), 2),
+            DataType::Map(
+                Arc::new(Field::new("entries", DataType::Struct(entries), false)),
+                false,
+            ),
+        ] {
+            assert!(DType::try_from_arrow(&Field::new("f", data_type, true)).is_err());
+        }
+    }
+
+    #[test]
+    fn test_schema_conversion() {
+        let struct_dtype = the_struct();
+        let schema_nonnull = DType::Struct(struct_dtype.clone(), Nullability::NonNullable);
+
+        assert_eq!(
+            infer_schema(&schema_nonnull).unwrap(),
+            Schema::new(Fields::from(vec![
+                Field::new("field_a", DataType::Boolean, false),
+                Field::new("field_b", DataType::Utf8, false),
+                Field::new("field_c", DataType::Int32, true),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_schema_conversion_fails() {
+        let struct_dtype = the_struct();
+        let schema_null = DType::Struct(struct_dtype.clone(), Nullability::Nullable);
+        assert!(infer_schema(&schema_null).is_err());
+        assert!(infer_schema(&DType::Bool(Nullability::NonNullable)).is_err());
+    }
+
+    #[test]
+    fn test_extension_round_trip() {
+        let dtype = DType::Struct(
+            StructDType::new(
+                FieldNames::from([FieldName::from("ts")]),
+                vec![DType::Extension(
+                    LocalDateTimeArray::ext_dtype(TimeUnit::Ms),
+                    Nullability::Nullable,
+                )],
+            ),
+            Nullability::NonNullable,
+        );
+        let schema = infer_schema(&dtype).unwrap();
+        assert_eq!(
+            schema.field(0).metadata()[EXTENSION_NAME_KEY],
+            LocalDateTimeArray::ID
+        );
+        assert_eq!(DType::try_from_arrow(Arc::new(schema)).unwrap(), dtype);
+    }
+
+    #[test]
+    fn test_arrow_extension() {
+        let field = Field::new("id", DataType::FixedSizeBinary(16), false).with_metadata(
+            [
+                (EXTENSION_NAME_KEY.to_string(), "arrow.uuid".to_string()),
+                (EXTENSION_METADATA_KEY.to_string(), "{}".to_string()),
+            ]
+            .into(),
+        );
+        assert_eq!(
+            DType::try_from_arrow(&field).unwrap(),
+            DType::Extension(
+                ExtDType::new(
+                    ExtID::from("arrow.uuid"),
+                    Some(ExtMetadata::from("{}".as_bytes()))
+                ),
+                Nullability::NonNullable
+            )
+        );
+
+        let unsupported = Field::new(
+            "ts",
+            DataType::Timestamp(ArrowTimeUnit::Second, Some("UTC".into())),
+            true,
+        );
+        assert!(DType::try_from_arrow(&unsupported).is_err());
+    }
+
+    fn the_struct() -> StructDType {
+        StructDType::new(
+            FieldNames::from([
+                FieldName::from("field_a"),
+                FieldName::from("field_b"),
+                FieldName::from("field_c"),
+            ]),
+            vec![
+                DType::Bool(Nullability::NonNullable),
+                DType::Utf8(Nullability::NonNullable),
+                DType::Primitive(PType::I32, Nullability::Nullable),
+            ],
+        )
+    }
+}
@@ -0,0 +1,200 @@
+//! Export and import of arrays through the Arrow [C Data Interface] and [C Stream Interface].
+//!
+//! Arrays cross the boundary in their canonical Arrow form, so any encoded array is decompressed
+//! on export, and imported arrays are canonical Vortex arrays.
+//!
+//! [C Data Interface]: https://arrow.apache.org/docs/format/CDataInterface.html
+//! [C Stream Interface]: https://arrow.apache.org/docs/format/CStreamInterface.html
+
+use std::sync::Arc;
+
+use arrow_array::ffi::{from_ffi, FFI_ArrowArray, FFI_ArrowSchema};
+use arrow_array::ffi_stream::{ArrowArrayStreamReader, FFI_ArrowArrayStream};
+use arrow_array::{
+    make_array, Array as ArrowArray, ArrayRef, RecordBatch, RecordBatchReader, StructArray,
+};
+use arrow_schema::{ArrowError, Field, SchemaRef};
+use futures_executor::block_on_stream;
+use vortex_dtype::DType;
+use vortex_error::VortexResult;
+
+use crate::arrow::array::with_extension;
+use crate::arrow::{infer_field, TryFromArrowArray, TryFromArrowType};
+use crate::iter::{ArrayIterator, ArrayIteratorAdapter, ArrayIteratorExt};
+use crate::stream::{ArrayStream, ArrayStreamExt};
+use crate::{Array, ArrayDType, ArrayData, IntoArray, IntoCanonical};
+
+/// Export an array as a C Data Interface array and schema.
+///
+/// The schema describes a field with an empty name whose nullability matches the array's dtype.
+/// Extension dtypes are recorded in the field metadata.
+pub fn export_array(array: Array) -> VortexResult<(FFI_ArrowArray, FFI_ArrowSchema)> {
+    let field = infer_field("", array.dtype())?;
+    let arrow = array.into_canonical()?.into_arrow();
+    // Canonical arrays may use a wider Arrow type than the dtype implies, e.g. LargeUtf8 for Utf8.
+    let arrow = if arrow.data_type() == field.data_type() {
+        arrow
+    } else {
+        arrow_cast::cast(&arrow, field.data_type())?
+    };
+
+    let schema = FFI_ArrowSchema::try_from(&field)?;
+    Ok((FFI_ArrowArray::new(&arrow.to_data()), schema))
+}
+
+/// Import an array from a C Data Interface array and schema.
+///
+/// # Safety
+///
+/// The array and schema must be valid according to the C Data Interface, and the schema must
+/// describe the array.
+pub unsafe fn import_array(array: FFI_ArrowArray, schema: &FFI_ArrowSchema) -> VortexResult<Array> {
+    let field = Field::try_from(schema)?;
+    let arrow = make_array(from_ffi(array, schema)?);
+    // Producers do not always flag nullable arrays, so trust the data when it contains nulls.
+    let nullable = field.is_nullable() || arrow.null_count() > 0;
+    Ok(with_extension(&field, ArrayData::try_from_arrow(arrow, nullable)?).into_array())
+}
+
+/// Export an iterator of struct arrays as a C Stream Interface stream of record batches of at
+/// most `max_rows` rows.
+pub fn export_array_iterator<I>(iter: I, max_rows: usize) -> VortexResult<FFI_ArrowArrayStream>
+where
+    I: ArrayIterator + Send + 'static,
+{
+    Ok(FFI_ArrowArrayStream::new(Box::new(
+        iter.into_record_batch_reader(max_rows)?,
+    )))
+}
+
+/// Export a stream of struct arrays as a C Stream Interface stream of record batches of at most
+/// `max_rows` rows.
+///
+/// The C Stream Interface is blocking, so each call from the consumer blocks the calling thread
+/// until the next batch has been produced.
+pub fn export_array_stream<S>(stream: S, max_rows: usize) -> VortexResult<FFI_ArrowArrayStream>
+where
+    S: ArrayStream + Send + 'static,
+{
+    let stream = Box::pin(stream.into_record_batch_stream(max_rows)?);
+    let schema = stream.schema();
+    Ok(FFI_ArrowArrayStream::new(Box::new(
+        BlockingRecordBatchReader {
+            schema,
+            batches: block_on_stream(stream),
+        },
+    )))
+}
+
+struct BlockingRecordBatchReader<I> {
+    schema: SchemaRef,
+    batches: I,
+}
+
+impl<I: Iterator<Item = VortexResult<RecordBatch>>> Iterator for BlockingRecordBatchReader<I> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.batches
+            .next()
+            .map(|batch| batch.map_err(|err| ArrowError::ExternalError(Box::new(err))))
+    }
+}
+
+impl<I: Iterator<Item = VortexResult<RecordBatch>>> RecordBatchReader
+    for BlockingRecordBatchReader<I>
+{
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
+
+/// Import a C Stream Interface stream of record batches as an iterator of struct arrays.
+///
+/// Use [`ArrayIteratorExt::into_stream`] to consume it asynchronously.
+pub fn import_array_stream(stream: FFI_ArrowArrayStream) -> VortexResult<impl ArrayIterator> {
+    let reader = ArrowArrayStreamReader::try_new(stream)?;
+    let dtype = DType::try_from_arrow(reader.schema())?;
+    Ok(ArrayIteratorAdapter::new(
+        dtype,
+        reader.map(|batch| {
+            let array: ArrayRef = Arc::new(StructArray::from(batch?));
+            Ok(ArrayData::try_from_arrow(array, false)?.into_array())
+        }),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability};
+
+    use super::*;
+    use crate::array::chunked::ChunkedArray;
+    use crate::array::datetime::{LocalDateTimeArray, TimeUnit};
+    use crate::array::primitive::PrimitiveArray;
+    use crate::array::struct_::StructArray;
+    use crate::array::varbin::VarBinArray;
+    use crate::compute::unary::scalar_at::scalar_at;
+    use crate::validity::Validity;
+    use crate::IntoArrayData;
+
+    #[test]
+    fn test_array_round_trip() {
+        let array = PrimitiveArray::from_nullable_vec(vec![Some(1i64), None, Some(3)]).into_array();
+        let (ffi_array, ffi_schema) = export_array(array.clone()).unwrap();
+        let imported = unsafe { import_array(ffi_array, &ffi_schema) }.unwrap();
+
+        assert_eq!(imported.dtype(), array.dtype());
+        assert_eq!(imported.len(), 3);
+        assert!(scalar_at(&imported, 1).unwrap().is_null());
+        assert_eq!(
+            scalar_at(&imported, 2).unwrap(),
+            scalar_at(&array, 2).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_extension_round_trip() {
+        let array = LocalDateTimeArray::try_new(
+            TimeUnit::Ms,
+            PrimitiveArray::from(vec![1i64, 2, 3]).into_array(),
+        )
+        .unwrap()
+        .into_array_data()
+        .into_array();
+        let (ffi_array, ffi_schema) = export_array(array.clone()).unwrap();
+        let imported = unsafe { import_array(ffi_array, &ffi_schema) }.unwrap();
+        assert_eq!(imported.dtype(), array.dtype());
+    }
+
+    #[test]
+    fn test_stream_round_trip() {
+        let chunk = StructArray::try_new(
+            ["a".into(), "b".into()].into(),
+            vec![
+                PrimitiveArray::from(vec![1u32, 2, 3]).into_array(),
+                VarBinArray::from(vec!["x", "y", "z"]).into_array(),
+            ],
+            3,
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array();
+        let dtype = chunk.dtype().clone();
+        let array = ChunkedArray::try_new(vec![chunk.clone(), chunk], dtype.clone()).unwrap();
+
+        let ffi_stream = export_array_stream(array.into_array_iterator().into_stream(), 4).unwrap();
+        let imported = import_array_stream(ffi_stream).unwrap();
+        assert_eq!(imported.dtype(), &dtype);
+
+        let chunks = imported.try_into_chunked().unwrap();
+        assert_eq!(
+            chunks.chunks().map(|c| c.len()).collect::<Vec<_>>(),
+            vec![4, 2]
+        );
+        assert!(matches!(
+            chunks.dtype(),
+            DType::Struct(_, Nullability::NonNullable)
+        ));
+    }
+}
@@ -1,18 +1,44 @@
+pub use array::try_struct_from_arrow;
+pub(crate) use dtype::extension_metadata;
+pub use dtype::{
+    infer_data_type, infer_field, infer_schema, EXTENSION_METADATA_KEY, EXTENSION_NAME_KEY,
+};
+pub use reader::*;
 use vortex_error::VortexResult;
 
 mod array;
 mod dtype;
+pub mod ffi;
+mod reader;
 mod recordbatch;
 pub mod wrappers;
 
+pub trait TryFromArrowArray<A>: Sized {
+    fn try_from_arrow(array: A, nullable: bool) -> VortexResult<Self>;
+}
+
+pub trait TryFromArrowType<T>: Sized {
+    fn try_from_arrow(value: T) -> VortexResult<Self>;
+}
+
+/// Infallible counterpart of [`TryFromArrowArray`], panicking if the array cannot be converted.
 pub trait FromArrowArray<A> {
     fn from_arrow(array: A, nullable: bool) -> Self;
 }
 
+impl<A, T: TryFromArrowArray<A>> FromArrowArray<A> for T {
+    fn from_arrow(array: A, nullable: bool) -> Self {
+        Self::try_from_arrow(array, nullable).unwrap()
+    }
+}
+
+/// Infallible counterpart of [`TryFromArrowType`], panicking if the type cannot be converted.
 pub trait FromArrowType<T>: Sized {
     fn from_arrow(value: T) -> Self;
 }
 
-pub trait TryFromArrowType<T>: Sized {
-    fn try_from_arrow(value: T) -> VortexResult<Self>;
+impl<T, U: TryFromArrowType<T>> FromArrowType<T> for U {
+    fn from_arrow(value: T) -> Self {
+        Self::try_from_arrow(value).unwrap()
+    }
 }
@@ -0,0 +1,285 @@
+use std::mem;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{ready, Context, Poll};
+
+use arrow_array::cast::AsArray;
+use arrow_array::{ArrayRef, RecordBatch, RecordBatchReader};
+use arrow_cast::cast;
+use arrow_schema::{ArrowError, SchemaRef};
+use futures_util::Stream;
+use pin_project::pin_project;
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::array::chunked::ChunkedArray;
+use crate::arrow::infer_schema;
+use crate::compute::slice::slice;
+use crate::iter::ArrayIterator;
+use crate::stream::ArrayStream;
+use crate::{Array, IntoArray, IntoCanonical};
+
+/// The default maximum number of rows in each [`RecordBatch`].
+pub const DEFAULT_BATCH_SIZE: usize = 8192;
+
+/// An Arrow [`RecordBatchReader`] over an [`ArrayIterator`] of struct arrays.
+///
+/// Only the rows of one batch are canonicalized at a time. Chunks longer than `max_rows` are
+/// sliced into several batches, and shorter chunks are coalesced until a batch is full.
+pub struct ArrayRecordBatchReader<I> {
+    inner: I,
+    batcher: Batcher,
+}
+
+impl<I: ArrayIterator> ArrayRecordBatchReader<I> {
+    pub fn try_new(inner: I, max_rows: usize) -> VortexResult<Self> {
+        let batcher = Batcher::try_new(inner.dtype(), max_rows)?;
+        Ok(Self { inner, batcher })
+    }
+}
+
+impl<I: ArrayIterator> Iterator for ArrayRecordBatchReader<I> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.batcher.next_batch() {
+                Ok(Some(batch)) => return Some(Ok(batch)),
+                Ok(None) => {}
+                Err(err) => return Some(Err(ArrowError::ExternalError(Box::new(err)))),
+            }
+            if self.batcher.finished {
+                return None;
+            }
+
+            match self.inner.next() {
+                Some(Ok(chunk)) => self.batcher.push(chunk),
+                Some(Err(err)) => return Some(Err(ArrowError::ExternalError(Box::new(err)))),
+                None => self.batcher.finish(),
+            }
+        }
+    }
+}
+
+impl<I: ArrayIterator> RecordBatchReader for ArrayRecordBatchReader<I> {
+    fn schema(&self) -> SchemaRef {
+        self.batcher.schema.clone()
+    }
+}
+
+/// A stream of Arrow [`RecordBatch`]es over an [`ArrayStream`] of struct arrays, batched the same
+/// way as [`ArrayRecordBatchReader`].
+#[pin_project]
+pub struct ArrayRecordBatchStream<S> {
+    #[pin]
+    inner: S,
+    batcher: Batcher,
+}
+
+impl<S: ArrayStream> ArrayRecordBatchStream<S> {
+    pub fn try_new(inner: S, max_rows: usize) -> VortexResult<Self> {
+        let batcher = Batcher::try_new(inner.dtype(), max_rows)?;
+        Ok(Self { inner, batcher })
+    }
+
+    pub fn schema(&self) -> SchemaRef {
+        self.batcher.schema.clone()
+    }
+}
+
+impl<S: ArrayStream> Stream for ArrayRecordBatchStream<S> {
+    type Item = VortexResult<RecordBatch>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            if let Some(batch) = this.batcher.next_batch().transpose() {
+                return Poll::Ready(Some(batch));
+            }
+            if this.batcher.finished {
+                return Poll::Ready(None);
+            }
+
+            match ready!(this.inner.as_mut().poll_next(cx)) {
+                Some(Ok(chunk)) => this.batcher.push(chunk),
+                Some(Err(err)) => return Poll::Ready(Some(Err(err))),
+                None => this.batcher.finish(),
+            }
+        }
+    }
+}
+
+/// Re-slices a sequence of chunks into batches of at most `max_rows` rows.
+struct Batcher {
+    dtype: DType,
+    schema: SchemaRef,
+    max_rows: usize,
+    /// The chunk being sliced into batches, along with the offset of its first unread row.
+    current: Option<(Array, usize)>,
+    /// Slices waiting to be coalesced into the next batch.
+    pending: Vec<Array>,
+    pending_rows: usize,
+    /// Whether the input is exhausted, in which case a partial batch may be emitted.
+    finished: bool,
+}
+
+impl Batcher {
+    fn try_new(dtype: &DType, max_rows: usize) -> VortexResult<Self> {
+        if max_rows == 0 {
+            vortex_bail!("Record batches must hold at least one row");
+        }
+
+        Ok(Self {
+            dtype: dtype.clone(),
+            schema: Arc::new(infer_schema(dtype)?),
+            max_rows,
+            current: None,
+            pending: Vec::new(),
+            pending_rows: 0,
+            finished: false,
+        })
+    }
+
+    fn push(&mut self, chunk: Array) {
+        debug_assert!(self.current.is_none(), "previous chunk was not consumed");
+        self.current = Some((chunk, 0));
+    }
+
+    fn finish(&mut self) {
+        self.finished = true;
+    }
+
+    /// Produce the next batch, or `None` if more input is needed (or there is none left).
+    fn next_batch(&mut self) -> VortexResult<Option<RecordBatch>> {
+        while let Some((chunk, offset)) = self.current.take() {
+            let rows = (self.max_rows - self.pending_rows).min(chunk.len() - offset);
+            let end = offset + rows;
+            if offset == 0 && end == chunk.len() {
+                self.pending.push(chunk);
+            } else {
+                self.pending.push(slice(&chunk, offset, end)?);
+                if end < chunk.len() {
+                    self.current = Some((chunk, end));
+                }
+            }
+            self.pending_rows += rows;
+
+            if self.pending_rows == self.max_rows {
+                return self.flush().map(Some);
+            }
+        }
+
+        if self.finished && self.pending_rows > 0 {
+            return self.flush().map(Some);
+        }
+        Ok(None)
+    }
+
+    fn flush(&mut self) -> VortexResult<RecordBatch> {
+        let mut chunks = mem::take(&mut self.pending);
+        self.pending_rows = 0;
+
+        let array = if chunks.len() == 1 {
+            chunks.remove(0)
+        } else {
+            ChunkedArray::try_new(chunks, self.dtype.clone())?.into_array()
+        };
+        let arrow = array.into_canonical()?.into_arrow();
+
+        // Canonical arrays may use a wider Arrow type than the schema, e.g. LargeUtf8 for Utf8.
+        let columns = arrow
+            .as_struct()
+            .columns()
+            .iter()
+            .zip(self.schema.fields())
+            .map(|(column, field)| {
+                if column.data_type() == field.data_type() {
+                    Ok(column.clone())
+                } else {
+                    cast(column, field.data_type())
+                }
+            })
+            .collect::<Result<Vec<ArrayRef>, ArrowError>>()?;
+
+        Ok(RecordBatch::try_new(self.schema.clone(), columns)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
+    use arrow_array::RecordBatchReader;
+    use futures_executor::block_on;
+    use futures_util::TryStreamExt;
+
+    use crate::array::chunked::ChunkedArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::array::struct_::StructArray;
+    use crate::iter::ArrayIteratorExt;
+    use crate::stream::ArrayStreamExt;
+    use crate::validity::Validity;
+    use crate::{Array, ArrayDType, IntoArray};
+
+    fn chunked(lengths: &[i32]) -> ChunkedArray {
+        let mut start = 0;
+        let chunks = lengths
+            .iter()
+            .map(|&len| {
+                let values = (start..start + len).collect::<Vec<_>>();
+                start += len;
+                StructArray::try_new(
+                    ["a".into()].into(),
+                    vec![PrimitiveArray::from(values).into_array()],
+                    len as usize,
+                    Validity::NonNullable,
+                )
+                .unwrap()
+                .into_array()
+            })
+            .collect::<Vec<Array>>();
+        let dtype = chunks[0].dtype().clone();
+        ChunkedArray::try_new(chunks, dtype).unwrap()
+    }
+
+    #[test]
+    fn test_rebatch() {
+        let reader = chunked(&[3, 1, 1, 10, 2])
+            .into_array_iterator()
+            .into_record_batch_reader(4)
+            .unwrap();
+        assert_eq!(reader.schema().fields().len(), 1);
+
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(
+            batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>(),
+            vec![4, 4, 4, 4, 1]
+        );
+        let values = batches
+            .iter()
+            .flat_map(|b| b.column(0).as_primitive::<Int32Type>().values().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(values, (0..17).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_rebatch_stream() {
+        let array = chunked(&[5, 5]);
+        let stream = array.array_stream().into_record_batch_stream(3).unwrap();
+        let batches = block_on(stream.try_collect::<Vec<_>>()).unwrap();
+        assert_eq!(
+            batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>(),
+            vec![3, 3, 3, 1]
+        );
+    }
+
+    #[test]
+    fn test_non_struct() {
+        let array = ChunkedArray::try_new(
+            vec![PrimitiveArray::from(vec![1i32]).into_array()],
+            PrimitiveArray::from(vec![1i32]).dtype().clone(),
+        )
+        .unwrap();
+        assert!(array.array_iterator().into_record_batch_reader(10).is_err());
+    }
+}
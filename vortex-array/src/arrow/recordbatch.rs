@@ -1,31 +1,19 @@
-use arrow_array::RecordBatch;
-use itertools::Itertools;
+use arrow_array::{RecordBatch, StructArray as ArrowStructArray};
+use vortex_error::{VortexError, VortexResult};
 
-use crate::array::struct_::StructArray;
-use crate::arrow::FromArrowArray;
-use crate::validity::Validity;
-use crate::{ArrayData, IntoArray, IntoArrayData, ToArrayData};
+use crate::arrow::TryFromArrowArray;
+use crate::{ArrayData, ToArrayData};
+
+impl TryFrom<&RecordBatch> for ArrayData {
+    type Error = VortexError;
+
+    fn try_from(value: &RecordBatch) -> VortexResult<Self> {
+        Self::try_from_arrow(&ArrowStructArray::from(value.clone()), true)
+    }
+}
 
 impl ToArrayData for RecordBatch {
     fn to_array_data(&self) -> ArrayData {
-        StructArray::try_new(
-            self.schema()
-                .fields()
-                .iter()
-                .map(|f| f.name().as_str().into())
-                .collect_vec()
-                .into(),
-            self.columns()
-                .iter()
-                .zip(self.schema().fields())
-                .map(|(array, field)| {
-                    ArrayData::from_arrow(array.clone(), field.is_nullable()).into_array()
-                })
-                .collect(),
-            self.num_rows(),
-            Validity::AllValid,
-        )
-        .unwrap()
-        .into_array_data()
+        ArrayData::try_from(self).unwrap()
     }
 }
@@ -22,6 +22,7 @@ use crate::array::null::NullArray;
 use crate::array::primitive::PrimitiveArray;
 use crate::array::struct_::StructArray;
 use crate::array::varbin::VarBinArray;
+use crate::arrow::extension_metadata;
 use crate::arrow::wrappers::as_offset_buffer;
 use crate::compute::unary::cast::try_cast;
 use crate::encoding::ArrayEncoding;
@@ -82,7 +83,12 @@ impl Canonical {
                 "vortex.localdatetime" => local_date_time_to_arrow(
                     LocalDateTimeArray::try_from(&a.into_array()).expect("localdatetime"),
                 ),
-                _ => panic!("unsupported extension dtype with ID {}", a.id().as_ref()),
+                // Other extensions have no Arrow equivalent, so export their storage.
+                _ => a
+                    .storage()
+                    .into_canonical()
+                    .expect("extension storage")
+                    .into_arrow(),
             },
         }
     }
@@ -194,11 +200,21 @@ fn struct_to_arrow(struct_array: StructArray) -> ArrayRef {
         .zip(field_arrays.iter())
         .zip(struct_array.dtypes().iter())
         .map(|((name, arrow_field), vortex_field)| {
-            Field::new(
+            let field = Field::new(
                 &**name,
                 arrow_field.data_type().clone(),
                 vortex_field.is_nullable(),
-            )
+            );
+            // Record extension dtypes exported as their storage, so that they survive a round trip
+            // through Arrow. Local date times are recovered from their timestamp type instead.
+            match vortex_field {
+                DType::Extension(ext_dtype, _)
+                    if ext_dtype.id().as_ref() != LocalDateTimeArray::ID =>
+                {
+                    field.with_metadata(extension_metadata(ext_dtype).expect("extension metadata"))
+                }
+                _ => field,
+            }
         })
         .map(Arc::new)
         .collect();
@@ -1,19 +1,45 @@
-use std::collections::HashSet;
+use std::any::{Any, TypeId};
+use std::collections::{HashMap, HashSet};
 use std::fmt::{Debug, Display, Formatter};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
 
 use log::{debug, info, warn};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rayon::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use serde::Serialize;
+use vortex_dtype::field::{Field, FieldPath};
 use vortex_error::{vortex_bail, VortexResult};
 
 use crate::array::chunked::{Chunked, ChunkedArray};
 use crate::array::constant::{Constant, ConstantArray};
+use crate::array::primitive::PrimitiveArray;
+use crate::array::sparse::SparseArray;
 use crate::array::struct_::{Struct, StructArray};
 use crate::compute::slice::slice;
 use crate::compute::unary::scalar_at::scalar_at;
 use crate::encoding::{ArrayEncoding, EncodingRef};
 use crate::sampling::stratified_slices;
-use crate::stats::ArrayStatistics;
+use crate::stats::{ArrayStatistics, BloomFilter, Stat};
 use crate::validity::Validity;
-use crate::{Array, ArrayDType, ArrayDef, ArrayTrait, Context, IntoArray, IntoCanonical};
+use crate::{
+    Array, ArrayDType, ArrayDef, ArrayTrait, Context, IntoArray, IntoArrayVariant, IntoCanonical,
+};
+
+/// Validity is stored sparsely when at most one in this many positions differ from the rest.
+const SPARSE_VALIDITY_RATIO: usize = 16;
+
+/// The statistics kept for each compressed chunk besides its min and max, see
+/// [`Compressor::compress_chunk`].
+const CHUNK_STATS: [Stat; 5] = [
+    Stat::NullCount,
+    Stat::TrueCount,
+    Stat::IsConstant,
+    Stat::IsSorted,
+    Stat::IsStrictSorted,
+];
 
 pub trait EncodingCompression: ArrayEncoding {
     fn cost(&self) -> u8 {
@@ -41,16 +67,124 @@ pub trait EncodingCompression: ArrayEncoding {
     fn compressed_nbytes(&self, array: &Array) -> usize {
         array.with_dyn(|a| a.nbytes())
     }
+
+    /// The estimated cost of decoding one element of an array of this encoding, not counting the
+    /// cost of decoding its children. Canonical encodings cost nothing to decode, so only
+    /// compressing encodings need to override this.
+    fn decode_cost(&self) -> f32 {
+        0.0
+    }
+}
+
+/// What the compressor optimizes for when choosing between candidate encodings.
+///
+/// Candidates must always shrink the array, the objective decides between those that do.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum CompressionObjective {
+    /// Choose the smallest encoding.
+    #[default]
+    MinSize,
+    /// Choose the encoding that is cheapest to decode, see [`EncodingCompression::decode_cost`].
+    MinDecodeCost,
+    /// Choose the encoding minimizing `ratio + decode_cost_weight * decode_cost`, where the ratio
+    /// is the compressed size over the uncompressed size.
+    Weighted { decode_cost_weight: f32 },
+}
+
+impl CompressionObjective {
+    /// Score a candidate by its compression ratio and per-element decode cost. Lower is better.
+    pub fn score(&self, ratio: f32, decode_cost: f32) -> (f32, f32) {
+        match self {
+            Self::MinSize => (ratio, decode_cost),
+            Self::MinDecodeCost => (decode_cost, ratio),
+            Self::Weighted { decode_cost_weight } => {
+                (ratio + decode_cost_weight * decode_cost, ratio)
+            }
+        }
+    }
+}
+
+/// The estimated cost of decoding each element of an array, summed over its encoding tree.
+pub fn decode_cost(array: &Array) -> f32 {
+    fn total_cost(array: &Array) -> f32 {
+        array.encoding().compression().decode_cost() * array.len() as f32
+            + array.children().iter().map(total_cost).sum::<f32>()
+    }
+
+    if array.is_empty() {
+        return 0.0;
+    }
+    total_cost(array) / array.len() as f32
+}
+
+/// The configuration of a single encoding, registered with
+/// [`CompressConfig::with_encoding_config`] and read by its [`EncodingCompression`].
+pub trait EncodingConfig: Any + Debug + Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T: Any + Debug + Send + Sync> EncodingConfig for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Overrides of the [`CompressConfig`] for a struct field and everything nested within it.
+#[derive(Debug, Clone, Default)]
+pub struct FieldOverride {
+    encoding: Option<EncodingRef>,
+    excluded: HashSet<EncodingRef>,
+    max_depth: Option<u8>,
+    disabled: bool,
+    bloom_filter: Option<f64>,
+}
+
+impl FieldOverride {
+    /// Compress the field with this encoding without sampling any others, as long as it can.
+    pub fn with_encoding(mut self, encoding: EncodingRef) -> Self {
+        self.encoding = Some(encoding);
+        self
+    }
+
+    /// Never compress the field or its descendants with this encoding.
+    pub fn excluding(mut self, encoding: EncodingRef) -> Self {
+        self.excluded.insert(encoding);
+        self
+    }
+
+    pub fn with_max_depth(mut self, max_depth: u8) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Leave the field uncompressed.
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
+
+    /// Store a [`BloomFilter`] of each chunk of the field with its statistics, reporting values
+    /// missing from the chunk as present with at most the given probability.
+    pub fn with_bloom_filter(mut self, false_positive_rate: f64) -> Self {
+        self.bloom_filter = Some(false_positive_rate);
+        self
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct CompressConfig {
-    #[allow(dead_code)]
     block_size: u32,
     sample_size: u16,
     sample_count: u16,
     max_depth: u8,
-    // TODO(ngates): can each encoding define their own configs?
+    objective: CompressionObjective,
+    rng_seed: u64,
+    threads: usize,
+    /// The pool compressing independent arrays in parallel, if more than one thread is
+    /// configured. It is shared by all compressors using this config.
+    pool: Option<Arc<ThreadPool>>,
+    field_overrides: Arc<HashMap<FieldPath, FieldOverride>>,
+    encoding_configs: Arc<HashMap<TypeId, Arc<dyn EncodingConfig>>>,
     pub ree_average_run_threshold: f32,
 }
 
@@ -63,11 +197,183 @@ impl Default for CompressConfig {
             sample_size: 128,
             sample_count: 8,
             max_depth: 3,
+            objective: CompressionObjective::MinSize,
+            rng_seed: 0,
+            threads: 1,
+            pool: None,
+            field_overrides: Arc::default(),
+            encoding_configs: Arc::default(),
             ree_average_run_threshold: 2.0,
         }
     }
 }
 
+impl CompressConfig {
+    /// The number of rows in each chunk when rechunking, see [`Compressor::compress_rechunked`].
+    pub fn with_block_size(mut self, block_size: u32) -> Self {
+        self.block_size = block_size;
+        self
+    }
+
+    pub fn with_sample_size(mut self, sample_size: u16) -> Self {
+        self.sample_size = sample_size;
+        self
+    }
+
+    pub fn with_sample_count(mut self, sample_count: u16) -> Self {
+        self.sample_count = sample_count;
+        self
+    }
+
+    pub fn with_max_depth(mut self, max_depth: u8) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    pub fn with_objective(mut self, objective: CompressionObjective) -> Self {
+        self.objective = objective;
+        self
+    }
+
+    /// Seed the random choice of samples, compressing the same array with the same config always
+    /// produces the same output.
+    pub fn with_rng_seed(mut self, rng_seed: u64) -> Self {
+        self.rng_seed = rng_seed;
+        self
+    }
+
+    /// Compress the fields of struct arrays and the chunks of chunked arrays on this many threads,
+    /// or on one thread per core when zero. The output is the same regardless of the thread count.
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = threads;
+        self.pool = (threads != 1)
+            .then(|| ThreadPoolBuilder::new().num_threads(threads).build())
+            .and_then(|pool| {
+                pool.map_err(|err| warn!("Compressing on a single thread: {}", err))
+                    .ok()
+            })
+            .map(Arc::new);
+        self
+    }
+
+    /// Override the config for the struct field at `path`, relative to the compressed array.
+    pub fn with_field_override(mut self, path: FieldPath, field_override: FieldOverride) -> Self {
+        Arc::make_mut(&mut self.field_overrides).insert(path, field_override);
+        self
+    }
+
+    /// Configure the encoding that reads configs of type `T`, replacing any previous `T`.
+    pub fn with_encoding_config<T: EncodingConfig>(mut self, config: T) -> Self {
+        Arc::make_mut(&mut self.encoding_configs).insert(TypeId::of::<T>(), Arc::new(config));
+        self
+    }
+
+    pub fn with_ree_average_run_threshold(mut self, ree_average_run_threshold: f32) -> Self {
+        self.ree_average_run_threshold = ree_average_run_threshold;
+        self
+    }
+
+    #[inline]
+    pub fn block_size(&self) -> u32 {
+        self.block_size
+    }
+
+    #[inline]
+    pub fn sample_size(&self) -> u16 {
+        self.sample_size
+    }
+
+    #[inline]
+    pub fn sample_count(&self) -> u16 {
+        self.sample_count
+    }
+
+    #[inline]
+    pub fn max_depth(&self) -> u8 {
+        self.max_depth
+    }
+
+    #[inline]
+    pub fn objective(&self) -> CompressionObjective {
+        self.objective
+    }
+
+    #[inline]
+    pub fn rng_seed(&self) -> u64 {
+        self.rng_seed
+    }
+
+    #[inline]
+    pub fn threads(&self) -> usize {
+        self.threads
+    }
+
+    pub fn field_override(&self, path: &FieldPath) -> Option<&FieldOverride> {
+        self.field_overrides.get(path)
+    }
+
+    pub fn encoding_config<T: EncodingConfig>(&self) -> Option<&T> {
+        self.encoding_configs
+            .get(&TypeId::of::<T>())
+            .and_then(|config| config.as_ref().as_any().downcast_ref())
+    }
+}
+
+/// A shared record of the decisions made by the compressors it is attached to, see
+/// [`Compressor::with_trace`]. Clones record into the same trace.
+#[derive(Debug, Clone, Default)]
+pub struct CompressionTrace(Arc<Mutex<Vec<ArrayTrace>>>);
+
+impl CompressionTrace {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The traced arrays, in the order their compression finished.
+    pub fn arrays(&self) -> Vec<ArrayTrace> {
+        self.0.lock().unwrap().clone()
+    }
+
+    fn push(&self, array: ArrayTrace) {
+        self.0.lock().unwrap().push(array);
+    }
+
+    fn append(&self, other: &Self) {
+        let arrays = std::mem::take(&mut *other.0.lock().unwrap());
+        self.0.lock().unwrap().extend(arrays);
+    }
+}
+
+/// How the compressor chose the encoding of an array.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ArrayTrace {
+    /// The path of the array, made of struct field names, chunk indices and encoding child names.
+    pub path: String,
+    /// The encoding of the array before compression.
+    pub encoding: String,
+    pub len: usize,
+    /// Whether the candidates were compared on a sample of the array rather than all of it.
+    pub sampled: bool,
+    /// The candidates tried, none if the array was compressed like a previous array.
+    pub candidates: Vec<CandidateTrace>,
+    /// The encoding of the compressed array, if any candidate shrank it or it was compressed like
+    /// a previous array.
+    pub winner: Option<String>,
+    pub nbytes: usize,
+    pub canonical_nbytes: usize,
+    /// The time spent choosing an encoding and compressing the array, including its children.
+    pub elapsed_micros: u64,
+}
+
+/// A candidate encoding tried on an array, or on its sample.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CandidateTrace {
+    pub encoding: String,
+    /// The compressed size over the uncompressed size.
+    pub ratio: f32,
+    pub decode_cost: f32,
+}
+
 #[derive(Debug, Clone)]
 pub struct Compressor<'a> {
     ctx: &'a Context,
@@ -75,8 +381,18 @@ pub struct Compressor<'a> {
 
     path: Vec<String>,
     depth: u8,
+    /// The path of struct fields to the array, used to look up field overrides.
+    field_path: Vec<Field>,
     /// A set of encodings disabled for this ctx.
     disabled_encodings: HashSet<EncodingRef>,
+    /// The encodings excluded by field overrides, which auxiliary arrays don't reset.
+    excluded_encodings: HashSet<EncodingRef>,
+    /// The encoding a field override forces for this array, if any.
+    forced_encoding: Option<EncodingRef>,
+    disabled: bool,
+    /// The false positive rate of the bloom filter a field override requests for this array.
+    bloom_filter: Option<f64>,
+    trace: Option<CompressionTrace>,
 }
 
 impl Display for Compressor<'_> {
@@ -96,10 +412,29 @@ impl<'a> Compressor<'a> {
             options,
             path: Vec::new(),
             depth: 0,
+            field_path: Vec::new(),
             disabled_encodings: HashSet::new(),
+            excluded_encodings: HashSet::new(),
+            forced_encoding: None,
+            disabled: false,
+            bloom_filter: None,
+            trace: None,
         }
     }
 
+    /// Record the encodings chosen for each array into `trace`.
+    pub fn with_trace(mut self, trace: CompressionTrace) -> Self {
+        self.trace = Some(trace);
+        self
+    }
+
+    /// Candidates compressed only to be measured are not traced.
+    fn untraced(&self) -> Self {
+        let mut cloned = self.clone();
+        cloned.trace = None;
+        cloned
+    }
+
     pub fn named(&self, name: &str) -> Self {
         let mut cloned = self.clone();
         cloned.path.push(name.into());
@@ -111,13 +446,41 @@ impl<'a> Compressor<'a> {
     pub fn auxiliary(&self, name: &str) -> Self {
         let mut cloned = self.clone();
         cloned.path.push(name.into());
-        cloned.disabled_encodings = HashSet::new();
+        cloned.disabled_encodings = cloned.excluded_encodings.clone();
+        cloned.bloom_filter = None;
+        cloned
+    }
+
+    /// Returns a new ctx used for compressing a struct field, applying any override of the field.
+    fn field(&self, name: &str) -> Self {
+        let mut cloned = self.named(name);
+        cloned.field_path.push(Field::from(name));
+        cloned.forced_encoding = None;
+        cloned.bloom_filter = None;
+
+        let path = FieldPath::from(cloned.field_path.clone());
+        if let Some(field_override) = self.options.field_override(&path) {
+            cloned.forced_encoding = field_override.encoding;
+            cloned
+                .excluded_encodings
+                .extend(field_override.excluded.iter().copied());
+            cloned
+                .disabled_encodings
+                .extend(field_override.excluded.iter().copied());
+            if let Some(max_depth) = field_override.max_depth {
+                cloned.options.max_depth = max_depth;
+            }
+            cloned.disabled |= field_override.disabled;
+            cloned.bloom_filter = field_override.bloom_filter;
+        }
         cloned
     }
 
     pub fn for_encoding(&self, compression: &dyn EncodingCompression) -> Self {
         let mut cloned = self.clone();
         cloned.depth += compression.cost();
+        cloned.forced_encoding = None;
+        cloned.bloom_filter = None;
         cloned
     }
 
@@ -133,12 +496,31 @@ impl<'a> Compressor<'a> {
     }
 
     pub fn compress(&self, arr: &Array, like: Option<&Array>) -> VortexResult<Array> {
-        if arr.is_empty() {
+        let compressed = self.compress_unfiltered(arr, like)?;
+        if let Some(false_positive_rate) = self.bloom_filter {
+            let filter = BloomFilter::try_build(arr, false_positive_rate)?;
+            compressed
+                .statistics()
+                .set(Stat::BloomFilter, filter.into());
+        }
+        Ok(compressed)
+    }
+
+    fn compress_unfiltered(&self, arr: &Array, like: Option<&Array>) -> VortexResult<Array> {
+        if arr.is_empty() || self.disabled {
             return Ok(arr.clone());
         }
 
         // Attempt to compress using the "like" array, otherwise fall back to sampled compression
         if let Some(l) = like {
+            if arr.encoding().id() == Struct::ID && l.encoding().id() == Struct::ID {
+                return self.compress_struct(
+                    &StructArray::try_from(arr)?,
+                    Some(&StructArray::try_from(l)?),
+                );
+            }
+
+            let start = Instant::now();
             if let Some(compressed) = l
                 .encoding()
                 .compression()
@@ -150,8 +532,9 @@ impl<'a> Compressor<'a> {
                 check_validity_unchanged(arr, &compressed);
                 check_dtype_unchanged(arr, &compressed);
 
+                self.trace_like(arr, &compressed, start)?;
                 return Ok(compressed);
-            } else {
+            } else if l.encoding().id() != arr.encoding().id() {
                 warn!(
                     "{} cannot find compressor to compress {} like {}",
                     self, arr, l
@@ -159,52 +542,97 @@ impl<'a> Compressor<'a> {
             }
         }
 
-        // Otherwise, attempt to compress the array
+        // Otherwise, attempt to compress the array. Arrays like one that was left in its canonical
+        // encoding are sampled too, since it has no encodings to reuse.
         let compressed = self.compress_array(arr)?;
         Ok(compressed)
     }
 
+    /// Record an array compressed like another array, for which no candidates were tried.
+    fn trace_like(&self, arr: &Array, compressed: &Array, start: Instant) -> VortexResult<()> {
+        if let Some(trace) = &self.trace {
+            trace.push(array_trace(
+                self,
+                arr,
+                Some(compressed),
+                false,
+                Vec::new(),
+                start,
+            )?);
+        }
+        Ok(())
+    }
+
     pub fn compress_validity(&self, validity: Validity) -> VortexResult<Validity> {
         match validity {
-            Validity::Array(a) => Ok(Validity::Array(self.compress(&a, None)?)),
+            // Validity that is sparse enough is stored as its exceptions, without first trying
+            // every other encoding on it.
+            Validity::Array(a) => match self.sparse_validity(&a)? {
+                Some(sparse) => Ok(Validity::Array(sparse)),
+                None => Ok(Validity::Array(self.compress(&a, None)?)),
+            },
             a => Ok(a),
         }
     }
 
+    /// Encode a validity array as a [`SparseArray`] holding the positions of its minority value.
+    ///
+    /// Columns that are almost entirely valid or almost entirely null then only store their few
+    /// exceptions, and can still be sliced, taken and queried for validity without expanding them.
+    fn sparse_validity(&self, validity: &Array) -> VortexResult<Option<Array>> {
+        let buffer = validity.clone().into_bool()?.boolean_buffer();
+        let true_count = buffer.count_set_bits();
+        let fill = true_count * 2 >= buffer.len();
+        let exceptions = if fill {
+            buffer.len() - true_count
+        } else {
+            true_count
+        };
+        if exceptions * SPARSE_VALIDITY_RATIO > buffer.len() {
+            return Ok(None);
+        }
+
+        let indices = if fill {
+            (!&buffer)
+                .set_indices()
+                .map(|i| i as u64)
+                .collect::<Vec<_>>()
+        } else {
+            buffer.set_indices().map(|i| i as u64).collect::<Vec<_>>()
+        };
+        let indices = self
+            .auxiliary("indices")
+            .compress(&PrimitiveArray::from(indices).into_array(), None)?;
+        Ok(Some(
+            SparseArray::try_new(
+                indices,
+                ConstantArray::new(!fill, exceptions).into_array(),
+                buffer.len(),
+                fill.into(),
+            )?
+            .into_array(),
+        ))
+    }
+
     fn compress_array(&self, arr: &Array) -> VortexResult<Array> {
         match arr.encoding().id() {
             Chunked::ID => {
                 // For chunked arrays, we compress each chunk individually
                 let chunked = ChunkedArray::try_from(arr)?;
-                let compressed_chunks: VortexResult<Vec<Array>> = chunked
-                    .chunks()
-                    .map(|chunk| self.compress_array(&chunk))
-                    .collect();
-                Ok(
-                    ChunkedArray::try_new(compressed_chunks?, chunked.dtype().clone())?
-                        .into_array(),
-                )
+                let compressed_chunks = self.compress_all(
+                    chunked
+                        .chunks()
+                        .enumerate()
+                        .map(|(idx, chunk)| (self.named(&format!("[{}]", idx)), chunk, None))
+                        .collect(),
+                )?;
+                Ok(ChunkedArray::try_new(compressed_chunks, chunked.dtype().clone())?.into_array())
             }
             Constant::ID => {
                 // Not much better we can do than constant!
                 Ok(arr.clone())
             }
-            Struct::ID => {
-                // For struct arrays, we compress each field individually
-                let strct = StructArray::try_from(arr)?;
-                let compressed_fields = strct
-                    .children()
-                    .map(|field| self.compress_array(&field))
-                    .collect::<VortexResult<Vec<_>>>()?;
-                let validity = self.compress_validity(strct.validity())?;
-                Ok(StructArray::try_new(
-                    strct.names().clone(),
-                    compressed_fields,
-                    strct.len(),
-                    validity,
-                )?
-                .into_array())
-            }
+            Struct::ID => self.compress_struct(&StructArray::try_from(arr)?, None),
             _ => {
                 // Otherwise, we run sampled compression over pluggable encodings
                 let sampled = sampled_compression(arr, self)?;
@@ -212,6 +640,101 @@ impl<'a> Compressor<'a> {
             }
         }
     }
+
+    /// Compress each field of a struct array individually, like the fields of `like` if given.
+    fn compress_struct(
+        &self,
+        strct: &StructArray,
+        like: Option<&StructArray>,
+    ) -> VortexResult<Array> {
+        let like_fields = like
+            .filter(|like| like.names() == strct.names())
+            .map(|like| like.children().map(Some).collect::<Vec<_>>())
+            .unwrap_or_else(|| vec![None; strct.names().len()]);
+        let compressed_fields = self.compress_all(
+            strct
+                .names()
+                .iter()
+                .zip(strct.children())
+                .zip(like_fields)
+                .map(|((name, field), like)| (self.field(name), field, like))
+                .collect(),
+        )?;
+        let validity = self.compress_validity(strct.validity())?;
+        Ok(StructArray::try_new(
+            strct.names().clone(),
+            compressed_fields,
+            strct.len(),
+            validity,
+        )?
+        .into_array())
+    }
+
+    /// Split and merge the chunks of an array into chunks of
+    /// [`block_size`](CompressConfig::block_size) rows, and compress each of them separately like
+    /// [`compress_chunk`](Self::compress_chunk).
+    pub fn compress_rechunked(&self, arr: &Array) -> VortexResult<ChunkedArray> {
+        let chunked = match ChunkedArray::try_from(arr) {
+            Ok(chunked) => chunked,
+            Err(_) => ChunkedArray::try_new(vec![arr.clone()], arr.dtype().clone())?,
+        };
+        let chunks = chunked
+            .rechunk(self.options.block_size as usize)?
+            .chunks()
+            .collect::<Vec<_>>();
+        let compressed = self.compress_all(
+            chunks
+                .iter()
+                .enumerate()
+                .map(|(idx, chunk)| (self.named(&format!("[{}]", idx)), chunk.clone(), None))
+                .collect(),
+        )?;
+        for (chunk, compressed) in chunks.iter().zip(&compressed) {
+            copy_chunk_statistics(chunk, compressed)?;
+        }
+        ChunkedArray::try_new(compressed, chunked.dtype().clone())
+    }
+
+    /// Compress one chunk of a larger array, optionally like another chunk, keeping the
+    /// statistics of its uncompressed values that readers use to prune chunks, including those
+    /// of each struct field.
+    pub fn compress_chunk(&self, chunk: &Array, like: Option<&Array>) -> VortexResult<Array> {
+        let compressed = self.compress(chunk, like)?;
+        copy_chunk_statistics(chunk, &compressed)?;
+        Ok(compressed)
+    }
+
+    /// Compress independent arrays with their own ctx and optional like array, in parallel if a
+    /// thread pool is configured, keeping their order. Each array is traced separately so the
+    /// trace doesn't depend on scheduling.
+    fn compress_all(&self, arrays: Vec<(Self, Array, Option<Array>)>) -> VortexResult<Vec<Array>> {
+        let compress = |(compressor, array, like): &(Self, Array, Option<Array>)| {
+            let mut compressor = compressor.clone();
+            compressor.trace = self.trace.as_ref().map(|_| CompressionTrace::new());
+            compressor
+                .compress(array, like.as_ref())
+                .map(|compressed| (compressed, compressor.trace))
+        };
+        let compressed = match &self.options.pool {
+            Some(pool) if arrays.len() > 1 => pool.install(|| {
+                arrays
+                    .par_iter()
+                    .map(compress)
+                    .collect::<VortexResult<Vec<_>>>()
+            }),
+            _ => arrays.iter().map(compress).collect(),
+        }?;
+
+        Ok(compressed
+            .into_iter()
+            .map(|(array, trace)| {
+                if let (Some(parent), Some(trace)) = (&self.trace, trace) {
+                    parent.append(&trace);
+                }
+                array
+            })
+            .collect())
+    }
 }
 
 /// Check that compression did not alter the length of the validity array.
@@ -231,6 +754,30 @@ fn check_validity_unchanged(arr: &Array, compressed: &Array) {
     }
 }
 
+/// Compute the statistics of an uncompressed chunk and set them on the compressed chunk, along
+/// with those of each struct field.
+fn copy_chunk_statistics(chunk: &Array, compressed: &Array) -> VortexResult<()> {
+    if chunk.encoding().id() == Struct::ID && compressed.encoding().id() == Struct::ID {
+        let chunk = StructArray::try_from(chunk)?;
+        let compressed = StructArray::try_from(compressed)?;
+        for (field, compressed_field) in chunk.children().zip(compressed.children()) {
+            copy_chunk_statistics(&field, &compressed_field)?;
+        }
+    }
+
+    for stat in [Stat::Min, Stat::Max] {
+        if let Some(bound) = chunk.statistics().compute_bound(stat) {
+            compressed.statistics().set_bound(stat, bound);
+        }
+    }
+    for stat in CHUNK_STATS {
+        if let Some(value) = chunk.statistics().compute(stat) {
+            compressed.statistics().set(stat, value);
+        }
+    }
+    Ok(())
+}
+
 /// Check that compression did not alter the dtype.
 fn check_dtype_unchanged(arr: &Array, compressed: &Array) {
     let _ = arr;
@@ -248,6 +795,72 @@ fn check_dtype_unchanged(arr: &Array, compressed: &Array) {
 }
 
 pub fn sampled_compression(array: &Array, compressor: &Compressor) -> VortexResult<Option<Array>> {
+    let Some(trace) = &compressor.trace else {
+        return choose_compression(array, compressor, &mut Vec::new(), &mut false);
+    };
+
+    let start = Instant::now();
+    let mut candidates = Vec::new();
+    let mut sampled = false;
+    let compressed = choose_compression(array, compressor, &mut candidates, &mut sampled)?;
+    trace.push(array_trace(
+        compressor,
+        array,
+        compressed.as_ref(),
+        sampled,
+        candidates,
+        start,
+    )?);
+    Ok(compressed)
+}
+
+/// The trace of an array compressed since `start`. Arrays compressed like another array have no
+/// candidates, and arrays that no candidate shrank have no compressed array.
+fn array_trace(
+    compressor: &Compressor,
+    array: &Array,
+    compressed: Option<&Array>,
+    sampled: bool,
+    candidates: Vec<CandidateTrace>,
+    start: Instant,
+) -> VortexResult<ArrayTrace> {
+    let elapsed = start.elapsed();
+    Ok(ArrayTrace {
+        path: compressor.path.join("."),
+        encoding: array.encoding().id().to_string(),
+        len: array.len(),
+        sampled,
+        candidates,
+        winner: compressed.map(|compressed| compressed.encoding().id().to_string()),
+        nbytes: compressed.unwrap_or(array).nbytes(),
+        canonical_nbytes: array.clone().into_canonical()?.into_array().nbytes(),
+        elapsed_micros: elapsed.as_micros() as u64,
+    })
+}
+
+fn choose_compression(
+    array: &Array,
+    compressor: &Compressor,
+    tried: &mut Vec<CandidateTrace>,
+    sampled: &mut bool,
+) -> VortexResult<Option<Array>> {
+    if let Some(encoding) = compressor.forced_encoding {
+        if let Some(compression) = encoding
+            .compression()
+            .can_compress(array, compressor.options())
+        {
+            return compression
+                .compress(array, None, compressor.for_encoding(compression))
+                .map(Some);
+        }
+        warn!(
+            "{} cannot compress {} with forced encoding {}",
+            compressor,
+            array,
+            encoding.id()
+        );
+    }
+
     // First, we try constant compression and shortcut any sampling.
     if !array.is_empty() && array.statistics().compute_is_constant().unwrap_or(false) {
         return Ok(Some(
@@ -279,6 +892,8 @@ pub fn sampled_compression(array: &Array, compressor: &Compressor) -> VortexResu
             }
         })
         .collect();
+    // The context does not order its encodings, sort them so ties are always broken the same way.
+    candidates.sort_by_key(|compression| compression.id());
     debug!("{} candidates for {}: {:?}", compressor, array, candidates);
 
     if candidates.is_empty() {
@@ -306,16 +921,34 @@ pub fn sampled_compression(array: &Array, compressor: &Compressor) -> VortexResu
         <= (compressor.options.sample_size as usize * compressor.options.sample_count as usize)
     {
         // We're either already within a sample, or we're operating over a sufficiently small array.
-        return find_best_compression(candidates, array, compressor)
+        return find_best_compression(candidates, array, compressor, tried)
             .map(|best| best.map(|(_compression, best)| best));
     }
 
     // Take a sample of the array, then ask codecs for their best compression estimate.
-    let sample = ChunkedArray::try_new(
+    *sampled = true;
+    let sample = sample(array, compressor.options())?;
+
+    find_best_compression(candidates, &sample, compressor, tried)?
+        .map(|(compression, best)| {
+            info!("{} compressing array {} like {}", compressor, array, best);
+            compressor
+                .for_encoding(compression)
+                .compress(array, Some(&best))
+        })
+        .transpose()
+}
+
+/// Take `sample_count` slices of `sample_size` values from the array, at offsets chosen by the
+/// config's seed, and concatenate them into a canonical array.
+pub fn sample(array: &Array, config: &CompressConfig) -> VortexResult<Array> {
+    // Every sampled array reseeds the RNG, so its samples don't depend on what was compressed before.
+    Ok(ChunkedArray::try_new(
         stratified_slices(
             array.len(),
-            compressor.options.sample_size,
-            compressor.options.sample_count,
+            config.sample_size,
+            config.sample_count,
+            &mut StdRng::seed_from_u64(config.rng_seed),
         )
         .into_iter()
         .map(|(start, stop)| slice(array, start, stop))
@@ -323,25 +956,17 @@ pub fn sampled_compression(array: &Array, compressor: &Compressor) -> VortexResu
         array.dtype().clone(),
     )?
     .into_canonical()?
-    .into_array();
-
-    find_best_compression(candidates, &sample, compressor)?
-        .map(|(compression, best)| {
-            info!("{} compressing array {} like {}", compressor, array, best);
-            compressor
-                .for_encoding(compression)
-                .compress(array, Some(&best))
-        })
-        .transpose()
+    .into_array())
 }
 
 fn find_best_compression<'a>(
     candidates: Vec<&'a dyn EncodingCompression>,
     sample: &Array,
     ctx: &Compressor,
+    tried: &mut Vec<CandidateTrace>,
 ) -> VortexResult<Option<(&'a dyn EncodingCompression, Array)>> {
     let mut best = None;
-    let mut best_ratio = 1.0;
+    let mut best_score = None;
     for compression in candidates {
         debug!(
             "{} trying candidate {} for {}",
@@ -353,14 +978,264 @@ fn find_best_compression<'a>(
             continue;
         }
         let compressed_sample =
-            compression.compress(sample, None, ctx.for_encoding(compression))?;
+            compression.compress(sample, None, ctx.untraced().for_encoding(compression))?;
         let compressed_size = compression.compressed_nbytes(&compressed_sample);
         let ratio = compressed_size as f32 / sample.with_dyn(|a| a.nbytes()) as f32;
-        debug!("{} ratio for {}: {}", ctx, compression.id(), ratio);
-        if ratio < best_ratio {
-            best_ratio = ratio;
+        let cost = decode_cost(&compressed_sample);
+        debug!(
+            "{} ratio for {}: {}, decode cost: {}",
+            ctx,
+            compression.id(),
+            ratio,
+            cost
+        );
+        tried.push(CandidateTrace {
+            encoding: compression.id().to_string(),
+            ratio,
+            decode_cost: cost,
+        });
+        if ratio >= 1.0 {
+            continue;
+        }
+        let score = ctx.options().objective().score(ratio, cost);
+        if best_score.map_or(true, |best_score| score < best_score) {
+            best_score = Some(score);
             best = Some((compression, compressed_sample))
         }
     }
     Ok(best)
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use vortex_dtype::field::FieldPath;
+
+    use crate::array::chunked::ChunkedArray;
+    use crate::array::constant::{Constant, ConstantArray};
+    use crate::array::primitive::PrimitiveArray;
+    use crate::array::sparse::Sparse;
+    use crate::array::sparse::SparseArray;
+    use crate::array::struct_::StructArray;
+    use crate::compress::{
+        decode_cost, CompressConfig, CompressionObjective, CompressionTrace, Compressor,
+        FieldOverride,
+    };
+    use crate::stats::{ArrayStatistics, BloomFilter, Stat};
+    use crate::validity::{ArrayValidity, Validity};
+    use crate::{ArrayDType, ArrayDef, Context, IntoArray};
+
+    #[test]
+    fn test_decode_cost() {
+        let sparse = SparseArray::try_new(
+            PrimitiveArray::from(vec![1u64, 5]).into_array(),
+            PrimitiveArray::from(vec![7i32, 9]).into_array(),
+            10,
+            0i32.into(),
+        )
+        .unwrap()
+        .into_array();
+        assert_eq!(decode_cost(&sparse), 0.5);
+        assert_eq!(decode_cost(&ConstantArray::new(1i32, 10).into_array()), 0.1);
+        assert_eq!(
+            decode_cost(&PrimitiveArray::from(vec![1i32]).into_array()),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_objective_score() {
+        let (small_slow, large_fast) = ((0.2, 2.0), (0.5, 0.5));
+        let better = |objective: CompressionObjective, a: (f32, f32), b: (f32, f32)| {
+            objective.score(a.0, a.1) < objective.score(b.0, b.1)
+        };
+        assert!(better(
+            CompressionObjective::MinSize,
+            small_slow,
+            large_fast
+        ));
+        assert!(better(
+            CompressionObjective::MinDecodeCost,
+            large_fast,
+            small_slow
+        ));
+        assert!(better(
+            CompressionObjective::Weighted {
+                decode_cost_weight: 0.1
+            },
+            small_slow,
+            large_fast
+        ));
+        assert!(better(
+            CompressionObjective::Weighted {
+                decode_cost_weight: 1.0
+            },
+            large_fast,
+            small_slow
+        ));
+    }
+
+    #[test]
+    fn test_sparse_validity() {
+        let valid = (0..1000).map(|i| i % 250 != 7).collect::<Vec<_>>();
+        let array = StructArray::try_new(
+            ["a".into()].into(),
+            vec![PrimitiveArray::from((0..1000u32).collect::<Vec<_>>()).into_array()],
+            1000,
+            Validity::from(valid.clone()),
+        )
+        .unwrap()
+        .into_array();
+
+        let ctx = Context::default();
+        let compressed =
+            StructArray::try_from(Compressor::new(&ctx).compress(&array, None).unwrap()).unwrap();
+        let validity = compressed.validity();
+        assert_eq!(validity.array().unwrap().encoding().id(), Sparse::ID);
+
+        for (i, v) in valid.iter().enumerate() {
+            assert_eq!(compressed.is_valid(i), *v);
+        }
+        assert!(!validity.slice(250, 500).unwrap().is_valid(7));
+        let indices = PrimitiveArray::from(vec![507u32, 8]).into_array();
+        let taken = validity.take(&indices).unwrap();
+        assert!(!taken.is_valid(0));
+        assert!(taken.is_valid(1));
+    }
+
+    #[test]
+    fn test_bloom_filter() {
+        let chunk = StructArray::try_new(
+            ["id".into(), "b".into()].into(),
+            vec![
+                PrimitiveArray::from((0..1000i64).map(|i| i * 7).collect::<Vec<_>>()).into_array(),
+                PrimitiveArray::from((0..1000i32).collect::<Vec<_>>()).into_array(),
+            ],
+            1000,
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array();
+        let array =
+            ChunkedArray::try_new(vec![chunk.clone(), chunk.clone()], chunk.dtype().clone())
+                .unwrap()
+                .into_array();
+
+        let ctx = Context::default();
+        let config = CompressConfig::default().with_field_override(
+            FieldPath::from_name("id"),
+            FieldOverride::default().with_bloom_filter(0.01),
+        );
+        let compressed = ChunkedArray::try_from(
+            Compressor::new_with_options(&ctx, config)
+                .compress(&array, None)
+                .unwrap(),
+        )
+        .unwrap();
+        for chunk in compressed.chunks() {
+            let chunk = StructArray::try_from(chunk).unwrap();
+            let filter = chunk
+                .field(0)
+                .unwrap()
+                .statistics()
+                .get_as::<BloomFilter>(Stat::BloomFilter)
+                .unwrap();
+            assert!(filter.might_contain(&700i64.to_le_bytes()));
+            assert!(chunk
+                .field(1)
+                .unwrap()
+                .statistics()
+                .get(Stat::BloomFilter)
+                .is_none());
+        }
+    }
+
+    #[test]
+    fn test_trace() {
+        let chunk = StructArray::try_new(
+            ["a".into(), "b".into()].into(),
+            vec![
+                PrimitiveArray::from(vec![7i32; 1000]).into_array(),
+                PrimitiveArray::from((0..1000i32).collect::<Vec<_>>()).into_array(),
+            ],
+            1000,
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array();
+        let array =
+            ChunkedArray::try_new(vec![chunk.clone(), chunk.clone()], chunk.dtype().clone())
+                .unwrap()
+                .into_array();
+
+        let ctx = Context::default();
+        let trace = |threads| {
+            let trace = CompressionTrace::new();
+            Compressor::new_with_options(&ctx, CompressConfig::default().with_threads(threads))
+                .with_trace(trace.clone())
+                .compress(&array, None)
+                .unwrap();
+            let mut arrays = trace.arrays();
+            arrays.iter_mut().for_each(|a| a.elapsed_micros = 0);
+            arrays
+        };
+
+        let arrays = trace(1);
+        assert_eq!(
+            arrays.iter().map(|a| a.path.as_str()).collect::<Vec<_>>(),
+            vec!["[0].a", "[0].b", "[1].a", "[1].b"]
+        );
+        assert_eq!(arrays[0].winner, Some(Constant::ID.to_string()));
+        assert!(arrays[0].nbytes < arrays[0].canonical_nbytes);
+        assert_eq!(arrays[1].winner, None);
+        assert_eq!(arrays[1].nbytes, arrays[1].canonical_nbytes);
+        assert_eq!(arrays, trace(4));
+    }
+
+    #[test]
+    fn test_thread_pool_is_shared() {
+        let ctx = Context::default();
+        let config = CompressConfig::default().with_threads(2);
+        let compressor = Compressor::new_with_options(&ctx, config.clone()).named("a");
+        let pool = |config: &CompressConfig| config.pool.clone().unwrap();
+        assert!(Arc::ptr_eq(&pool(&config), &pool(&compressor.options)));
+
+        assert!(CompressConfig::default().pool.is_none());
+    }
+
+    #[test]
+    fn test_rechunk() {
+        let chunk = |start: i32| {
+            StructArray::try_new(
+                ["a".into()].into(),
+                vec![PrimitiveArray::from((start..start + 1000).collect::<Vec<_>>()).into_array()],
+                1000,
+                Validity::NonNullable,
+            )
+            .unwrap()
+            .into_array()
+        };
+        let chunks = (0..5).map(|i| chunk(i * 1000)).collect::<Vec<_>>();
+        let dtype = chunks[0].dtype().clone();
+        let array = ChunkedArray::try_new(chunks, dtype).unwrap().into_array();
+
+        let ctx = Context::default();
+        let config = CompressConfig::default().with_block_size(2048);
+        let compressed = Compressor::new_with_options(&ctx, config)
+            .compress_rechunked(&array)
+            .unwrap();
+        assert_eq!(
+            compressed.chunks().map(|c| c.len()).collect::<Vec<_>>(),
+            vec![2048, 2048, 904]
+        );
+
+        let field = StructArray::try_from(compressed.chunk(1).unwrap())
+            .unwrap()
+            .field(0)
+            .unwrap();
+        assert_eq!(field.statistics().get(Stat::Min), Some(2048i32.into()));
+        assert_eq!(field.statistics().get(Stat::Max), Some(4095i32.into()));
+        assert_eq!(field.statistics().get(Stat::IsSorted), Some(true.into()));
+    }
+}
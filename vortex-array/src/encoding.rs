@@ -9,7 +9,7 @@ use crate::compress::EncodingCompression;
 use crate::ArrayDef;
 use crate::{Array, ArrayTrait};
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct EncodingId(&'static str);
 
 impl EncodingId {
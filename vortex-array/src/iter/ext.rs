@@ -2,6 +2,7 @@ use itertools::Itertools;
 use vortex_error::VortexResult;
 
 use crate::array::chunked::ChunkedArray;
+use crate::arrow::ArrayRecordBatchReader;
 use crate::iter::ArrayIterator;
 use crate::stream::{ArrayStream, ArrayStreamAdapter};
 
@@ -20,4 +21,14 @@ pub trait ArrayIteratorExt: ArrayIterator {
         let dtype = self.dtype().clone();
         ChunkedArray::try_new(self.try_collect()?, dtype)
     }
+
+    /// Convert into an Arrow `RecordBatchReader` yielding batches of at most `max_rows` rows.
+    fn into_record_batch_reader(self, max_rows: usize) -> VortexResult<ArrayRecordBatchReader<Self>>
+    where
+        Self: Sized,
+    {
+        ArrayRecordBatchReader::try_new(self, max_rows)
+    }
 }
+
+impl<I: ArrayIterator> ArrayIteratorExt for I {}
@@ -1,9 +1,12 @@
 use rand::Rng;
 
-pub fn stratified_slices(
+/// Choose `sample_count` slices of `sample_size` elements, one at a random offset within each of
+/// `sample_count` equal partitions of `length`. The offsets are drawn from `rng`.
+pub fn stratified_slices<R: Rng>(
     length: usize,
     sample_size: u16,
     sample_count: u16,
+    rng: &mut R,
 ) -> Vec<(usize, usize)> {
     let total_num_samples: usize = (sample_count * sample_size) as usize;
     if total_num_samples >= length {
@@ -24,7 +27,7 @@ pub fn stratified_slices(
                 stop - start >= size,
                 "Slices must be bigger than their sampled size"
             );
-            let random_start = rand::thread_rng().gen_range(start..=(stop - size));
+            let random_start = rng.gen_range(start..=(stop - size));
             (random_start, random_start + size)
         })
         .collect()
@@ -51,7 +54,10 @@ pub fn partition_indices(length: usize, num_partitions: u16) -> Vec<(usize, usiz
 
 #[cfg(test)]
 mod test {
-    use crate::sampling::partition_indices;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::sampling::{partition_indices, stratified_slices};
 
     #[test]
     pub fn partitioning_non_even() {
@@ -68,4 +74,15 @@ mod test {
             vec![(0, 5), (5, 10), (10, 15), (15, 20), (20, 25)]
         );
     }
+
+    #[test]
+    pub fn stratified_slices_seeded() {
+        let slices = |seed| stratified_slices(100_000, 128, 8, &mut StdRng::seed_from_u64(seed));
+        assert_eq!(slices(0), slices(0));
+        assert_ne!(slices(0), slices(1));
+        for (i, (start, stop)) in slices(0).into_iter().enumerate() {
+            assert_eq!(stop - start, 128);
+            assert!(start >= i * 12_500 && stop <= (i + 1) * 12_500);
+        }
+    }
 }
@@ -0,0 +1,337 @@
+use std::f64::consts::LN_2;
+
+use vortex_buffer::Buffer;
+use vortex_dtype::Nullability::NonNullable;
+use vortex_dtype::{match_each_native_ptype, DType, NativePType};
+use vortex_error::{vortex_bail, VortexError, VortexResult};
+use vortex_expr::{Disjunction, Operator, Predicate, Value};
+use vortex_scalar::{PrimitiveScalar, Scalar};
+
+use crate::accessor::ArrayAccessor;
+use crate::array::struct_::StructArray;
+use crate::array::varbinview::{VarBinView, VarBinViewArray};
+use crate::stats::hash::hash_bytes;
+use crate::stats::{ArrayStatistics, Stat};
+use crate::{Array, ArrayDType, ArrayDef, IntoArrayVariant};
+
+const MAX_HASHES: u32 = 16;
+
+/// A bloom filter of the non-null values of an array, stored as its
+/// [`BloomFilter`](Stat::BloomFilter) statistic so that readers can skip chunks that cannot
+/// contain a value, see [`can_skip`].
+///
+/// Filters are only built for the struct fields configured with
+/// [`FieldOverride::with_bloom_filter`](crate::compress::FieldOverride::with_bloom_filter).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BloomFilter {
+    num_hashes: u32,
+    bits: Vec<u64>,
+}
+
+impl BloomFilter {
+    /// An empty filter sized for `num_values` values, such that it reports a value it doesn't
+    /// contain with at most the given probability.
+    pub fn with_false_positive_rate(num_values: usize, false_positive_rate: f64) -> Self {
+        let num_values = num_values.max(1) as f64;
+        let false_positive_rate = false_positive_rate.clamp(f64::MIN_POSITIVE, 1.0);
+        let num_bits = (-num_values * false_positive_rate.ln() / (LN_2 * LN_2)).ceil();
+        let num_words = ((num_bits / 64.0).ceil() as usize).max(1);
+        let num_hashes = ((num_words * 64) as f64 / num_values * LN_2).round() as u32;
+        Self {
+            num_hashes: num_hashes.clamp(1, MAX_HASHES),
+            bits: vec![0; num_words],
+        }
+    }
+
+    /// Build a filter of the values of an array with a primitive, utf8 or binary dtype, decoding
+    /// the array to its canonical form first.
+    pub fn try_build(array: &Array, false_positive_rate: f64) -> VortexResult<Self> {
+        let mut filter = Self::with_false_positive_rate(array.len(), false_positive_rate);
+        match array.dtype() {
+            DType::Primitive(..) => {
+                let primitive = array.clone().into_primitive()?;
+                match_each_native_ptype!(primitive.ptype(), |$T| {
+                    ArrayAccessor::<$T>::with_iterator(&primitive, |iter| {
+                        iter.flatten()
+                            .for_each(|value| filter.insert(&normalize(*value).to_le_bytes()))
+                    })?
+                })
+            }
+            DType::Utf8(_) | DType::Binary(_) if array.encoding().id() == VarBinView::ID => {
+                VarBinViewArray::try_from(array)?
+                    .with_iterator(|iter| iter.flatten().for_each(|value| filter.insert(value)))?
+            }
+            DType::Utf8(_) | DType::Binary(_) => array
+                .clone()
+                .into_varbin()?
+                .with_iterator(|iter| iter.flatten().for_each(|value| filter.insert(value)))?,
+            dtype => vortex_bail!("Bloom filters don't support {}", dtype),
+        }
+        Ok(filter)
+    }
+
+    pub fn insert(&mut self, value: &[u8]) {
+        for bit in self.bit_indices(value) {
+            self.bits[bit / 64] |= 1 << (bit % 64);
+        }
+    }
+
+    /// Whether the value may have been inserted. Values that were inserted are always reported.
+    pub fn might_contain(&self, value: &[u8]) -> bool {
+        self.bit_indices(value)
+            .all(|bit| self.bits[bit / 64] & (1 << (bit % 64)) != 0)
+    }
+
+    /// Whether an array with the given dtype may contain the scalar. Nulls are never contained,
+    /// and neither are numbers that can't be cast to the array's type, e.g. because they are out
+    /// of its range.
+    pub fn might_contain_scalar(&self, value: &Scalar, dtype: &DType) -> VortexResult<bool> {
+        if value.is_null() {
+            return Ok(false);
+        }
+        let bytes = match dtype {
+            DType::Primitive(ptype, _) => {
+                let Ok(value) = PrimitiveScalar::try_from(value)
+                    .and_then(|value| value.cast(&DType::Primitive(*ptype, NonNullable)))
+                else {
+                    return Ok(false);
+                };
+                let value = PrimitiveScalar::try_from(&value)?;
+                match_each_native_ptype!(ptype, |$T| {
+                    value.typed_value::<$T>().map(|v| normalize(v).to_le_bytes().to_vec())
+                })
+            }
+            DType::Utf8(_) => value
+                .value()
+                .as_buffer_string()?
+                .map(|value| value.as_str().as_bytes().to_vec()),
+            DType::Binary(_) => value.value().as_buffer()?.map(|value| value.to_vec()),
+            _ => vortex_bail!("Bloom filters don't support {}", dtype),
+        };
+        Ok(bytes.is_some_and(|bytes| self.might_contain(&bytes)))
+    }
+
+    /// Combine the filter of another array into this one, yielding the filter of both arrays.
+    /// Filters can only be merged if they have the same size.
+    pub fn merge(&mut self, other: &Self) -> VortexResult<()> {
+        if self.num_hashes != other.num_hashes || self.bits.len() != other.bits.len() {
+            vortex_bail!("Cannot merge bloom filters of different sizes");
+        }
+        self.bits
+            .iter_mut()
+            .zip(other.bits.iter())
+            .for_each(|(own, other)| *own |= *other);
+        Ok(())
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(4 + self.bits.len() * 8);
+        bytes.extend_from_slice(&self.num_hashes.to_le_bytes());
+        self.bits
+            .iter()
+            .for_each(|word| bytes.extend_from_slice(&u64::to_le_bytes(*word)));
+        bytes
+    }
+
+    pub fn try_from_bytes(bytes: &[u8]) -> VortexResult<Self> {
+        if bytes.len() < 12 || (bytes.len() - 4) % 8 != 0 {
+            vortex_bail!("Invalid bloom filter of {} bytes", bytes.len());
+        }
+        let (num_hashes, bits) = bytes.split_at(4);
+        let num_hashes = u32::from_le_bytes(num_hashes.try_into().unwrap());
+        if num_hashes == 0 || num_hashes > MAX_HASHES {
+            vortex_bail!("Invalid bloom filter with {} hashes", num_hashes);
+        }
+        Ok(Self {
+            num_hashes,
+            bits: bits
+                .chunks_exact(8)
+                .map(|word| u64::from_le_bytes(word.try_into().unwrap()))
+                .collect(),
+        })
+    }
+
+    /// Derive the bits of a value from two halves of its hash, see Kirsch and Mitzenmacher,
+    /// "Less Hashing, Same Performance: Building a Better Bloom Filter".
+    fn bit_indices(&self, value: &[u8]) -> impl Iterator<Item = usize> {
+        let hash = hash_bytes(value);
+        let (h1, h2) = (hash & 0xffff_ffff, (hash >> 32) | 1);
+        let num_bits = self.bits.len() as u64 * 64;
+        (0..self.num_hashes as u64).map(move |i| (h1.wrapping_add(i * h2) % num_bits) as usize)
+    }
+}
+
+/// Floats that compare equal must be hashed the same, so `-0.0` is hashed as `0.0`. All NaNs are
+/// hashed as the same NaN, such that a NaN literal matches any chunk that contains a NaN.
+fn normalize<T: NativePType>(value: T) -> T {
+    if value.partial_cmp(&value).is_none() {
+        T::from_f64(f64::NAN).unwrap_or(value)
+    } else if value == T::zero() {
+        T::zero()
+    } else {
+        value
+    }
+}
+
+impl From<BloomFilter> for Scalar {
+    fn from(value: BloomFilter) -> Self {
+        Scalar::binary(Buffer::from(value.to_bytes()), NonNullable)
+    }
+}
+
+impl TryFrom<&Scalar> for BloomFilter {
+    type Error = VortexError;
+
+    fn try_from(value: &Scalar) -> VortexResult<Self> {
+        Self::try_from_bytes(Buffer::try_from(value)?.as_slice())
+    }
+}
+
+/// Whether the statistics stored with the fields of a struct chunk prove that none of its rows
+/// satisfy `predicate`, so that a reader can skip the chunk without decoding it.
+///
+/// Only equality with a literal is checked, against the field's bloom filter if it has one.
+pub fn can_skip(chunk: &StructArray, predicate: &Predicate) -> VortexResult<bool> {
+    let (Operator::Eq, Value::Literal(value)) = (predicate.op, &predicate.rhs) else {
+        return Ok(false);
+    };
+    // Rows of null parent structs don't match, so the values as stored are a superset.
+    let (field, _) = chunk.field_and_validity_by_path(&predicate.lhs)?;
+    let Some(filter) = field.statistics().get(Stat::BloomFilter) else {
+        return Ok(false);
+    };
+    Ok(!BloomFilter::try_from(&filter)?.might_contain_scalar(value, field.dtype())?)
+}
+
+/// Whether every conjunction of the filter has a predicate for which [`can_skip`] holds, such that
+/// none of the rows of the chunk satisfy the filter.
+pub fn can_skip_filter(chunk: &StructArray, filter: &Disjunction) -> VortexResult<bool> {
+    let mut skip = false;
+    for conjunction in filter.iter() {
+        skip = false;
+        for predicate in conjunction.iter() {
+            if can_skip(chunk, predicate)? {
+                skip = true;
+                break;
+            }
+        }
+        if !skip {
+            return Ok(false);
+        }
+    }
+    Ok(skip)
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::field::FieldPath;
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_expr::lit;
+    use vortex_scalar::Scalar;
+
+    use crate::array::bool::BoolArray;
+    use crate::array::constant::ConstantArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::array::struct_::StructArray;
+    use crate::array::varbin::VarBinArray;
+    use crate::stats::{can_skip, ArrayStatistics, BloomFilter, Stat};
+    use crate::validity::Validity;
+    use crate::{ArrayDType, IntoArray};
+
+    #[test]
+    fn false_positive_rate() {
+        let mut filter = BloomFilter::with_false_positive_rate(10_000, 0.01);
+        (0u32..10_000).for_each(|v| filter.insert(&v.to_le_bytes()));
+        assert!((0u32..10_000).all(|v| filter.might_contain(&v.to_le_bytes())));
+
+        let false_positives = (10_000u32..110_000)
+            .filter(|v| filter.might_contain(&v.to_le_bytes()))
+            .count();
+        assert!(false_positives < 2_000, "{false_positives}");
+    }
+
+    #[test]
+    fn bytes_roundtrip() {
+        let mut filter = BloomFilter::with_false_positive_rate(100, 0.05);
+        filter.insert(b"hello");
+        let scalar = Scalar::from(filter.clone());
+        assert_eq!(BloomFilter::try_from(&scalar).unwrap(), filter);
+    }
+
+    #[test]
+    fn equal_floats() {
+        let floats = PrimitiveArray::from(vec![-0.0f64, f64::NAN]).into_array();
+        let filter = BloomFilter::try_build(&floats, 0.001).unwrap();
+        assert!(filter
+            .might_contain_scalar(&0.0f64.into(), floats.dtype())
+            .unwrap());
+        assert!(filter
+            .might_contain_scalar(&(-f64::NAN).into(), floats.dtype())
+            .unwrap());
+    }
+
+    #[test]
+    fn build_encoded() {
+        let constant = ConstantArray::new(7i64, 10).into_array();
+        let filter = BloomFilter::try_build(&constant, 0.001).unwrap();
+        assert!(filter
+            .might_contain_scalar(&7i64.into(), constant.dtype())
+            .unwrap());
+        assert!(!filter
+            .might_contain_scalar(&8i64.into(), constant.dtype())
+            .unwrap());
+
+        let bools = BoolArray::from(vec![true, false]).into_array();
+        assert!(BloomFilter::try_build(&bools, 0.001).is_err());
+    }
+
+    #[test]
+    fn skip_chunks() {
+        let ids = PrimitiveArray::from_nullable_vec(vec![Some(1i64), None, Some(5)]).into_array();
+        let names =
+            VarBinArray::from_vec(vec!["a", "b", "c"], DType::Utf8(Nullability::NonNullable))
+                .into_array();
+        for field in [&ids, &names] {
+            let filter = BloomFilter::try_build(field, 0.001).unwrap();
+            field.statistics().set(Stat::BloomFilter, filter.into());
+        }
+        let chunk = StructArray::try_new(
+            ["id".into(), "name".into()].into(),
+            vec![ids, names],
+            3,
+            Validity::NonNullable,
+        )
+        .unwrap();
+
+        let eq = |field: &str, value: Scalar| lit(value).eq(FieldPath::from_name(field));
+        // The literal is cast to the type of the field.
+        assert!(!can_skip(&chunk, &eq("id", 5i32.into())).unwrap());
+        assert!(can_skip(&chunk, &eq("id", 4i32.into())).unwrap());
+        assert!(!can_skip(&chunk, &eq("name", "c".into())).unwrap());
+        assert!(can_skip(&chunk, &eq("name", "d".into())).unwrap());
+        // Only equality is checked.
+        assert!(!can_skip(&chunk, &lit(4i64).gt(FieldPath::from_name("id"))).unwrap());
+    }
+
+    #[test]
+    fn skip_uncastable_literals() {
+        let ids = PrimitiveArray::from(vec![1i32, 5]).into_array();
+        let filter = BloomFilter::try_build(&ids, 0.001).unwrap();
+        ids.statistics().set(Stat::BloomFilter, filter.into());
+        let chunk = StructArray::try_new(["id".into()].into(), vec![ids], 2, Validity::NonNullable)
+            .unwrap();
+
+        let eq = |value: Scalar| lit(value).eq(FieldPath::from_name("id"));
+        // A literal out of the range of the field can't be equal to any of its values.
+        assert!(can_skip(&chunk, &eq(5_000_000_000i64.into())).unwrap());
+        // Nor can a null literal.
+        assert!(can_skip(
+            &chunk,
+            &eq(Scalar::null(DType::Primitive(
+                PType::I32,
+                Nullability::Nullable
+            )))
+        )
+        .unwrap());
+    }
+}
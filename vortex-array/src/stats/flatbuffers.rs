@@ -2,7 +2,7 @@ use flatbuffers::{FlatBufferBuilder, WIPOffset};
 use itertools::Itertools;
 use vortex_flatbuffers::WriteFlatBuffer;
 
-use crate::stats::{Stat, Statistics};
+use crate::stats::{BloomFilter, Stat, Statistics};
 
 impl WriteFlatBuffer for &dyn Statistics {
     type Target<'t> = crate::flatbuffers::ArrayStats<'t>;
@@ -29,6 +29,10 @@ impl WriteFlatBuffer for &dyn Statistics {
             .get(Stat::Max)
             .map(|max| max.value().write_flatbuffer(fbb));
 
+        let bloom_filter = self
+            .get_as::<BloomFilter>(Stat::BloomFilter)
+            .map(|v| fbb.create_vector(v.to_bytes().as_slice()));
+
         let stat_args = &crate::flatbuffers::ArrayStatsArgs {
             min,
             max,
@@ -40,6 +44,10 @@ impl WriteFlatBuffer for &dyn Statistics {
             null_count: self.get_as_cast::<u64>(Stat::NullCount),
             bit_width_freq,
             trailing_zero_freq,
+            distinct_count: self.get_as_cast::<u64>(Stat::DistinctCount),
+            bloom_filter,
+            is_min_truncated: self.get_as::<bool>(Stat::IsMinTruncated),
+            is_max_truncated: self.get_as::<bool>(Stat::IsMaxTruncated),
         };
 
         crate::flatbuffers::ArrayStats::create(fbb, stat_args)
@@ -0,0 +1,23 @@
+/// A fixed 64-bit hash of a value's bytes, so that sketches and filters stored with an array don't
+/// depend on a per-process random state.
+pub(super) fn hash_bytes(bytes: &[u8]) -> u64 {
+    const K: u64 = 0x9e37_79b9_7f4a_7c15;
+    let mut hash = (bytes.len() as u64).wrapping_mul(K);
+    for chunk in bytes.chunks(8) {
+        let mut word = [0u8; 8];
+        word[..chunk.len()].copy_from_slice(chunk);
+        hash = (hash ^ mix(u64::from_le_bytes(word)))
+            .rotate_left(27)
+            .wrapping_mul(K);
+    }
+    mix(hash)
+}
+
+/// The 64-bit finalizer of MurmurHash3.
+fn mix(mut h: u64) -> u64 {
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+    h ^ (h >> 33)
+}
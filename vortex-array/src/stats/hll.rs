@@ -0,0 +1,152 @@
+use vortex_buffer::Buffer;
+use vortex_dtype::Nullability::NonNullable;
+use vortex_error::{vortex_bail, VortexError, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::stats::hash::hash_bytes;
+
+/// Number of bits of the hash used to select a register.
+const PRECISION: u32 = 10;
+const NUM_REGISTERS: usize = 1 << PRECISION;
+
+/// A HyperLogLog sketch estimating the number of distinct values inserted into it.
+///
+/// Sketches are kept in memory as the
+/// [`DistinctCountSketch`](crate::stats::Stat::DistinctCountSketch) statistic, so that the distinct
+/// counts of two arrays can be combined without revisiting their values. Only the estimate is
+/// written alongside an array, not the sketch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HyperLogLog {
+    registers: Vec<u8>,
+}
+
+impl Default for HyperLogLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HyperLogLog {
+    pub fn new() -> Self {
+        Self {
+            registers: vec![0; NUM_REGISTERS],
+        }
+    }
+
+    /// Insert the byte representation of a value into the sketch.
+    pub fn insert(&mut self, value: &[u8]) {
+        let hash = hash_bytes(value);
+        let index = (hash >> (u64::BITS - PRECISION)) as usize;
+        // Count the leading zeros of the remaining bits, bounding the rank by their number.
+        let rank = ((hash << PRECISION) | (1 << (PRECISION - 1))).leading_zeros() as u8 + 1;
+        if rank > self.registers[index] {
+            self.registers[index] = rank;
+        }
+    }
+
+    /// Combine the sketch of another set of values into this one, yielding the sketch of their
+    /// union.
+    pub fn merge(&mut self, other: &Self) {
+        self.registers
+            .iter_mut()
+            .zip(other.registers.iter())
+            .for_each(|(own, other)| *own = (*own).max(*other));
+    }
+
+    /// Estimate the number of distinct values inserted into the sketch.
+    pub fn estimate(&self) -> usize {
+        let m = NUM_REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let sum: f64 = self.registers.iter().map(|r| 2f64.powi(-(*r as i32))).sum();
+        let estimate = alpha * m * m / sum;
+
+        // Fall back to linear counting while many registers are still empty.
+        let zeros = self.registers.iter().filter(|r| **r == 0).count();
+        if estimate <= 2.5 * m && zeros > 0 {
+            (m * (m / zeros as f64).ln()).round() as usize
+        } else {
+            estimate.round() as usize
+        }
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.registers
+    }
+
+    pub fn try_from_bytes(bytes: &[u8]) -> VortexResult<Self> {
+        if bytes.len() != NUM_REGISTERS {
+            vortex_bail!(
+                "Expected {} HyperLogLog registers, found {}",
+                NUM_REGISTERS,
+                bytes.len()
+            );
+        }
+        Ok(Self {
+            registers: bytes.to_vec(),
+        })
+    }
+}
+
+impl<'a> FromIterator<&'a [u8]> for HyperLogLog {
+    fn from_iter<T: IntoIterator<Item = &'a [u8]>>(iter: T) -> Self {
+        let mut hll = Self::new();
+        iter.into_iter().for_each(|value| hll.insert(value));
+        hll
+    }
+}
+
+impl From<HyperLogLog> for Scalar {
+    fn from(value: HyperLogLog) -> Self {
+        Scalar::binary(Buffer::from(value.registers), NonNullable)
+    }
+}
+
+impl TryFrom<&Scalar> for HyperLogLog {
+    type Error = VortexError;
+
+    fn try_from(value: &Scalar) -> VortexResult<Self> {
+        Self::try_from_bytes(Buffer::try_from(value)?.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_scalar::Scalar;
+
+    use crate::stats::HyperLogLog;
+
+    fn sketch(values: impl Iterator<Item = u64>) -> HyperLogLog {
+        let mut hll = HyperLogLog::new();
+        values.for_each(|v| hll.insert(&v.to_le_bytes()));
+        hll
+    }
+
+    #[test]
+    fn estimate() {
+        assert_eq!(HyperLogLog::new().estimate(), 0);
+        assert_eq!(sketch([7, 7, 7].into_iter()).estimate(), 1);
+        for n in [100u64, 10_000, 1_000_000] {
+            let estimate = sketch(0..n).estimate() as f64;
+            assert!(
+                (estimate - n as f64).abs() / (n as f64) < 0.1,
+                "{n}: {estimate}"
+            );
+        }
+    }
+
+    #[test]
+    fn merge() {
+        let mut first = sketch(0..60_000);
+        first.merge(&sketch(40_000..100_000));
+        let estimate = first.estimate() as f64;
+        assert!((estimate - 100_000.0).abs() < 10_000.0, "{estimate}");
+        assert_eq!(first, sketch(0..100_000));
+    }
+
+    #[test]
+    fn scalar_roundtrip() {
+        let hll = sketch(0..1000);
+        let scalar = Scalar::from(hll.clone());
+        assert_eq!(HyperLogLog::try_from(&scalar).unwrap(), hll);
+    }
+}
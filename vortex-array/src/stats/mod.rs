@@ -1,14 +1,19 @@
 use std::fmt::{Display, Formatter};
 use std::hash::Hash;
 
+pub use bloom::*;
 use enum_iterator::Sequence;
+pub use hll::*;
 pub use statsset::*;
 use vortex_dtype::Nullability::NonNullable;
 use vortex_dtype::{DType, NativePType};
 use vortex_error::{VortexError, VortexResult};
 use vortex_scalar::Scalar;
 
+mod bloom;
 pub mod flatbuffers;
+mod hash;
+mod hll;
 mod statsset;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Sequence)]
@@ -23,6 +28,55 @@ pub enum Stat {
     RunCount,
     TrueCount,
     NullCount,
+    /// Approximate number of distinct non-null values, estimated from the
+    /// [`DistinctCountSketch`](Stat::DistinctCountSketch). Once merged with statistics that lack a
+    /// sketch, it is only an approximate lower bound.
+    DistinctCount,
+    /// The serialized [`HyperLogLog`] sketch of the non-null values, used to merge distinct
+    /// counts. Sketches are only kept in memory and are not written alongside the array.
+    DistinctCountSketch,
+    /// A serialized [`BloomFilter`] of the non-null values, only present if configured.
+    BloomFilter,
+    /// Whether the [`Min`](Stat::Min) is a truncated lower bound rather than the minimum itself.
+    IsMinTruncated,
+    /// Whether the [`Max`](Stat::Max) is an incremented upper bound rather than the maximum itself.
+    IsMaxTruncated,
+}
+
+impl Stat {
+    /// The flag marking the [`Min`](Stat::Min) or [`Max`](Stat::Max) as a truncated bound.
+    pub fn truncation_flag(&self) -> Option<Stat> {
+        match self {
+            Self::Min => Some(Self::IsMinTruncated),
+            Self::Max => Some(Self::IsMaxTruncated),
+            _ => None,
+        }
+    }
+}
+
+/// A [`Min`](Stat::Min) or [`Max`](Stat::Max) statistic, which for long strings and binaries is
+/// only a bound of the actual value.
+///
+/// Bounds are read and written together with their truncation flag, such that a truncated bound
+/// can't be mistaken for the exact value when copying statistics between arrays.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bound {
+    pub value: Scalar,
+    pub is_truncated: bool,
+}
+
+impl Bound {
+    pub fn exact(value: Scalar) -> Self {
+        Self {
+            value,
+            is_truncated: false,
+        }
+    }
+}
+
+/// Whether the min and max of arrays with the dtype may be truncated bounds.
+fn has_truncated_bounds(dtype: &DType) -> bool {
+    matches!(dtype, DType::Utf8(_) | DType::Binary(_))
 }
 
 impl Display for Stat {
@@ -38,6 +92,11 @@ impl Display for Stat {
             Self::RunCount => write!(f, "run_count"),
             Self::TrueCount => write!(f, "true_count"),
             Self::NullCount => write!(f, "null_count"),
+            Self::DistinctCount => write!(f, "distinct_count"),
+            Self::DistinctCountSketch => write!(f, "distinct_count_sketch"),
+            Self::BloomFilter => write!(f, "bloom_filter"),
+            Self::IsMinTruncated => write!(f, "is_min_truncated"),
+            Self::IsMaxTruncated => write!(f, "is_max_truncated"),
         }
     }
 }
@@ -125,6 +184,44 @@ impl dyn Statistics + '_ {
             .map(|s| U::try_from(&s).expect("Invalid stats cast"))
     }
 
+    /// The min or max of the array together with whether it is a truncated bound, if present.
+    pub fn get_bound(&self, stat: Stat) -> Option<Bound> {
+        let value = self.get(stat)?;
+        let is_truncated = has_truncated_bounds(value.dtype())
+            && stat
+                .truncation_flag()
+                .and_then(|flag| self.get_as::<bool>(flag))
+                .unwrap_or(false);
+        Some(Bound {
+            value,
+            is_truncated,
+        })
+    }
+
+    /// Computes the min or max of the array together with whether it is a truncated bound.
+    pub fn compute_bound(&self, stat: Stat) -> Option<Bound> {
+        let value = self.compute(stat)?;
+        let is_truncated = has_truncated_bounds(value.dtype())
+            && stat
+                .truncation_flag()
+                .and_then(|flag| self.compute_as::<bool>(flag))
+                .unwrap_or(false);
+        Some(Bound {
+            value,
+            is_truncated,
+        })
+    }
+
+    /// Set the min or max of the array together with its truncation flag.
+    pub fn set_bound(&self, stat: Stat, bound: Bound) {
+        if let Some(flag) = stat.truncation_flag() {
+            if bound.is_truncated || has_truncated_bounds(bound.value.dtype()) {
+                self.set(flag, bound.is_truncated.into());
+            }
+        }
+        self.set(stat, bound.value);
+    }
+
     pub fn compute_min<U: for<'a> TryFrom<&'a Scalar, Error = VortexError>>(&self) -> Option<U> {
         self.compute_as(Stat::Min)
     }
@@ -157,6 +254,10 @@ impl dyn Statistics + '_ {
         self.compute_as(Stat::RunCount)
     }
 
+    pub fn compute_distinct_count(&self) -> Option<usize> {
+        self.compute_as(Stat::DistinctCount)
+    }
+
     pub fn compute_bit_width_freq(&self) -> Option<Vec<usize>> {
         self.compute_as::<Vec<usize>>(Stat::BitWidthFreq)
     }
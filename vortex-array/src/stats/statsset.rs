@@ -1,3 +1,4 @@
+use std::cmp::Ordering;
 use std::collections::hash_map::{Entry, IntoIter};
 use std::collections::HashMap;
 
@@ -7,7 +8,7 @@ use vortex_dtype::DType;
 use vortex_error::VortexError;
 use vortex_scalar::Scalar;
 
-use crate::stats::Stat;
+use crate::stats::{has_truncated_bounds, BloomFilter, Bound, HyperLogLog, Stat};
 
 #[derive(Debug, Clone, Default)]
 pub struct StatsSet {
@@ -55,6 +56,10 @@ impl StatsSet {
                     Stat::TrailingZeroFreq,
                     vec![ptype.byte_width() * 8; ptype.byte_width() * 8 + 1].into(),
                 );
+                stats.insert(Stat::DistinctCount, 0.into());
+            }
+            DType::Utf8(_) | DType::Binary(_) => {
+                stats.insert(Stat::DistinctCount, 0.into());
             }
             _ => {}
         }
@@ -62,6 +67,14 @@ impl StatsSet {
         Self::from(stats)
     }
 
+    /// The distinct count statistics estimated from a sketch of the values of an array.
+    pub fn distinct_count(sketch: HyperLogLog) -> Self {
+        Self::from(HashMap::from([
+            (Stat::DistinctCount, sketch.estimate().into()),
+            (Stat::DistinctCountSketch, sketch.into()),
+        ]))
+    }
+
     pub fn of(stat: Stat, value: Scalar) -> Self {
         Self::from(HashMap::from([(stat, value)]))
     }
@@ -78,6 +91,16 @@ impl StatsSet {
         self.values.insert(stat, value);
     }
 
+    /// Set the min or max together with its truncation flag, see [`Bound`].
+    pub fn set_bound(&mut self, stat: Stat, bound: Bound) {
+        if let Some(flag) = stat.truncation_flag() {
+            if bound.is_truncated || has_truncated_bounds(bound.value.dtype()) {
+                self.set(flag, bound.is_truncated.into());
+            }
+        }
+        self.set(stat, bound.value);
+    }
+
     pub fn merge(&mut self, other: &Self) -> &Self {
         for s in all::<Stat>() {
             match s {
@@ -91,6 +114,12 @@ impl StatsSet {
                 Stat::RunCount => self.merge_run_count(other),
                 Stat::TrueCount => self.merge_true_count(other),
                 Stat::NullCount => self.merge_null_count(other),
+                Stat::DistinctCount => self.merge_distinct_count(other),
+                // Merged together with the distinct count.
+                Stat::DistinctCountSketch => {}
+                Stat::BloomFilter => self.merge_bloom_filter(other),
+                // Merged together with the bound they describe.
+                Stat::IsMinTruncated | Stat::IsMaxTruncated => {}
             }
         }
 
@@ -98,33 +127,58 @@ impl StatsSet {
     }
 
     fn merge_min(&mut self, other: &Self) {
-        self.merge_ordered(Stat::Min, other, |other, own| other < own);
+        self.merge_bound(Stat::Min, Stat::IsMinTruncated, other, Ordering::Less);
     }
 
     fn merge_max(&mut self, other: &Self) {
-        self.merge_ordered(Stat::Max, other, |other, own| other > own);
+        self.merge_bound(Stat::Max, Stat::IsMaxTruncated, other, Ordering::Greater);
     }
 
     /// Merges stats if both are present, if either stat is not present, drops the stat from the
     /// result set. For example, if we know the minimums of two arrays, the minimum of their union
     /// is the minimum-of-minimums, but if we only know the minimum of one of the two arrays, we
     /// do not know the minimum of their union.
-    fn merge_ordered<F: Fn(&Scalar, &Scalar) -> bool>(&mut self, stat: Stat, other: &Self, cmp: F) {
-        if let Entry::Occupied(mut e) = self.values.entry(stat) {
-            if let Some(ov) = other.get(stat) {
-                if cmp(ov, e.get()) {
-                    e.insert(ov.clone());
-                }
-            } else {
-                e.remove();
+    ///
+    /// The flag marking a bound as truncated is merged alongside: the merged bound is only
+    /// truncated if the side it was taken from is, or if both sides are when their bounds are equal.
+    fn merge_bound(&mut self, stat: Stat, truncated: Stat, other: &Self, better: Ordering) {
+        let ordering = match (self.get(stat), other.get(stat)) {
+            (Some(own), Some(other)) => other.partial_cmp(own),
+            _ => {
+                self.values.remove(&stat);
+                self.values.remove(&truncated);
+                return;
             }
+        };
+
+        let own_truncated = self.get_as::<bool>(truncated);
+        let other_truncated = other.get_as::<bool>(truncated);
+        let is_truncated = match ordering {
+            Some(Ordering::Equal) => {
+                own_truncated.unwrap_or(false) && other_truncated.unwrap_or(false)
+            }
+            Some(o) if o == better => {
+                self.values.insert(stat, other.get(stat).cloned().unwrap());
+                other_truncated.unwrap_or(false)
+            }
+            _ => own_truncated.unwrap_or(false),
+        };
+        if own_truncated.is_some() || other_truncated.is_some() {
+            self.values.insert(truncated, is_truncated.into());
         }
     }
 
     fn merge_is_constant(&mut self, other: &Self) {
         if let Some(is_constant) = self.get_as(Stat::IsConstant) {
             if let Some(other_is_constant) = other.get_as(Stat::IsConstant) {
-                if is_constant && other_is_constant && self.get(Stat::Min) == other.get(Stat::Min) {
+                // Equal truncated bounds don't imply equal values.
+                let is_exact = !self.get_as(Stat::IsMinTruncated).unwrap_or(false)
+                    && !other.get_as(Stat::IsMinTruncated).unwrap_or(false);
+                if is_constant
+                    && other_is_constant
+                    && is_exact
+                    && self.get(Stat::Min) == other.get(Stat::Min)
+                {
                     return;
                 }
             }
@@ -210,6 +264,65 @@ impl StatsSet {
         }
     }
 
+    /// Distinct counts are merged through their sketches, which are merged alongside. Without
+    /// both sketches, e.g. for statistics read back from a file, the larger of the two counts is
+    /// kept as an approximate lower bound of the merged count.
+    fn merge_distinct_count(&mut self, other: &Self) {
+        let merged = self
+            .distinct_count_sketch()
+            .zip(other.distinct_count_sketch())
+            .map(|(mut own, other)| {
+                own.merge(&other);
+                own
+            });
+        if let Some(sketch) = merged {
+            self.values
+                .insert(Stat::DistinctCount, sketch.estimate().into());
+            self.values.insert(Stat::DistinctCountSketch, sketch.into());
+            return;
+        }
+
+        self.values.remove(&Stat::DistinctCountSketch);
+        match self
+            .get_as::<usize>(Stat::DistinctCount)
+            .zip(other.get_as::<usize>(Stat::DistinctCount))
+        {
+            Some((own, other)) => {
+                self.values
+                    .insert(Stat::DistinctCount, own.max(other).into());
+            }
+            None => {
+                self.values.remove(&Stat::DistinctCount);
+            }
+        }
+    }
+
+    /// The sketch of the distinct values, which is empty if there are none.
+    fn distinct_count_sketch(&self) -> Option<HyperLogLog> {
+        self.get_as::<HyperLogLog>(Stat::DistinctCountSketch)
+            .or_else(|| {
+                (self.get_as::<usize>(Stat::DistinctCount) == Some(0)).then(HyperLogLog::new)
+            })
+    }
+
+    /// Bloom filters of the same size are merged, any others are dropped.
+    fn merge_bloom_filter(&mut self, other: &Self) {
+        if let Entry::Occupied(mut e) = self.values.entry(Stat::BloomFilter) {
+            let merged = BloomFilter::try_from(e.get()).ok().and_then(|mut filter| {
+                let other = other.get_as::<BloomFilter>(Stat::BloomFilter)?;
+                filter.merge(&other).ok().map(|_| filter)
+            });
+            match merged {
+                Some(filter) => {
+                    e.insert(filter.into());
+                }
+                None => {
+                    e.remove();
+                }
+            }
+        }
+    }
+
     /// Merged run count is an upper bound where we assume run is interrupted at the boundary
     fn merge_run_count(&mut self, other: &Self) {
         if let Entry::Occupied(mut e) = self.values.entry(Stat::RunCount) {
@@ -242,8 +355,9 @@ impl IntoIterator for StatsSet {
 #[cfg(test)]
 mod test {
     use itertools::Itertools;
+    use vortex_dtype::{DType, Nullability, PType};
 
-    use crate::stats::{Stat, StatsSet};
+    use crate::stats::{HyperLogLog, Stat, StatsSet};
 
     #[test]
     fn merge_into_min() {
@@ -287,6 +401,46 @@ mod test {
         assert_eq!(first.get(Stat::Max).cloned(), Some(42.into()));
     }
 
+    #[test]
+    fn merge_truncated_bounds() {
+        let bounds = |min: &str, min_truncated: bool, max: &str, max_truncated: bool| {
+            let mut stats = StatsSet::of(Stat::Min, min.into());
+            stats.set(Stat::IsMinTruncated, min_truncated.into());
+            stats.set(Stat::Max, max.into());
+            stats.set(Stat::IsMaxTruncated, max_truncated.into());
+            stats
+        };
+
+        let mut first = bounds("a", true, "c", false);
+        first.merge(&bounds("b", false, "d", true));
+        assert_eq!(first.get(Stat::Min).cloned(), Some("a".into()));
+        assert_eq!(first.get_as::<bool>(Stat::IsMinTruncated), Some(true));
+        assert_eq!(first.get(Stat::Max).cloned(), Some("d".into()));
+        assert_eq!(first.get_as::<bool>(Stat::IsMaxTruncated), Some(true));
+
+        // An exact bound equal to a truncated one is the bound of both.
+        first.merge(&bounds("a", false, "d", false));
+        assert_eq!(first.get_as::<bool>(Stat::IsMinTruncated), Some(false));
+        assert_eq!(first.get_as::<bool>(Stat::IsMaxTruncated), Some(false));
+
+        first.merge(&StatsSet::new());
+        assert_eq!(first.get(Stat::Min), None);
+        assert_eq!(first.get(Stat::IsMinTruncated), None);
+    }
+
+    #[test]
+    fn merge_truncated_is_constant() {
+        let constant = || {
+            let mut stats = StatsSet::of(Stat::IsConstant, true.into());
+            stats.set(Stat::Min, "a".into());
+            stats.set(Stat::IsMinTruncated, true.into());
+            stats
+        };
+        let mut first = constant();
+        first.merge(&constant());
+        assert_eq!(first.get_as::<bool>(Stat::IsConstant), Some(false));
+    }
+
     #[test]
     fn merge_into_scalar() {
         let mut first = StatsSet::of(Stat::TrueCount, 42.into());
@@ -333,6 +487,45 @@ mod test {
         assert_eq!(first.get(Stat::BitWidthFreq).cloned(), Some(vec_out.into()));
     }
 
+    #[test]
+    fn merge_distinct_counts() {
+        let sketch = |values: std::ops::Range<u32>| {
+            let mut hll = HyperLogLog::new();
+            values.for_each(|v| hll.insert(&v.to_le_bytes()));
+            let mut stats = StatsSet::of(Stat::DistinctCount, hll.estimate().into());
+            stats.set(Stat::DistinctCountSketch, hll.into());
+            stats
+        };
+
+        let mut first = sketch(0..100);
+        first.merge(&sketch(50..150));
+        let distinct_count: usize = first.get_as(Stat::DistinctCount).unwrap();
+        assert!((140..=160).contains(&distinct_count), "{distinct_count}");
+
+        // Arrays without any values have no distinct values to merge.
+        first.merge(&StatsSet::nulls(
+            10,
+            &DType::Primitive(PType::U32, Nullability::Nullable),
+        ));
+        assert_eq!(
+            first.get_as::<usize>(Stat::DistinctCount),
+            Some(distinct_count)
+        );
+
+        // Counts without a sketch are merged into the larger of the two.
+        first.merge(&StatsSet::of(Stat::DistinctCount, 10usize.into()));
+        assert_eq!(
+            first.get_as::<usize>(Stat::DistinctCount),
+            Some(distinct_count)
+        );
+        assert_eq!(first.get(Stat::DistinctCountSketch), None);
+        first.merge(&StatsSet::of(Stat::DistinctCount, 1000usize.into()));
+        assert_eq!(first.get_as::<usize>(Stat::DistinctCount), Some(1000));
+
+        first.merge(&StatsSet::new());
+        assert_eq!(first.get(Stat::DistinctCount), None);
+    }
+
     #[test]
     fn merge_into_sortedness() {
         let mut first = StatsSet::of(Stat::IsStrictSorted, true.into());
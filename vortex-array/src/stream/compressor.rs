@@ -0,0 +1,205 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_util::{ready, Stream};
+use log::debug;
+use pin_project::pin_project;
+use vortex_dtype::DType;
+use vortex_error::VortexResult;
+
+use crate::array::bool::Bool;
+use crate::array::extension::Extension;
+use crate::array::null::Null;
+use crate::array::primitive::Primitive;
+use crate::array::varbin::VarBin;
+use crate::array::varbinview::VarBinView;
+use crate::compress::Compressor;
+use crate::stream::ArrayStream;
+use crate::{Array, ArrayDef};
+
+/// The default fraction by which the compression ratio of a chunk may exceed that of the last
+/// sampled chunk before the stream is sampled again.
+pub const DEFAULT_RESAMPLE_THRESHOLD: f32 = 0.2;
+
+/// Compresses the chunks of an array one after another.
+///
+/// Only the first chunk is sampled, the following chunks are compressed like the last sampled
+/// one, unless it was left uncompressed. When a chunk compresses worse than the sampled chunk by
+/// more than the resample threshold, it is sampled again, and the smaller of the two results is
+/// kept and used for the following chunks. Chunk `i` is compressed by a compressor
+/// [named](Compressor::named) `[i]`.
+#[derive(Debug, Clone)]
+pub struct ChunkCompressor<'a> {
+    compressor: Compressor<'a>,
+    resample_threshold: f32,
+    chunk_statistics: bool,
+    /// The last sampled chunk once compressed, or the result it was compared against if that was
+    /// smaller, and its compression ratio.
+    like: Option<(Array, f32)>,
+    num_chunks: usize,
+    num_sampled: usize,
+}
+
+impl<'a> ChunkCompressor<'a> {
+    pub fn new(compressor: Compressor<'a>) -> Self {
+        Self {
+            compressor,
+            resample_threshold: DEFAULT_RESAMPLE_THRESHOLD,
+            chunk_statistics: false,
+            like: None,
+            num_chunks: 0,
+            num_sampled: 0,
+        }
+    }
+
+    pub fn with_resample_threshold(mut self, resample_threshold: f32) -> Self {
+        self.resample_threshold = resample_threshold;
+        self
+    }
+
+    /// Keep the statistics of the uncompressed values of each chunk, see
+    /// [`Compressor::compress_chunk`].
+    pub fn with_chunk_statistics(mut self, chunk_statistics: bool) -> Self {
+        self.chunk_statistics = chunk_statistics;
+        self
+    }
+
+    /// The number of chunks that were sampled so far.
+    pub fn num_sampled(&self) -> usize {
+        self.num_sampled
+    }
+
+    /// Compress the next chunk.
+    pub fn compress(&mut self, chunk: &Array) -> VortexResult<Array> {
+        if chunk.is_empty() {
+            return Ok(chunk.clone());
+        }
+
+        let index = self.num_chunks;
+        let compressor = self.compressor.named(&format!("[{}]", index));
+        self.num_chunks += 1;
+        let compress = |like: Option<&Array>| {
+            if self.chunk_statistics {
+                compressor.compress_chunk(chunk, like)
+            } else {
+                compressor.compress(chunk, like)
+            }
+        };
+
+        let nbytes = chunk.nbytes() as f32;
+        let mut compressed = None;
+        // A chunk left in a canonical encoding has no encodings to reuse, so the next one is
+        // sampled again.
+        if let Some((like, like_ratio)) = self.like.as_ref().filter(|(like, _)| !is_canonical(like))
+        {
+            let like_compressed = compress(Some(like))?;
+            let ratio = like_compressed.nbytes() as f32 / nbytes;
+            if ratio <= like_ratio * (1.0 + self.resample_threshold) {
+                return Ok(like_compressed);
+            }
+
+            debug!(
+                "Resampling chunk {} with compression ratio {} against {}",
+                index, ratio, like_ratio
+            );
+            compressed = Some((like_compressed, ratio));
+        }
+
+        let sampled = compress(None)?;
+        self.num_sampled += 1;
+        let sampled_ratio = sampled.nbytes() as f32 / nbytes;
+        let (kept, ratio) = match compressed {
+            Some((compressed, ratio)) if ratio < sampled_ratio => (compressed, ratio),
+            _ => (sampled, sampled_ratio),
+        };
+        self.like = Some((kept.clone(), ratio));
+        Ok(kept)
+    }
+}
+
+fn is_canonical(array: &Array) -> bool {
+    [
+        Null::ID,
+        Bool::ID,
+        Primitive::ID,
+        VarBin::ID,
+        VarBinView::ID,
+        Extension::ID,
+    ]
+    .contains(&array.encoding().id())
+}
+
+/// An [`ArrayStream`] compressing the chunks of another stream with a [`ChunkCompressor`].
+///
+/// Chunks are compressed within [`poll_next`](Stream::poll_next), on the thread polling the
+/// stream. Compression is CPU bound, so on an async runtime the stream should be polled from a
+/// blocking thread, e.g. one given by `tokio::task::spawn_blocking`, rather than from a task
+/// sharing the executor with latency sensitive work.
+#[pin_project]
+pub struct StreamingCompressor<'a, S: ArrayStream> {
+    #[pin]
+    stream: S,
+    chunks: ChunkCompressor<'a>,
+}
+
+impl<'a, S: ArrayStream> StreamingCompressor<'a, S> {
+    pub fn new(stream: S, compressor: Compressor<'a>) -> Self {
+        Self::with_chunk_compressor(stream, ChunkCompressor::new(compressor))
+    }
+
+    pub fn with_chunk_compressor(stream: S, chunks: ChunkCompressor<'a>) -> Self {
+        Self { stream, chunks }
+    }
+
+    pub fn with_resample_threshold(mut self, resample_threshold: f32) -> Self {
+        self.chunks = self.chunks.with_resample_threshold(resample_threshold);
+        self
+    }
+
+    /// The number of chunks that were sampled so far.
+    pub fn num_sampled(&self) -> usize {
+        self.chunks.num_sampled()
+    }
+}
+
+impl<S: ArrayStream> ArrayStream for StreamingCompressor<'_, S> {
+    fn dtype(&self) -> &DType {
+        self.stream.dtype()
+    }
+}
+
+impl<S: ArrayStream> Stream for StreamingCompressor<'_, S> {
+    type Item = VortexResult<Array>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let Some(chunk) = ready!(this.stream.poll_next(cx)?) else {
+            return Poll::Ready(None);
+        };
+        Poll::Ready(Some(this.chunks.compress(&chunk)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::constant::Constant;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::{Context, IntoArray};
+
+    #[test]
+    fn test_resample_uncompressed() {
+        let ctx = Context::default();
+        let mut chunks = ChunkCompressor::new(Compressor::new(&ctx));
+
+        let incompressible = PrimitiveArray::from((0..1000i32).collect::<Vec<_>>()).into_array();
+        let compressed = chunks.compress(&incompressible).unwrap();
+        assert_eq!(compressed.encoding().id(), Primitive::ID);
+
+        // The first chunk was left uncompressed, so the second one is sampled on its own.
+        let constant = PrimitiveArray::from(vec![7i32; 1000]).into_array();
+        let compressed = chunks.compress(&constant).unwrap();
+        assert_eq!(compressed.encoding().id(), Constant::ID);
+        assert_eq!(chunks.num_sampled(), 2);
+    }
+}
@@ -1,13 +1,18 @@
 use std::future::Future;
 
+use futures_util::future::ready;
 use futures_util::TryStreamExt;
 use vortex_error::VortexResult;
+use vortex_expr::Disjunction;
 
 use crate::array::chunked::ChunkedArray;
+use crate::array::struct_::{Struct, StructArray};
+use crate::arrow::ArrayRecordBatchStream;
+use crate::stats::can_skip_filter;
 use crate::stream::take_rows::TakeRows;
 use crate::stream::ArrayStream;
 use crate::stream::ArrayStreamAdapter;
-use crate::Array;
+use crate::{Array, ArrayDef};
 
 pub trait ArrayStreamExt: ArrayStream {
     fn collect_chunked(self) -> impl Future<Output = VortexResult<ChunkedArray>>
@@ -30,6 +35,32 @@ pub trait ArrayStreamExt: ArrayStream {
             TakeRows::try_new(self, indices)?,
         ))
     }
+
+    /// Drop the struct chunks whose statistics prove that none of their rows satisfy `filter`,
+    /// see [`can_skip_filter`]. The remaining chunks are passed through unfiltered.
+    fn skip_chunks(self, filter: Disjunction) -> impl ArrayStream
+    where
+        Self: Sized,
+    {
+        let dtype = self.dtype().clone();
+        let chunks = self.try_filter_map(move |chunk| {
+            let skip = if chunk.encoding().id() == Struct::ID {
+                StructArray::try_from(&chunk).and_then(|chunk| can_skip_filter(&chunk, &filter))
+            } else {
+                Ok(false)
+            };
+            ready(skip.map(|skip| (!skip).then_some(chunk)))
+        });
+        ArrayStreamAdapter::new(dtype, chunks)
+    }
+
+    /// Convert into a stream of Arrow record batches of at most `max_rows` rows.
+    fn into_record_batch_stream(self, max_rows: usize) -> VortexResult<ArrayRecordBatchStream<Self>>
+    where
+        Self: Sized,
+    {
+        ArrayRecordBatchStream::try_new(self, max_rows)
+    }
 }
 
 impl<R: ArrayStream> ArrayStreamExt for R {}
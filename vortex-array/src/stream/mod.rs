@@ -1,4 +1,5 @@
 pub use adapter::*;
+pub use compressor::*;
 pub use ext::*;
 use futures_util::Stream;
 pub use take_rows::*;
@@ -8,6 +9,7 @@ use vortex_error::VortexResult;
 use crate::Array;
 
 mod adapter;
+mod compressor;
 mod ext;
 mod take_rows;
 
@@ -5,7 +5,7 @@ use vortex_dtype::DType;
 use vortex_error::{vortex_bail, VortexError, VortexResult};
 
 use crate::stats::StatsSet;
-use crate::{Array, ArrayData, ArrayDef, AsArray, IntoArray, ToArray, TryDeserializeArrayMetadata};
+use crate::{Array, ArrayData, ArrayDef, AsArray, IntoArray, ToArray};
 
 #[derive(Debug, Clone)]
 pub struct TypedArray<D: ArrayDef> {
@@ -61,7 +61,7 @@ impl<D: ArrayDef> TryFrom<Array> for TypedArray<D> {
                 .downcast_ref::<D::Metadata>()
                 .unwrap()
                 .clone(),
-            Array::View(v) => D::Metadata::try_deserialize_metadata(v.metadata())?,
+            Array::View(v) => v.typed_metadata::<D::Metadata>()?,
         };
         Ok(Self { array, metadata })
     }
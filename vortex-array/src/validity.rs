@@ -1,14 +1,18 @@
 use arrow_buffer::{BooleanBuffer, BooleanBufferBuilder, NullBuffer};
 use serde::{Deserialize, Serialize};
-use vortex_dtype::{DType, Nullability};
+use vortex_dtype::{match_each_native_ptype, DType, Nullability};
 use vortex_error::{vortex_bail, VortexResult};
 
 use crate::array::bool::BoolArray;
+use crate::array::extension::ExtensionArray;
+use crate::array::primitive::PrimitiveArray;
+use crate::array::struct_::StructArray;
+use crate::array::varbin::VarBinArray;
 use crate::compute::slice::slice;
 use crate::compute::take::take;
 use crate::compute::unary::scalar_at::scalar_at;
 use crate::stats::ArrayStatistics;
-use crate::{Array, Canonical, IntoArray, IntoArrayVariant, IntoCanonical};
+use crate::{Array, ArrayDType, ArrayTrait, Canonical, IntoArray, IntoArrayVariant, IntoCanonical};
 
 pub trait ArrayValidity {
     fn is_valid(&self, index: usize) -> bool;
@@ -42,6 +46,8 @@ pub enum Validity {
     NonNullable,
     AllValid,
     AllInvalid,
+    /// A non-nullable boolean array of any encoding, e.g. a sparse or roaring bitmap for columns
+    /// that are almost all valid or almost all null.
     Array(Array),
 }
 
@@ -113,6 +119,69 @@ impl Validity {
         }
     }
 
+    /// The validity of values that are valid in both, e.g. of a struct field within its struct.
+    pub fn and(&self, other: &Validity) -> VortexResult<Self> {
+        Ok(match (self, other) {
+            (Self::AllInvalid, _) | (_, Self::AllInvalid) => Self::AllInvalid,
+            (Self::NonNullable, Self::NonNullable) => Self::NonNullable,
+            (Self::NonNullable | Self::AllValid, Self::NonNullable | Self::AllValid) => {
+                Self::AllValid
+            }
+            (Self::Array(a), Self::NonNullable | Self::AllValid)
+            | (Self::NonNullable | Self::AllValid, Self::Array(a)) => Self::Array(a.clone()),
+            (Self::Array(a), Self::Array(b)) => Self::Array(
+                BoolArray::from(
+                    &a.clone().into_bool()?.boolean_buffer()
+                        & &b.clone().into_bool()?.boolean_buffer(),
+                )
+                .into_array(),
+            ),
+        })
+    }
+
+    /// Make `array` null wherever this validity is, e.g. to apply the validity of a struct to one
+    /// of its fields.
+    ///
+    /// Arrays are returned as they are if this validity has no nulls, otherwise they are decoded
+    /// to combine it with their own validity. Structs keep their fields encoded.
+    pub fn mask(&self, array: Array) -> VortexResult<Array> {
+        if self.to_logical(array.len()).all_valid() {
+            return Ok(array);
+        }
+
+        let validity = array
+            .with_dyn(|a| a.logical_validity())
+            .into_validity()
+            .and(self)?;
+        Ok(match array.into_canonical()? {
+            Canonical::Null(array) => array.into_array(),
+            Canonical::Bool(array) => {
+                BoolArray::try_new(array.boolean_buffer(), validity)?.into_array()
+            }
+            Canonical::Primitive(array) => match_each_native_ptype!(array.ptype(), |$T| {
+                PrimitiveArray::try_new(array.scalar_buffer::<$T>(), validity)?.into_array()
+            }),
+            Canonical::Struct(array) => StructArray::try_new(
+                array.names().clone(),
+                array.children().collect(),
+                array.len(),
+                validity,
+            )?
+            .into_array(),
+            Canonical::VarBin(array) => VarBinArray::try_new(
+                array.offsets(),
+                array.bytes(),
+                array.dtype().as_nullable(),
+                validity,
+            )?
+            .into_array(),
+            Canonical::Extension(array) => {
+                ExtensionArray::new(array.ext_dtype().clone(), validity.mask(array.storage())?)
+                    .into_array()
+            }
+        })
+    }
+
     pub fn to_logical(&self, length: usize) -> LogicalValidity {
         match self {
             Self::NonNullable => LogicalValidity::AllValid(length),
@@ -236,6 +305,8 @@ impl<'a, E> FromIterator<&'a Option<E>> for Validity {
 pub enum LogicalValidity {
     AllValid(usize),
     AllInvalid(usize),
+    /// A validity array in whichever encoding it was stored, only expanded into a boolean buffer
+    /// when converting to a [`NullBuffer`].
     Array(Array),
 }
 
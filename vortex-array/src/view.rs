@@ -1,5 +1,5 @@
 use std::fmt::{Debug, Formatter};
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 use enum_iterator::all;
 use itertools::Itertools;
@@ -14,7 +14,7 @@ use crate::flatbuffers as fb;
 use crate::stats::{Stat, Statistics, StatsSet};
 use crate::visitor::ArrayVisitor;
 use crate::Context;
-use crate::{Array, IntoArray, ToArray};
+use crate::{Array, ArrayMetadata, IntoArray, ToArray, TryDeserializeArrayMetadata};
 
 #[derive(Clone)]
 pub struct ArrayView {
@@ -25,6 +25,8 @@ pub struct ArrayView {
     // TODO(ngates): create an RC'd vector that can be lazily sliced.
     buffers: Vec<Buffer>,
     ctx: Arc<ViewContext>,
+    /// The deserialized metadata, shared between all clones of the view.
+    typed_metadata: Arc<OnceLock<Arc<dyn ArrayMetadata>>>,
     // TODO(ngates): a store a Projection. A projected ArrayView contains the full fb::Array
     //  metadata, but only the buffers from the selected columns. Therefore we need to know
     //  which fb:Array children to skip when calculating how to slice into buffers.
@@ -73,6 +75,7 @@ impl ArrayView {
             flatbuffer_loc,
             buffers,
             ctx,
+            typed_metadata: Arc::default(),
         };
 
         // Validate here that the metadata correctly parses, so that an encoding can infallibly
@@ -102,6 +105,26 @@ impl ArrayView {
         self.flatbuffer().metadata().map(|m| m.bytes())
     }
 
+    /// The metadata deserialized into the metadata type of the view's encoding.
+    ///
+    /// The metadata is deserialized once and shared between all clones of the view, such that
+    /// any state an encoding caches in its metadata, e.g. a decoded buffer, is shared as well.
+    pub(crate) fn typed_metadata<M>(&self) -> VortexResult<M>
+    where
+        M: ArrayMetadata + Clone + for<'m> TryDeserializeArrayMetadata<'m>,
+    {
+        if self.typed_metadata.get().is_none() {
+            let metadata = M::try_deserialize_metadata(self.metadata())?;
+            // Another clone of the view may have won the race, either metadata will do.
+            let _ = self.typed_metadata.set(Arc::new(metadata));
+        }
+        self.typed_metadata
+            .get()
+            .and_then(|metadata| metadata.as_any().downcast_ref::<M>())
+            .cloned()
+            .ok_or_else(|| vortex_err!("Metadata of {} has an unexpected type", self.encoding.id()))
+    }
+
     // TODO(ngates): should we separate self and DType lifetimes? Should DType be cloned?
     pub fn child(&self, idx: usize, dtype: &DType) -> Option<Self> {
         let child = self.array_child(idx)?;
@@ -127,6 +150,7 @@ impl ArrayView {
             flatbuffer_loc,
             buffers: self.buffers[buffer_offset..][0..buffer_count].to_vec(),
             ctx: self.ctx.clone(),
+            typed_metadata: Arc::default(),
         })
     }
 
@@ -230,6 +254,24 @@ impl Statistics for ArrayView {
                 .trailing_zero_freq()
                 .map(|v| v.iter().collect_vec())
                 .map(|v| v.into()),
+            Stat::DistinctCount => self.flatbuffer().stats()?.distinct_count().map(u64::into),
+            // Sketches are not serialized, only the distinct count estimated from them.
+            Stat::DistinctCountSketch => None,
+            Stat::BloomFilter => self
+                .flatbuffer()
+                .stats()?
+                .bloom_filter()
+                .map(|v| Scalar::binary(Buffer::from(v.bytes()), Nullability::NonNullable)),
+            Stat::IsMinTruncated => self
+                .flatbuffer()
+                .stats()?
+                .is_min_truncated()
+                .map(bool::into),
+            Stat::IsMaxTruncated => self
+                .flatbuffer()
+                .stats()?
+                .is_max_truncated()
+                .map(bool::into),
         }
     }
 
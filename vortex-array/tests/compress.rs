@@ -0,0 +1,101 @@
+use itertools::Itertools;
+use vortex::array::primitive::{Primitive, PrimitiveArray};
+use vortex::array::struct_::StructArray;
+use vortex::compress::{
+    decode_cost, CompressConfig, CompressionObjective, Compressor, FieldOverride,
+};
+use vortex::encoding::{ArrayEncoding, EncodingRef};
+use vortex::validity::Validity;
+use vortex::{ArrayDef, Context, IntoArray};
+use vortex_dtype::field::FieldPath;
+use vortex_fastlanes::{BitPackedEncoding, DeltaEncoding, FoREncoding};
+
+fn ctx() -> Context {
+    Context::default().with_encodings([
+        &FoREncoding as EncodingRef,
+        &BitPackedEncoding,
+        &DeltaEncoding,
+    ])
+}
+
+#[test]
+fn test_seeded_compression() {
+    // Runs of small values at varying offsets, so samples taken at different places may choose
+    // different encodings.
+    let array = PrimitiveArray::from(
+        (0u32..200_000)
+            .map(|v| v % 13 + (v / 1_000 % 7) * 1_000_000)
+            .collect_vec(),
+    )
+    .into_array();
+    let compress = || {
+        let compressed =
+            Compressor::new_with_options(&ctx(), CompressConfig::default().with_rng_seed(42))
+                .compress(&array, None)
+                .unwrap();
+        let buffers = compressed
+            .depth_first_traversal()
+            .filter_map(|child| child.buffer().map(|buffer| buffer.as_slice().to_vec()))
+            .collect_vec();
+        (compressed.tree_display().to_string(), buffers)
+    };
+
+    assert_eq!(compress(), compress());
+}
+
+#[test]
+fn test_objective() {
+    let ctx = ctx();
+    let array = PrimitiveArray::from((0u32..10_000).map(|v| v * 3 + 1_000_000).collect_vec());
+    let compress = |objective| {
+        Compressor::new_with_options(&ctx, CompressConfig::default().with_objective(objective))
+            .compress(array.array(), None)
+            .unwrap()
+    };
+
+    let smallest = compress(CompressionObjective::MinSize);
+    let fastest = compress(CompressionObjective::MinDecodeCost);
+    assert!(smallest.nbytes() <= fastest.nbytes());
+    assert!(decode_cost(&fastest) <= decode_cost(&smallest));
+    assert_ne!(fastest.encoding().id(), DeltaEncoding.id());
+}
+
+#[test]
+fn test_field_overrides() {
+    let ctx = ctx();
+    let names = ["forced", "excluded", "disabled", "default"];
+    let field =
+        || PrimitiveArray::from((0u32..10_000).map(|v| v + 1_000_000).collect_vec()).into_array();
+    let array = StructArray::try_new(
+        names.iter().map(|&name| name.into()).collect(),
+        names.iter().map(|_| field()).collect(),
+        10_000,
+        Validity::NonNullable,
+    )
+    .unwrap();
+
+    let config = CompressConfig::default()
+        .with_field_override(
+            FieldPath::from_name("forced"),
+            FieldOverride::default().with_encoding(&FoREncoding),
+        )
+        .with_field_override(
+            FieldPath::from_name("excluded"),
+            FieldOverride::default().excluding(&DeltaEncoding),
+        )
+        .with_field_override(
+            FieldPath::from_name("disabled"),
+            FieldOverride::default().disabled(),
+        );
+    let compressed = StructArray::try_from(
+        Compressor::new_with_options(&ctx, config)
+            .compress(array.array(), None)
+            .unwrap(),
+    )
+    .unwrap();
+    let encoding = |idx| compressed.field(idx).unwrap().encoding().id();
+    assert_eq!(encoding(0), FoREncoding.id());
+    assert_ne!(encoding(1), DeltaEncoding.id());
+    assert_eq!(encoding(2), Primitive::ID);
+    assert_eq!(encoding(3), DeltaEncoding.id());
+}
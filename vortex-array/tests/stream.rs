@@ -0,0 +1,77 @@
+use futures_executor::block_on;
+use futures_util::{stream, StreamExt};
+use itertools::Itertools;
+use vortex::array::primitive::{Primitive, PrimitiveArray};
+use vortex::compress::Compressor;
+use vortex::encoding::{ArrayEncoding, EncodingId, EncodingRef};
+use vortex::stream::{ArrayStreamAdapter, StreamingCompressor};
+use vortex::{Array, ArrayDType, ArrayDef, Context, IntoArray};
+use vortex_fastlanes::{BitPackedEncoding, FoREncoding};
+
+fn ctx() -> Context {
+    Context::default().with_encodings([&FoREncoding as EncodingRef, &BitPackedEncoding])
+}
+
+/// Compress the chunks one at a time, checking the number of chunks sampled after each.
+fn compress_stream(chunks: Vec<Array>, num_sampled: &[usize]) -> Vec<EncodingId> {
+    let dtype = chunks[0].dtype().clone();
+    let ctx = ctx();
+    let mut streaming = StreamingCompressor::new(
+        ArrayStreamAdapter::new(dtype, stream::iter(chunks.into_iter().map(Ok))),
+        Compressor::new(&ctx),
+    );
+
+    let encodings = num_sampled
+        .iter()
+        .map(|&num_sampled| {
+            let compressed = block_on(streaming.next()).unwrap().unwrap();
+            assert_eq!(streaming.num_sampled(), num_sampled);
+            compressed.encoding().id()
+        })
+        .collect();
+    assert!(block_on(streaming.next()).is_none());
+    encodings
+}
+
+fn small_values(offset: u32) -> Array {
+    PrimitiveArray::from((0u32..10_000).map(|v| v % 16 + offset).collect_vec()).into_array()
+}
+
+#[test]
+fn test_streaming() {
+    let chunks = vec![
+        small_values(0),
+        small_values(0),
+        small_values(1_000_000),
+        small_values(1_000_000),
+    ];
+    assert_eq!(
+        compress_stream(chunks, &[1, 1, 2, 2]),
+        [
+            BitPackedEncoding.id(),
+            BitPackedEncoding.id(),
+            FoREncoding.id(),
+            FoREncoding.id()
+        ]
+    );
+}
+
+#[test]
+fn test_streaming_incompressible_first_chunk() {
+    // Values spread over the whole u32 range, which neither encoding can shrink.
+    let incompressible = PrimitiveArray::from(
+        (0u32..10_000)
+            .map(|v| v.wrapping_mul(2_654_435_761))
+            .collect_vec(),
+    )
+    .into_array();
+    let chunks = vec![incompressible, small_values(0), small_values(0)];
+    assert_eq!(
+        compress_stream(chunks, &[1, 2, 2]),
+        [
+            Primitive::ID,
+            BitPackedEncoding.id(),
+            BitPackedEncoding.id()
+        ]
+    );
+}
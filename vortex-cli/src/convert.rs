@@ -0,0 +1,620 @@
+//! Conversion of Parquet files into Vortex IPC streams.
+//!
+//! Files are read one row group at a time, so only a single chunk of rows is held in memory
+//! while it is being compressed and written.
+
+use std::fs::File;
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, StructArray};
+use arrow_schema::DataType;
+use parquet::arrow::arrow_reader::{
+    ArrowReaderMetadata, ArrowReaderOptions, ParquetRecordBatchReader,
+    ParquetRecordBatchReaderBuilder,
+};
+use parquet::arrow::ProjectionMask;
+use parquet::file::statistics::Statistics;
+use vortex::array::struct_::StructArray as VortexStructArray;
+use vortex::array::varbin::{lower_bound, upper_bound, varbin_scalar};
+use vortex::arrow::{TryFromArrowArray, TryFromArrowType};
+use vortex::compress::{CompressConfig, CompressionTrace, Compressor};
+use vortex::iter::{ArrayIterator, ArrayIteratorExt};
+use vortex::stats::{ArrayStatistics, Bound, Stat};
+use vortex::stream::ChunkCompressor;
+use vortex::{Array, ArrayData, Context, IntoArray, ViewContext};
+use vortex_dtype::{DType, Nullability};
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_ipc::io::VortexWrite;
+use vortex_ipc::writer::ArrayWriter;
+use vortex_scalar::Scalar;
+
+/// The default maximum number of rows in each Vortex chunk.
+pub const DEFAULT_CHUNK_SIZE: usize = 65_536;
+
+#[derive(Debug, Clone)]
+pub struct ConvertOptions {
+    chunk_size: usize,
+    rechunk: bool,
+    compress_config: CompressConfig,
+    columns: Option<Vec<String>>,
+    trace: Option<CompressionTrace>,
+}
+
+impl Default for ConvertOptions {
+    fn default() -> Self {
+        Self {
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            rechunk: false,
+            compress_config: CompressConfig::default(),
+            columns: None,
+            trace: None,
+        }
+    }
+}
+
+impl ConvertOptions {
+    /// Set the maximum number of rows in each chunk. Chunks never span Parquet row groups unless
+    /// they are rechunked.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size;
+        self
+    }
+
+    /// Fill every chunk but the last with [`chunk_size`](Self::chunk_size) rows, even if they
+    /// span Parquet row groups. The statistics of each chunk are then computed from its values,
+    /// see [`Compressor::compress_chunk`].
+    pub fn with_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    pub fn with_compress_config(mut self, compress_config: CompressConfig) -> Self {
+        self.compress_config = compress_config;
+        self
+    }
+
+    /// Only convert the named top-level columns. Columns keep their order in the Parquet file.
+    pub fn with_columns<S: Into<String>>(mut self, columns: impl IntoIterator<Item = S>) -> Self {
+        self.columns = Some(columns.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Record how each chunk was compressed into `trace`. Array paths start with the chunk index.
+    pub fn with_trace(mut self, trace: CompressionTrace) -> Self {
+        self.trace = Some(trace);
+        self
+    }
+
+    #[inline]
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    #[inline]
+    pub fn rechunk(&self) -> bool {
+        self.rechunk
+    }
+
+    #[inline]
+    pub fn compress_config(&self) -> &CompressConfig {
+        &self.compress_config
+    }
+
+    #[inline]
+    pub fn columns(&self) -> Option<&[String]> {
+        self.columns.as_deref()
+    }
+
+    #[inline]
+    pub fn trace(&self) -> Option<&CompressionTrace> {
+        self.trace.as_ref()
+    }
+}
+
+/// Convert a Parquet file into a Vortex IPC stream holding a single chunked struct array.
+///
+/// The returned writer describes the layout of the written chunks.
+pub async fn convert_parquet<W: VortexWrite>(
+    parquet: File,
+    write: W,
+    ctx: &Context,
+    options: &ConvertOptions,
+) -> VortexResult<ArrayWriter<W>> {
+    let chunks = ParquetChunks::try_new(parquet, ctx, options)?;
+    ArrayWriter::new(write, ViewContext::from(ctx))
+        .write_context()
+        .await?
+        .write_array_stream(chunks.into_stream())
+        .await
+}
+
+/// An [`ArrayIterator`] of compressed struct arrays read from a Parquet file.
+///
+/// Chunks hold at most [`ConvertOptions::chunk_size`] rows and never span row groups, unless
+/// [rechunked](ConvertOptions::with_rechunk). When a row group fits in a single chunk, the exact
+/// min and max recorded in the Parquet statistics of its primitive columns are kept as the
+/// statistics of the chunk's fields.
+///
+/// Chunks are compressed by a [`ChunkCompressor`], which only samples a chunk when it compresses
+/// worse than the previously sampled one.
+pub struct ParquetChunks<'a> {
+    file: File,
+    metadata: ArrowReaderMetadata,
+    mask: ProjectionMask,
+    /// For each converted column, the index of its Parquet leaf column if it has one.
+    leaves: Vec<Option<usize>>,
+    dtype: DType,
+    compressor: ChunkCompressor<'a>,
+    chunk_size: usize,
+    rechunk: bool,
+    next_row_group: usize,
+    /// The reader over the current row group, and whether the row group fits in one chunk.
+    current: Option<(ParquetRecordBatchReader, usize, bool)>,
+}
+
+impl<'a> ParquetChunks<'a> {
+    pub fn try_new(file: File, ctx: &'a Context, options: &ConvertOptions) -> VortexResult<Self> {
+        if options.chunk_size() == 0 {
+            vortex_bail!("Chunks must hold at least one row");
+        }
+
+        let metadata = ArrowReaderMetadata::load(&file, ArrowReaderOptions::new())?;
+        let schema = metadata.schema().clone();
+        let roots = match options.columns() {
+            None => (0..schema.fields().len()).collect::<Vec<_>>(),
+            Some(columns) => {
+                let mut roots = columns
+                    .iter()
+                    .map(|name| schema.index_of(name))
+                    .collect::<Result<Vec<_>, _>>()?;
+                roots.sort_unstable();
+                roots.dedup();
+                roots
+            }
+        };
+
+        let parquet_schema = metadata.parquet_schema();
+        let root_fields = parquet_schema.root_schema().get_fields();
+        let leaves = roots
+            .iter()
+            .map(|&root| {
+                root_fields[root]
+                    .is_primitive()
+                    .then(|| {
+                        (0..parquet_schema.num_columns())
+                            .find(|&leaf| parquet_schema.get_column_root_idx(leaf) == root)
+                    })
+                    .flatten()
+            })
+            .collect();
+
+        let compressor = Compressor::new_with_options(ctx, options.compress_config().clone());
+        Ok(Self {
+            mask: ProjectionMask::roots(parquet_schema, roots.iter().copied()),
+            dtype: DType::try_from_arrow(Arc::new(schema.project(&roots)?))?,
+            file,
+            metadata,
+            leaves,
+            compressor: ChunkCompressor::new(match options.trace() {
+                Some(trace) => compressor.with_trace(trace.clone()),
+                None => compressor,
+            })
+            .with_chunk_statistics(options.rechunk()),
+            chunk_size: options.chunk_size(),
+            rechunk: options.rechunk(),
+            next_row_group: 0,
+            current: None,
+        })
+    }
+
+    fn open_row_group(&mut self) -> VortexResult<bool> {
+        let row_group = self.next_row_group;
+        let num_row_groups = self.metadata.metadata().num_row_groups();
+        if row_group == num_row_groups {
+            return Ok(false);
+        }
+        // When rechunking, a single reader fills its batches from all row groups.
+        self.next_row_group = if self.rechunk {
+            num_row_groups
+        } else {
+            row_group + 1
+        };
+
+        let num_rows = self.metadata.metadata().row_group(row_group).num_rows();
+        let reader = ParquetRecordBatchReaderBuilder::new_with_metadata(
+            self.file.try_clone()?,
+            self.metadata.clone(),
+        )
+        .with_row_groups((row_group..self.next_row_group).collect())
+        .with_projection(self.mask.clone())
+        .with_batch_size(self.chunk_size)
+        .build()?;
+        let whole = !self.rechunk && num_rows as usize <= self.chunk_size;
+        self.current = Some((reader, row_group, whole));
+        Ok(true)
+    }
+
+    fn next_chunk(&mut self) -> VortexResult<Option<Array>> {
+        loop {
+            if let Some((reader, row_group, whole)) = &mut self.current {
+                if let Some(batch) = reader.next() {
+                    let (row_group, whole) = (*row_group, *whole);
+                    let arrow: ArrayRef = Arc::new(StructArray::from(batch?));
+                    let array = ArrayData::try_from_arrow(arrow, false)?.into_array();
+                    let compressed = self.compressor.compress(&array)?;
+                    if whole {
+                        self.set_statistics(&compressed, row_group)?;
+                    }
+                    return Ok(Some(compressed));
+                }
+                self.current = None;
+            }
+
+            if !self.open_row_group()? {
+                return Ok(None);
+            }
+        }
+    }
+
+    /// Set the statistics of each field of a chunk holding a whole row group.
+    fn set_statistics(&self, chunk: &Array, row_group: usize) -> VortexResult<()> {
+        let chunk = VortexStructArray::try_from(chunk)?;
+        let row_group = self.metadata.metadata().row_group(row_group);
+        for (idx, leaf) in self.leaves.iter().enumerate() {
+            let Some(stats) = leaf.and_then(|leaf| row_group.column(leaf).statistics()) else {
+                continue;
+            };
+            let field = chunk
+                .field(idx)
+                .unwrap_or_else(|| unreachable!("projected field {idx} is missing"));
+            let data_type = self
+                .metadata
+                .schema()
+                .field_with_name(&chunk.names()[idx])?;
+            if let Some((min, max)) = min_max(stats, data_type.data_type()) {
+                field.statistics().set_bound(Stat::Min, min);
+                field.statistics().set_bound(Stat::Max, max);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Iterator for ParquetChunks<'_> {
+    type Item = VortexResult<Array>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_chunk().transpose()
+    }
+}
+
+impl ArrayIterator for ParquetChunks<'_> {
+    fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+}
+
+/// The min and max of a column chunk as bounds of the column's Vortex dtype.
+///
+/// Only integer, boolean, string and binary columns are supported. Float statistics are skipped
+/// since Parquet writers ignore NaNs when computing them, and null counts are skipped since
+/// Parquet reports a missing null count as zero.
+///
+/// Integer and boolean statistics are only used if they are exact. String and binary statistics
+/// are truncated to [`MAX_BOUND_LENGTH`](vortex::array::varbin::MAX_BOUND_LENGTH) bytes like
+/// those computed by Vortex, and are exact only if Parquet reports them as exact and they fit.
+fn min_max(stats: &Statistics, data_type: &DataType) -> Option<(Bound, Bound)> {
+    if !stats.has_min_max_set() || stats.is_min_max_deprecated() {
+        return None;
+    }
+
+    if let Statistics::ByteArray(s) = stats {
+        let dtype = match data_type {
+            DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => {
+                s.min().as_utf8().ok()?;
+                s.max().as_utf8().ok()?;
+                DType::Utf8(Nullability::NonNullable)
+            }
+            DataType::Binary | DataType::LargeBinary | DataType::BinaryView => {
+                DType::Binary(Nullability::NonNullable)
+            }
+            _ => return None,
+        };
+        // Inexact Parquet statistics are still a lower and an upper bound of the values.
+        let (min, is_min_truncated) = lower_bound(s.min().data(), &dtype);
+        let (max, is_max_truncated) = upper_bound(s.max().data(), &dtype);
+        return Some((
+            Bound {
+                value: varbin_scalar(min, &dtype),
+                is_truncated: is_min_truncated || !stats.min_is_exact(),
+            },
+            Bound {
+                value: varbin_scalar(max, &dtype),
+                is_truncated: is_max_truncated || !stats.max_is_exact(),
+            },
+        ));
+    }
+
+    if !stats.min_is_exact() || !stats.max_is_exact() {
+        return None;
+    }
+    let (min, max): (Scalar, Scalar) = match (stats, data_type) {
+        (Statistics::Boolean(s), DataType::Boolean) => ((*s.min()).into(), (*s.max()).into()),
+        (Statistics::Int32(s), DataType::Int8) => {
+            ((*s.min() as i8).into(), (*s.max() as i8).into())
+        }
+        (Statistics::Int32(s), DataType::Int16) => {
+            ((*s.min() as i16).into(), (*s.max() as i16).into())
+        }
+        (Statistics::Int32(s), DataType::Int32) => ((*s.min()).into(), (*s.max()).into()),
+        (Statistics::Int32(s), DataType::UInt8) => {
+            ((*s.min() as u8).into(), (*s.max() as u8).into())
+        }
+        (Statistics::Int32(s), DataType::UInt16) => {
+            ((*s.min() as u16).into(), (*s.max() as u16).into())
+        }
+        (Statistics::Int32(s), DataType::UInt32) => {
+            ((*s.min() as u32).into(), (*s.max() as u32).into())
+        }
+        (Statistics::Int64(s), DataType::Int64) => ((*s.min()).into(), (*s.max()).into()),
+        (Statistics::Int64(s), DataType::UInt64) => {
+            ((*s.min() as u64).into(), (*s.max() as u64).into())
+        }
+        _ => return None,
+    };
+    Some((Bound::exact(min), Bound::exact(max)))
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::{Int32Array, RecordBatch, StringArray};
+    use futures_executor::block_on;
+    use futures_util::io::Cursor;
+    use parquet::arrow::ArrowWriter;
+    use parquet::file::properties::WriterProperties;
+    use vortex::array::struct_::StructArray;
+    use vortex::array::varbin::MAX_BOUND_LENGTH;
+    use vortex::iter::ArrayIteratorExt;
+    use vortex::stats::{ArrayStatistics, Stat};
+    use vortex::stream::ArrayStreamExt;
+    use vortex::ArrayDType;
+    use vortex_dtype::DType;
+    use vortex_ipc::io::FuturesAdapter;
+    use vortex_ipc::MessageReader;
+
+    use super::*;
+    use crate::CTX;
+
+    /// Write a Parquet file with an integer and a string column, in row groups of `row_group_size`.
+    fn parquet_file(rows: i32, row_group_size: usize) -> File {
+        let batch = RecordBatch::try_from_iter([
+            (
+                "id",
+                Arc::new(Int32Array::from_iter_values(0..rows)) as ArrayRef,
+            ),
+            (
+                "name",
+                Arc::new(StringArray::from_iter_values(
+                    (0..rows).map(|i| format!("name-{}", i % 7)),
+                )) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+
+        let mut file = tempfile().unwrap();
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(row_group_size)
+            .build();
+        let mut writer =
+            ArrowWriter::try_new(file.try_clone().unwrap(), batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        std::io::Seek::rewind(&mut file).unwrap();
+        file
+    }
+
+    fn tempfile() -> std::io::Result<File> {
+        let path = std::env::temp_dir().join(format!(
+            "vortex-convert-{}-{:?}.parquet",
+            std::process::id(),
+            std::thread::current().id()
+        ));
+        let file = File::options()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&path)?;
+        std::fs::remove_file(path)?;
+        Ok(file)
+    }
+
+    #[test]
+    fn test_chunks_follow_row_groups() {
+        let options = ConvertOptions::default().with_chunk_size(40);
+        let chunks = ParquetChunks::try_new(parquet_file(250, 100), &CTX, &options)
+            .unwrap()
+            .try_into_chunked()
+            .unwrap();
+        assert_eq!(
+            chunks.chunks().map(|c| c.len()).collect::<Vec<_>>(),
+            vec![40, 40, 20, 40, 40, 20, 40, 10]
+        );
+    }
+
+    #[test]
+    fn test_rechunk() {
+        let options = ConvertOptions::default()
+            .with_chunk_size(40)
+            .with_rechunk(true);
+        let chunks = ParquetChunks::try_new(parquet_file(250, 100), &CTX, &options)
+            .unwrap()
+            .try_into_chunked()
+            .unwrap();
+        assert_eq!(
+            chunks.chunks().map(|c| c.len()).collect::<Vec<_>>(),
+            vec![40, 40, 40, 40, 40, 40, 10]
+        );
+
+        // The third chunk spans the first two row groups.
+        let ids = StructArray::try_from(chunks.chunk(2).unwrap())
+            .unwrap()
+            .field(0)
+            .unwrap();
+        assert_eq!(ids.statistics().get(Stat::Min), Some(80i32.into()));
+        assert_eq!(ids.statistics().get(Stat::Max), Some(119i32.into()));
+    }
+
+    #[test]
+    fn test_statistics() {
+        let options = ConvertOptions::default().with_chunk_size(100);
+        let chunks = ParquetChunks::try_new(parquet_file(250, 100), &CTX, &options)
+            .unwrap()
+            .try_into_chunked()
+            .unwrap();
+        let last = StructArray::try_from(chunks.chunk(2).unwrap()).unwrap();
+        let ids = last.field(0).unwrap();
+        assert_eq!(ids.statistics().get(Stat::Min), Some(200i32.into()));
+        assert_eq!(ids.statistics().get(Stat::Max), Some(249i32.into()));
+        let names = last.field(1).unwrap();
+        assert_eq!(
+            names.statistics().get(Stat::Max),
+            Some(Scalar::utf8("name-6".to_string(), Nullability::NonNullable))
+        );
+    }
+
+    #[test]
+    fn test_truncated_statistics() {
+        let long = |c: char| c.to_string().repeat(100);
+        let batch = RecordBatch::try_from_iter([(
+            "text",
+            Arc::new(StringArray::from_iter_values([long('a'), long('b')])) as ArrayRef,
+        )])
+        .unwrap();
+        let mut file = tempfile().unwrap();
+        let mut writer =
+            ArrowWriter::try_new(file.try_clone().unwrap(), batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        std::io::Seek::rewind(&mut file).unwrap();
+
+        let chunks = ParquetChunks::try_new(file, &CTX, &ConvertOptions::default())
+            .unwrap()
+            .try_into_chunked()
+            .unwrap();
+        let text = StructArray::try_from(chunks.chunk(0).unwrap())
+            .unwrap()
+            .field(0)
+            .unwrap();
+        let stats = text.statistics();
+        assert_eq!(
+            stats.get(Stat::Min),
+            Some(Scalar::utf8(
+                "a".repeat(MAX_BOUND_LENGTH),
+                Nullability::NonNullable
+            ))
+        );
+        assert_eq!(
+            stats.get(Stat::Max),
+            Some(Scalar::utf8(
+                format!("{}c", "b".repeat(MAX_BOUND_LENGTH - 1)),
+                Nullability::NonNullable
+            ))
+        );
+        assert_eq!(stats.get_as::<bool>(Stat::IsMinTruncated), Some(true));
+        assert_eq!(stats.get_as::<bool>(Stat::IsMaxTruncated), Some(true));
+    }
+
+    #[test]
+    fn test_columns() {
+        let options = ConvertOptions::default().with_columns(["name"]);
+        let chunks = ParquetChunks::try_new(parquet_file(10, 100), &CTX, &options).unwrap();
+        let DType::Struct(st, _) = chunks.dtype() else {
+            unreachable!()
+        };
+        assert_eq!(st.names().as_ref(), &["name".into()]);
+
+        let options = ConvertOptions::default().with_columns(["missing"]);
+        assert!(ParquetChunks::try_new(parquet_file(10, 100), &CTX, &options).is_err());
+    }
+
+    #[test]
+    fn test_convert() {
+        let written = block_on(convert_parquet(
+            parquet_file(250, 100),
+            Vec::new(),
+            &CTX,
+            &ConvertOptions::default(),
+        ))
+        .unwrap();
+        assert_eq!(
+            written.array_layouts()[0].chunks.row_offsets,
+            vec![0, 100, 200, 250]
+        );
+
+        let bytes = written.into_inner();
+        let array = block_on(async {
+            let mut msgs = MessageReader::try_new(FuturesAdapter(Cursor::new(bytes))).await?;
+            let array = msgs
+                .array_stream_from_messages(&CTX)
+                .await?
+                .collect_chunked()
+                .await?;
+            VortexResult::Ok(array)
+        })
+        .unwrap();
+        assert_eq!(array.array().len(), 250);
+        assert!(matches!(array.dtype(), DType::Struct(..)));
+    }
+
+    #[test]
+    fn test_convert_deterministic() {
+        let convert = |config: CompressConfig| {
+            block_on(convert_parquet(
+                parquet_file(20_000, 10_000),
+                Vec::new(),
+                &CTX,
+                &ConvertOptions::default().with_compress_config(config),
+            ))
+            .unwrap()
+            .into_inner()
+        };
+        let sequential = convert(CompressConfig::default());
+        assert_eq!(sequential, convert(CompressConfig::default()));
+        assert_eq!(
+            sequential,
+            convert(CompressConfig::default().with_threads(4))
+        );
+    }
+
+    #[test]
+    fn test_trace() {
+        let trace = CompressionTrace::new();
+        let options = ConvertOptions::default()
+            .with_chunk_size(100)
+            .with_trace(trace.clone());
+        ParquetChunks::try_new(parquet_file(250, 100), &CTX, &options)
+            .unwrap()
+            .try_into_chunked()
+            .unwrap();
+
+        let arrays = trace.arrays();
+        let names = arrays.iter().find(|a| a.path == "[0].name").unwrap();
+        assert_eq!(names.len, 100);
+        assert!(!names.candidates.is_empty());
+        assert!(names.winner.is_some());
+        assert!(names.nbytes < names.canonical_nbytes);
+        assert!(serde_json::to_string(&arrays)
+            .unwrap()
+            .contains(r#""path":"[0].id""#));
+        // The second chunk is compressed like the first one, without sampling.
+        let like = arrays.iter().find(|a| a.path == "[1].name").unwrap();
+        assert_eq!(like.len, 100);
+        assert!(!like.sampled);
+        assert!(like.candidates.is_empty());
+        assert_eq!(like.winner, names.winner);
+        assert!(like.nbytes < like.canonical_nbytes);
+    }
+}
@@ -0,0 +1,223 @@
+//! Inspection of the arrays stored in Vortex IPC streams.
+
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+use enum_iterator::all;
+use serde::Serialize;
+use vortex::stats::{ArrayStatistics, Stat};
+use vortex::visitor::ArrayVisitor;
+use vortex::{Array, ArrayDType, Context};
+use vortex_buffer::Buffer;
+use vortex_error::VortexResult;
+use vortex_ipc::io::VortexRead;
+use vortex_ipc::MessageReader;
+
+/// A description of the first array in a Vortex IPC stream.
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+    /// The encodings of the stream's view context, in the order chunks refer to them.
+    pub encodings: Vec<String>,
+    pub dtype: String,
+    /// The byte offset of each chunk in the stream, followed by the offset of the end of the last.
+    pub byte_offsets: Vec<u64>,
+    /// The first row of each chunk, followed by the total number of rows.
+    pub row_offsets: Vec<u64>,
+    pub chunks: Vec<ChunkReport>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChunkReport {
+    /// The rendered encoding tree of the chunk.
+    pub tree: String,
+    pub array: ArrayReport,
+}
+
+/// An array node within a chunk, along with its persisted statistics.
+#[derive(Debug, Clone, Serialize)]
+pub struct ArrayReport {
+    pub name: String,
+    pub encoding: String,
+    pub dtype: String,
+    pub len: usize,
+    pub nbytes: usize,
+    /// The size in bytes of each of the array's own buffers.
+    pub buffers: Vec<usize>,
+    pub stats: BTreeMap<String, String>,
+    pub children: Vec<ArrayReport>,
+}
+
+/// Read the view context and the first array of a Vortex IPC stream.
+pub async fn inspect<R: VortexRead>(read: R, ctx: &Context) -> VortexResult<Report> {
+    let mut msgs = MessageReader::try_new(read).await?;
+    let view_ctx = msgs.read_view_context(ctx).await?;
+    let dtype = msgs.read_dtype().await?;
+
+    let mut byte_offsets = vec![msgs.tell()];
+    let mut row_offsets = vec![0];
+    let mut chunks = Vec::new();
+    while let Some(chunk) = msgs
+        .maybe_read_chunk(view_ctx.clone(), dtype.clone())
+        .await?
+    {
+        byte_offsets.push(msgs.tell());
+        row_offsets.push(row_offsets[row_offsets.len() - 1] + chunk.len() as u64);
+        chunks.push(ChunkReport {
+            tree: chunk.tree_display().to_string(),
+            array: ArrayReport::try_new("root", &chunk)?,
+        });
+    }
+
+    Ok(Report {
+        encodings: view_ctx
+            .encodings()
+            .iter()
+            .map(|encoding| encoding.id().to_string())
+            .collect(),
+        dtype: dtype.to_string(),
+        byte_offsets,
+        row_offsets,
+        chunks,
+    })
+}
+
+impl ArrayReport {
+    pub fn try_new(name: &str, array: &Array) -> VortexResult<Self> {
+        let mut visitor = ReportVisitor::default();
+        array.with_dyn(|a| a.accept(&mut visitor))?;
+
+        Ok(Self {
+            name: name.to_string(),
+            encoding: array.encoding().id().to_string(),
+            dtype: array.dtype().to_string(),
+            len: array.len(),
+            nbytes: array.nbytes(),
+            buffers: visitor.buffers,
+            stats: all::<Stat>()
+                .filter_map(|stat| {
+                    array
+                        .statistics()
+                        .get(stat)
+                        .map(|value| (stat.to_string(), value.to_string()))
+                })
+                .collect(),
+            children: visitor.children,
+        })
+    }
+
+    /// Write the statistics of this array and its descendants, one line per array.
+    fn fmt_stats(&self, f: &mut Formatter<'_>, path: &str) -> std::fmt::Result {
+        let path = if path.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}.{}", path, self.name)
+        };
+        if !self.stats.is_empty() {
+            let stats = self
+                .stats
+                .iter()
+                .map(|(stat, value)| format!("{}={}", stat, value))
+                .collect::<Vec<_>>();
+            writeln!(f, "  {}: {}", path, stats.join(", "))?;
+        }
+        self.children
+            .iter()
+            .try_for_each(|child| child.fmt_stats(f, &path))
+    }
+}
+
+#[derive(Default)]
+struct ReportVisitor {
+    children: Vec<ArrayReport>,
+    buffers: Vec<usize>,
+}
+
+impl ArrayVisitor for ReportVisitor {
+    fn visit_child(&mut self, name: &str, array: &Array) -> VortexResult<()> {
+        self.children.push(ArrayReport::try_new(name, array)?);
+        Ok(())
+    }
+
+    fn visit_buffer(&mut self, buffer: &Buffer) -> VortexResult<()> {
+        self.buffers.push(buffer.len());
+        Ok(())
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "encodings: {}", self.encodings.join(", "))?;
+        writeln!(f, "dtype: {}", self.dtype)?;
+        writeln!(
+            f,
+            "chunks: {}, rows: {}, bytes: {}..{}",
+            self.chunks.len(),
+            self.row_offsets[self.row_offsets.len() - 1],
+            self.byte_offsets[0],
+            self.byte_offsets[self.byte_offsets.len() - 1],
+        )?;
+
+        for (idx, chunk) in self.chunks.iter().enumerate() {
+            writeln!(
+                f,
+                "\nchunk {}: rows {}..{}, bytes {}..{}",
+                idx,
+                self.row_offsets[idx],
+                self.row_offsets[idx + 1],
+                self.byte_offsets[idx],
+                self.byte_offsets[idx + 1],
+            )?;
+            write!(f, "{}", chunk.tree)?;
+            writeln!(f, "stats:")?;
+            chunk.array.fmt_stats(f, "")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use futures_executor::block_on;
+    use vortex::array::chunked::ChunkedArray;
+    use vortex::array::primitive::PrimitiveArray;
+    use vortex::stats::ArrayStatistics;
+    use vortex::{ArrayDType, Context, IntoArray, ViewContext};
+    use vortex_ipc::writer::ArrayWriter;
+
+    use super::*;
+
+    #[test]
+    fn test_inspect() {
+        let chunk = PrimitiveArray::from((0i32..100).collect::<Vec<_>>()).into_array();
+        chunk.statistics().compute_min::<i32>();
+        let chunked =
+            ChunkedArray::try_new(vec![chunk.clone(), chunk.clone()], chunk.dtype().clone())
+                .unwrap();
+        let writer = block_on(async {
+            ArrayWriter::new(vec![], ViewContext::from(&Context::default()))
+                .write_context()
+                .await?
+                .write_array(chunked.into_array())
+                .await
+        })
+        .unwrap();
+        let layout = writer.array_layouts()[0].chunks.clone();
+
+        let report = block_on(inspect(
+            Cursor::new(writer.into_inner()),
+            &Context::default(),
+        ))
+        .unwrap();
+        assert_eq!(report.byte_offsets, layout.byte_offsets);
+        assert_eq!(report.row_offsets, vec![0, 100, 200]);
+        assert_eq!(report.chunks.len(), 2);
+
+        let root = &report.chunks[0].array;
+        assert_eq!(root.encoding, "vortex.primitive");
+        assert_eq!(root.buffers, vec![400]);
+        assert_eq!(root.stats.get("min").map(String::as_str), Some("0"));
+        assert!(report.to_string().contains("chunk 1: rows 100..200"));
+    }
+}
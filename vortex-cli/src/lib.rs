@@ -0,0 +1,42 @@
+use lazy_static::lazy_static;
+use vortex::encoding::EncodingRef;
+use vortex::Context;
+use vortex_alp::ALPEncoding;
+use vortex_bytebool::ByteBoolEncoding;
+use vortex_datetime_parts::DateTimePartsEncoding;
+use vortex_dict::DictEncoding;
+use vortex_fastlanes::{BitPackedEncoding, DeltaEncoding, FoREncoding};
+use vortex_ree::REEEncoding;
+use vortex_roaring::{RoaringBoolEncoding, RoaringIntEncoding};
+use vortex_zigzag::ZigZagEncoding;
+
+pub mod convert;
+pub mod inspect;
+
+lazy_static! {
+    /// The encodings used to compress converted files.
+    pub static ref CTX: Context = Context::default().with_encodings([
+        &ALPEncoding as EncodingRef,
+        &DictEncoding,
+        &BitPackedEncoding,
+        &FoREncoding,
+        &DateTimePartsEncoding,
+        &REEEncoding,
+        &RoaringBoolEncoding,
+    ]);
+
+    /// Every encoding that may appear in the files being inspected.
+    pub static ref READ_CTX: Context = Context::default().with_encodings([
+        &ALPEncoding as EncodingRef,
+        &ByteBoolEncoding,
+        &DateTimePartsEncoding,
+        &DictEncoding,
+        &BitPackedEncoding,
+        &DeltaEncoding,
+        &FoREncoding,
+        &REEEncoding,
+        &RoaringBoolEncoding,
+        &RoaringIntEncoding,
+        &ZigZagEncoding,
+    ]);
+}
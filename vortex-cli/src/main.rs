@@ -0,0 +1,191 @@
+use std::fs::File;
+use std::path::PathBuf;
+
+use clap::{Args, Parser, Subcommand};
+use log::{info, LevelFilter};
+use simplelog::{ColorChoice, Config, TermLogger, TerminalMode};
+use vortex::compress::{CompressConfig, CompressionTrace, FieldOverride};
+use vortex_cli::convert::{convert_parquet, ConvertOptions, DEFAULT_CHUNK_SIZE};
+use vortex_cli::inspect::Report;
+use vortex_cli::{CTX, READ_CTX};
+use vortex_dtype::field::FieldPath;
+use vortex_error::{vortex_err, VortexResult};
+use vortex_ipc::io::TokioAdapter;
+
+#[derive(Parser)]
+#[command(
+    name = "vortex",
+    about = "Tools for working with Vortex files",
+    version
+)]
+struct Cli {
+    /// Log verbosity.
+    #[arg(long, global = true, default_value_t = LevelFilter::Warn)]
+    log_level: LevelFilter,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Convert a Parquet file into a Vortex IPC file.
+    Convert(ConvertArgs),
+    /// Describe the schema, chunks, encodings and statistics of a Vortex IPC file.
+    Inspect(InspectArgs),
+}
+
+#[derive(Args)]
+struct ConvertArgs {
+    /// The Parquet file to read.
+    input: PathBuf,
+
+    /// The Vortex file to write.
+    output: PathBuf,
+
+    /// The maximum number of rows in each chunk.
+    #[arg(long, default_value_t = DEFAULT_CHUNK_SIZE)]
+    chunk_size: usize,
+
+    /// Fill chunks across Parquet row groups, rather than starting a chunk at each row group.
+    #[arg(long)]
+    rechunk: bool,
+
+    /// Comma separated top-level columns to convert, defaulting to all of them.
+    #[arg(long, value_delimiter = ',')]
+    columns: Option<Vec<String>>,
+
+    /// The number of rows in each compression sample.
+    #[arg(long)]
+    sample_size: Option<u16>,
+
+    /// The number of samples taken from each chunk.
+    #[arg(long)]
+    sample_count: Option<u16>,
+
+    /// The maximum depth of nested encodings.
+    #[arg(long)]
+    max_depth: Option<u8>,
+
+    /// The seed used to choose compression samples.
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// The number of threads compressing columns and chunks, or zero for one per core.
+    #[arg(long)]
+    threads: Option<usize>,
+
+    /// Comma separated top-level columns to store a bloom filter of with each chunk.
+    #[arg(long, value_delimiter = ',')]
+    bloom_filter: Vec<String>,
+
+    /// The false positive rate of the bloom filters.
+    #[arg(long, default_value_t = 0.01)]
+    bloom_filter_fpr: f64,
+
+    /// Write a JSON trace of the encodings chosen for each array to this file.
+    #[arg(long)]
+    trace: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct InspectArgs {
+    /// The Vortex file to read.
+    input: PathBuf,
+
+    /// Print the report as JSON.
+    #[arg(long)]
+    json: bool,
+}
+
+impl ConvertArgs {
+    fn options(&self) -> ConvertOptions {
+        let mut config = CompressConfig::default();
+        if let Some(sample_size) = self.sample_size {
+            config = config.with_sample_size(sample_size);
+        }
+        if let Some(sample_count) = self.sample_count {
+            config = config.with_sample_count(sample_count);
+        }
+        if let Some(max_depth) = self.max_depth {
+            config = config.with_max_depth(max_depth);
+        }
+        if let Some(seed) = self.seed {
+            config = config.with_rng_seed(seed);
+        }
+        if let Some(threads) = self.threads {
+            config = config.with_threads(threads);
+        }
+        for column in &self.bloom_filter {
+            config = config.with_field_override(
+                FieldPath::from_name(column),
+                FieldOverride::default().with_bloom_filter(self.bloom_filter_fpr),
+            );
+        }
+
+        let options = ConvertOptions::default()
+            .with_chunk_size(self.chunk_size)
+            .with_rechunk(self.rechunk)
+            .with_compress_config(config);
+        match &self.columns {
+            Some(columns) => options.with_columns(columns.iter().cloned()),
+            None => options,
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() -> VortexResult<()> {
+    let cli = Cli::parse();
+    TermLogger::init(
+        cli.log_level,
+        Config::default(),
+        TerminalMode::Stderr,
+        ColorChoice::Auto,
+    )
+    .unwrap();
+
+    match cli.command {
+        Command::Convert(args) => convert(args).await,
+        Command::Inspect(args) => inspect(args).await,
+    }
+}
+
+async fn convert(args: ConvertArgs) -> VortexResult<()> {
+    let input = File::open(&args.input)?;
+    let output = tokio::fs::File::create(&args.output).await?;
+    let trace = CompressionTrace::new();
+    let options = match &args.trace {
+        Some(_) => args.options().with_trace(trace.clone()),
+        None => args.options(),
+    };
+    let written = convert_parquet(input, output, &CTX, &options).await?;
+    let chunks = &written.array_layouts()[0].chunks;
+    info!(
+        "Wrote {} rows in {} chunks ({} bytes) to {}",
+        chunks.row_offsets.last().copied().unwrap_or_default(),
+        chunks.row_offsets.len() - 1,
+        chunks.byte_offsets.last().copied().unwrap_or_default(),
+        args.output.display()
+    );
+
+    if let Some(path) = &args.trace {
+        let json = serde_json::to_string_pretty(&trace.arrays())
+            .map_err(|err| vortex_err!("Failed to serialize trace: {}", err))?;
+        std::fs::write(path, json)?;
+    }
+    Ok(())
+}
+
+async fn inspect(args: InspectArgs) -> VortexResult<()> {
+    let input = tokio::fs::File::open(&args.input).await?;
+    let report: Report = vortex_cli::inspect::inspect(TokioAdapter(input), &READ_CTX).await?;
+    if args.json {
+        let json = serde_json::to_string_pretty(&report)
+            .map_err(|err| vortex_err!("Failed to serialize report: {}", err))?;
+        println!("{}", json);
+    } else {
+        print!("{}", report);
+    }
+    Ok(())
+}
@@ -1,9 +1,18 @@
+use std::iter;
+
 use arrow_schema::SchemaRef;
 use datafusion::optimizer::simplify_expressions::ExprSimplifier;
-use datafusion_common::{Result as DFResult, ToDFSchema};
+use datafusion_common::{Result as DFResult, ScalarValue, ToDFSchema};
 use datafusion_expr::execution_props::ExecutionProps;
 use datafusion_expr::simplify::SimplifyContext;
-use datafusion_expr::{and, lit, Expr};
+use datafusion_expr::utils::split_conjunction;
+use datafusion_expr::{and, lit, BinaryExpr, Expr, Operator};
+use vortex_dtype::field::{Field, FieldPath};
+use vortex_expr::{Predicate, Value};
+use vortex_scalar::Scalar;
+
+/// Name of the DataFusion scalar function used to access struct fields, e.g. `a.b`.
+pub(crate) const GET_FIELD: &str = "get_field";
 
 /// Convert a set of expressions into a single AND expression.
 ///
@@ -32,11 +41,100 @@ pub(crate) fn simplify_expr(expr: &Expr, schema: SchemaRef) -> DFResult<Expr> {
     simplifier.simplify(expr.clone())
 }
 
+/// Resolve an expression that references a (possibly nested) column into its [FieldPath].
+///
+/// Columns resolve to a single-element path, and chains of [`get_field`](GET_FIELD) calls with
+/// literal field names on top of a column extend that path, e.g. `a.b.c` resolves to
+/// `$a.$b.$c`. Any other expression returns `None`.
+pub(crate) fn field_path(expr: &Expr) -> Option<FieldPath> {
+    match expr {
+        Expr::Column(column) => Some(FieldPath::from_name(column.name.as_str())),
+        Expr::ScalarFunction(func) if func.name() == GET_FIELD => {
+            let [base, Expr::Literal(ScalarValue::Utf8(Some(name)))] = func.args.as_slice() else {
+                return None;
+            };
+
+            field_path(base).map(|path| {
+                path.path()
+                    .iter()
+                    .cloned()
+                    .chain(iter::once(Field::from(name.as_str())))
+                    .collect()
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Convert the conjuncts of an expression that compare a (possibly nested) column to a literal
+/// into Vortex predicates, which can be checked against the statistics of a chunk.
+///
+/// All other conjuncts are dropped, so the predicates hold for a superset of the selected rows.
+/// The returned flag is `true` if no conjunct was dropped, in which case the predicates select
+/// exactly the rows the expression selects.
+pub(crate) fn comparison_predicates(expr: &Expr) -> (Vec<Predicate>, bool) {
+    let conjuncts = split_conjunction(expr);
+    let predicates = conjuncts
+        .iter()
+        .filter_map(|conjunct| comparison_predicate(conjunct))
+        .collect::<Vec<_>>();
+    let complete = predicates.len() == conjuncts.len();
+    (predicates, complete)
+}
+
+/// Convert a comparison of a (possibly nested) column with a literal into a Vortex predicate on
+/// that column.
+fn comparison_predicate(expr: &Expr) -> Option<Predicate> {
+    let Expr::BinaryExpr(BinaryExpr { left, op, right }) = expr else {
+        return None;
+    };
+    // Predicates always hold the column on the left-hand side.
+    let (column, op, value) = match (left.as_ref(), right.as_ref()) {
+        (column, Expr::Literal(value)) => (column, *op, value),
+        (Expr::Literal(value), column) => (column, op.swap()?, value),
+        _ => return None,
+    };
+    let op = match op {
+        Operator::Eq => vortex_expr::Operator::Eq,
+        Operator::NotEq => vortex_expr::Operator::NotEq,
+        Operator::Lt => vortex_expr::Operator::Lt,
+        Operator::LtEq => vortex_expr::Operator::Lte,
+        Operator::Gt => vortex_expr::Operator::Gt,
+        Operator::GtEq => vortex_expr::Operator::Gte,
+        _ => return None,
+    };
+
+    Some(Predicate {
+        lhs: field_path(column)?,
+        op,
+        rhs: Value::Literal(literal_scalar(value)?),
+    })
+}
+
+/// The Vortex scalar of a non-null integer, float or string literal.
+fn literal_scalar(value: &ScalarValue) -> Option<Scalar> {
+    Some(match value {
+        ScalarValue::Int8(Some(v)) => (*v).into(),
+        ScalarValue::Int16(Some(v)) => (*v).into(),
+        ScalarValue::Int32(Some(v)) => (*v).into(),
+        ScalarValue::Int64(Some(v)) => (*v).into(),
+        ScalarValue::UInt8(Some(v)) => (*v).into(),
+        ScalarValue::UInt16(Some(v)) => (*v).into(),
+        ScalarValue::UInt32(Some(v)) => (*v).into(),
+        ScalarValue::UInt64(Some(v)) => (*v).into(),
+        ScalarValue::Float32(Some(v)) => (*v).into(),
+        ScalarValue::Float64(Some(v)) => (*v).into(),
+        ScalarValue::Utf8(Some(v)) | ScalarValue::LargeUtf8(Some(v)) => v.as_str().into(),
+        _ => return None,
+    })
+}
+
 #[cfg(test)]
 mod test {
     use std::sync::Arc;
 
-    use arrow_schema::{DataType, Field, Schema};
+    use arrow_schema::{DataType, Field as ArrowField, Schema};
+    use datafusion::functions::core::expr_ext::FieldAccessor;
     use datafusion_expr::{col, lit};
 
     use super::*;
@@ -44,8 +142,8 @@ mod test {
     #[test]
     fn test_conjunction_simplify() {
         let schema = Arc::new(Schema::new(vec![
-            Field::new("int_col", DataType::Int32, false),
-            Field::new("bool_col", DataType::Boolean, false),
+            ArrowField::new("int_col", DataType::Int32, false),
+            ArrowField::new("bool_col", DataType::Boolean, false),
         ]));
 
         let exprs = vec![col("int_col").gt_eq(lit(4)), col("bool_col").is_true()];
@@ -55,4 +153,43 @@ mod test {
             and(col("int_col").gt_eq(lit(4)), col("bool_col").is_true())
         );
     }
+
+    #[test]
+    fn test_field_path() {
+        assert_eq!(field_path(&col("a")), Some(FieldPath::from_name("a")));
+        assert_eq!(
+            field_path(&col("a").field("b").field("c")),
+            Some(FieldPath::from(vec![
+                Field::from("a"),
+                Field::from("b"),
+                Field::from("c"),
+            ]))
+        );
+        assert_eq!(field_path(&(col("a") + lit(1))), None);
+    }
+
+    #[test]
+    fn test_comparison_predicates() {
+        let expr = and(col("a").field("b").eq(lit(1i64)), lit(5u32).lt(col("c")));
+        assert_eq!(
+            comparison_predicates(&expr),
+            (
+                vec![
+                    vortex_expr::lit(1i64)
+                        .eq(FieldPath::from(vec![Field::from("a"), Field::from("b"),])),
+                    Predicate {
+                        lhs: FieldPath::from_name("c"),
+                        op: vortex_expr::Operator::Gt,
+                        rhs: vortex_expr::lit(5u32),
+                    },
+                ],
+                true
+            )
+        );
+
+        let (predicates, complete) =
+            comparison_predicates(&and(col("c").gt_eq(lit(5u32)), col("d").is_not_null()));
+        assert_eq!(predicates.len(), 1);
+        assert!(!complete);
+    }
 }
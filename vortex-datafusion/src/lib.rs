@@ -7,7 +7,7 @@ use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll};
 
-use arrow_array::{RecordBatch, StructArray as ArrowStructArray};
+use arrow_array::RecordBatch;
 use arrow_schema::SchemaRef;
 use async_trait::async_trait;
 use datafusion::dataframe::DataFrame;
@@ -15,8 +15,11 @@ use datafusion::datasource::TableProvider;
 use datafusion::execution::context::SessionState;
 use datafusion::execution::{RecordBatchStream, SendableRecordBatchStream, TaskContext};
 use datafusion::prelude::SessionContext;
+use datafusion_common::stats::Precision;
 use datafusion_common::tree_node::{TreeNode, TreeNodeRecursion, TreeNodeVisitor};
-use datafusion_common::{exec_datafusion_err, DataFusionError, Result as DFResult};
+use datafusion_common::{
+    exec_datafusion_err, ColumnStatistics, DataFusionError, Result as DFResult, Statistics,
+};
 use datafusion_expr::{Expr, Operator, TableProviderFilterPushDown, TableType};
 use datafusion_physical_expr::EquivalenceProperties;
 use datafusion_physical_plan::{
@@ -27,17 +30,22 @@ use itertools::Itertools;
 use pin_project::pin_project;
 use vortex::array::chunked::ChunkedArray;
 use vortex::array::struct_::StructArray;
-use vortex::{Array, ArrayDType, IntoArrayVariant, IntoCanonical};
+use vortex::arrow::infer_schema;
+use vortex::stats::{ArrayStatistics, Stat};
+use vortex::{Array, ArrayDType, ArrayTrait, IntoArrayVariant};
+use vortex_dtype::field::FieldPath;
 use vortex_dtype::DType;
 use vortex_error::VortexResult;
 
-use crate::datatype::infer_schema;
-use crate::plans::{RowSelectorExec, TakeRowsExec};
+use crate::expr::field_path;
+use crate::plans::{decode_fields, RowSelectorExec, TakeRowsExec};
 
-mod datatype;
 mod expr;
+mod optimizer;
 mod plans;
 
+pub use optimizer::{AggregatePushdown, NestedProjectionPushdown};
+
 /// Optional configurations to pass when loading a [VortexMemTable].
 #[derive(Default, Debug, Clone)]
 pub struct VortexMemTableOptions {
@@ -92,11 +100,10 @@ pub struct VortexMemTable {
 impl VortexMemTable {
     /// Build a new table provider from an existing [struct type](vortex_dtype::StructDType) array.
     ///
-    /// # Panics
-    ///
-    /// Creation will panic if the provided array is not of `DType::Struct` type.
+    /// Returns an error if the provided array is not of non-nullable `DType::Struct` type, or if
+    /// any of its fields cannot be represented in Arrow.
     pub fn try_new(array: Array, options: VortexMemTableOptions) -> VortexResult<Self> {
-        let arrow_schema = infer_schema(array.dtype());
+        let arrow_schema = infer_schema(array.dtype())?;
         let schema_ref = SchemaRef::new(arrow_schema);
 
         Ok(Self {
@@ -125,6 +132,9 @@ impl TableProvider for VortexMemTable {
     ///
     /// Currently, projection pushdown is supported, but not filter pushdown.
     /// The array is flattened directly into the nearest Arrow-compatible encoding.
+    ///
+    /// The chunks of the array are split into up to `target_partitions` independent partitions,
+    /// each of which decodes its own chunks.
     async fn scan(
         &self,
         state: &SessionState,
@@ -132,17 +142,14 @@ impl TableProvider for VortexMemTable {
         filters: &[Expr],
         _limit: Option<usize>,
     ) -> DFResult<Arc<dyn ExecutionPlan>> {
-        fn get_filter_projection(exprs: &[Expr], schema: SchemaRef) -> Vec<usize> {
-            let referenced_columns: HashSet<String> =
-                exprs.iter().flat_map(get_column_references).collect();
-
-            let projection: Vec<usize> = referenced_columns
-                .iter()
-                .map(|col_name| schema.column_with_name(col_name).unwrap().0)
-                .sorted()
-                .collect();
-
-            projection
+        fn get_filter_projection(exprs: &[Expr]) -> Vec<FieldPath> {
+            let referenced_fields: HashSet<FieldPath> =
+                exprs.iter().flat_map(get_field_references).collect();
+
+            referenced_fields
+                .into_iter()
+                .sorted_by_cached_key(|path| path.to_string())
+                .collect()
         }
 
         let filter_exprs = if filters.is_empty() {
@@ -151,55 +158,47 @@ impl TableProvider for VortexMemTable {
             Some(filters)
         };
 
-        let partitioning = if let Ok(chunked_array) = ChunkedArray::try_from(&self.array) {
-            Partitioning::RoundRobinBatch(chunked_array.nchunks())
-        } else {
-            Partitioning::UnknownPartitioning(1)
-        };
+        let partitions = partition_chunks(&self.array, state.config().target_partitions());
 
         let output_projection: Vec<usize> = match projection {
             None => (0..self.schema_ref.fields().len()).collect(),
             Some(proj) => proj.clone(),
         };
+        let output_schema = Arc::new(
+            self.schema_ref
+                .project(output_projection.as_slice())
+                .expect("project output schema"),
+        );
+        let output_paths: Vec<FieldPath> = output_schema
+            .fields()
+            .iter()
+            .map(|field| FieldPath::from_name(field.name()))
+            .collect();
 
         match filter_exprs {
             // If there is a filter expression, we execute in two phases, first performing a filter
             // on the input to get back row indices, and then taking the remaining struct columns
             // using the calculated indices from the filter.
             Some(filter_exprs) => {
-                let filter_projection =
-                    get_filter_projection(filter_exprs, self.schema_ref.clone());
+                let filter_projection = get_filter_projection(filter_exprs);
 
-                Ok(make_filter_then_take_plan(
-                    self.schema_ref.clone(),
+                make_filter_then_take_plan(
+                    output_schema,
                     filter_exprs,
                     filter_projection,
-                    self.array.clone(),
-                    output_projection.clone(),
+                    partitions,
+                    output_paths,
                     state,
-                ))
+                )
             }
 
-            // If no filters were pushed down, we materialize the entire StructArray into a
+            // If no filters were pushed down, we materialize each chunk of the StructArray into a
             // RecordBatch and let DataFusion process the entire query.
-            _ => {
-                let output_schema = Arc::new(
-                    self.schema_ref
-                        .project(output_projection.as_slice())
-                        .expect("project output schema"),
-                );
-                let plan_properties = PlanProperties::new(
-                    EquivalenceProperties::new(output_schema),
-                    partitioning,
-                    ExecutionMode::Bounded,
-                );
-
-                Ok(Arc::new(VortexScanExec {
-                    array: self.array.clone(),
-                    scan_projection: output_projection.clone(),
-                    plan_properties,
-                }))
-            }
+            _ => Ok(Arc::new(VortexScanExec::new(
+                partitions.into(),
+                output_paths,
+                output_schema,
+            ))),
         }
     }
 
@@ -229,35 +228,86 @@ impl TableProvider for VortexMemTable {
     }
 }
 
+/// Split the chunks of `array` into at most `target_partitions` contiguous groups.
+///
+/// Each group is scanned by a separate DataFusion partition, allowing the chunks of a
+/// [ChunkedArray] to be decoded concurrently. Non-chunked arrays are scanned as a single chunk.
+/// At least one (possibly empty) partition is always returned.
+fn partition_chunks(array: &Array, target_partitions: usize) -> Vec<Vec<Array>> {
+    let chunks: Vec<Array> = match ChunkedArray::try_from(array) {
+        Ok(chunked_array) => chunked_array.chunks().collect(),
+        Err(_) => vec![array.clone()],
+    };
+
+    let npartitions = target_partitions.clamp(1, chunks.len().max(1));
+    let chunks_per_partition = chunks.len() / npartitions;
+    let remainder = chunks.len() % npartitions;
+
+    // The first `remainder` partitions receive one extra chunk each.
+    let mut chunks = chunks.into_iter();
+    (0..npartitions)
+        .map(|partition| {
+            let nchunks = chunks_per_partition + usize::from(partition < remainder);
+            chunks.by_ref().take(nchunks).collect()
+        })
+        .collect()
+}
+
+/// Project each chunk of each partition down to the struct fields referenced by `projection`,
+/// preserving the nesting of the referenced fields.
+fn project_partitions(
+    partitions: &[Vec<Array>],
+    projection: &[FieldPath],
+) -> DFResult<Vec<Vec<StructArray>>> {
+    partitions
+        .iter()
+        .map(|chunks| {
+            chunks
+                .iter()
+                .map(|chunk| {
+                    chunk
+                        .clone()
+                        .into_struct()
+                        .and_then(|struct_array| struct_array.project_paths(projection))
+                        .map_err(|vortex_err| {
+                            exec_datafusion_err!(
+                                "projection pushdown to Vortex failed: {vortex_err}"
+                            )
+                        })
+                })
+                .try_collect()
+        })
+        .try_collect()
+}
+
 /// Construct an operator plan that executes in two stages.
 ///
-/// The first plan stage only materializes the columns related to the provided set of filter
-/// expressions. It evaluates the filters into a row selection.
+/// The first plan stage only materializes the (possibly nested) fields related to the provided
+/// set of filter expressions. It evaluates the filters into a row selection.
 ///
 /// The second stage receives the row selection above and dispatches a `take` on the remaining
 /// columns.
+///
+/// Both stages are partitioned identically, so every partition filters and takes from its own
+/// range of chunks independently of the others.
 fn make_filter_then_take_plan(
-    schema: SchemaRef,
+    output_schema: SchemaRef,
     filter_exprs: &[Expr],
-    filter_projection: Vec<usize>,
-    array: Array,
-    output_projection: Vec<usize>,
+    filter_projection: Vec<FieldPath>,
+    partitions: Vec<Vec<Array>>,
+    output_projection: Vec<FieldPath>,
     _session_state: &SessionState,
-) -> Arc<dyn ExecutionPlan> {
-    let struct_array = StructArray::try_from(array).unwrap();
-
-    let filter_struct = struct_array
-        .project(filter_projection.as_slice())
-        .expect("projecting filter struct");
+) -> DFResult<Arc<dyn ExecutionPlan>> {
+    let filter_partitions = project_partitions(&partitions, filter_projection.as_slice())?;
 
-    let row_selector_op = Arc::new(RowSelectorExec::new(filter_exprs, &filter_struct));
+    let row_selector_op = Arc::new(RowSelectorExec::new(filter_exprs, filter_partitions));
 
-    Arc::new(TakeRowsExec::new(
-        schema.clone(),
-        &output_projection,
+    Ok(Arc::new(TakeRowsExec::new(
+        output_schema,
+        output_projection,
         row_selector_op.clone(),
-        &struct_array,
-    ))
+        partitions.into(),
+    )))
 }
 
 /// Check if the given expression tree can be pushed down into the scan.
@@ -294,6 +344,8 @@ fn can_be_pushed_down(expr: &Expr) -> DFResult<bool> {
             | Expr::Literal(_)
             // TODO(aduffy): ensure that cast can be pushed down.
             | Expr::Cast(_) => true,
+            // Access to nested struct fields, e.g. `a.b`.
+            Expr::ScalarFunction(_) => field_path(expr).is_some(),
             _ => false,
         }
     }
@@ -329,17 +381,18 @@ fn can_be_pushed_down(expr: &Expr) -> DFResult<bool> {
     Ok(visitor.supported_expressions_only)
 }
 
-/// Extract out the columns from our table referenced by the expression.
-fn get_column_references(expr: &Expr) -> HashSet<String> {
+/// Extract out the (possibly nested) fields from our table referenced by the expression.
+fn get_field_references(expr: &Expr) -> HashSet<FieldPath> {
     let mut references = HashSet::new();
 
-    expr.apply(|node| match node {
-        Expr::Column(col) => {
-            references.insert(col.name.clone());
+    expr.apply(|node| match field_path(node) {
+        Some(path) => {
+            references.insert(path);
 
-            Ok(TreeNodeRecursion::Continue)
+            // Do not descend into the column referenced by a nested field access.
+            Ok(TreeNodeRecursion::Jump)
         }
-        _ => Ok(TreeNodeRecursion::Continue),
+        None => Ok(TreeNodeRecursion::Continue),
     })
     .unwrap();
 
@@ -347,54 +400,60 @@ fn get_column_references(expr: &Expr) -> HashSet<String> {
 }
 
 /// Physical plan node for scans against an in-memory, possibly chunked Vortex Array.
+///
+/// Every partition owns a contiguous range of chunks, which it decodes lazily as its output
+/// stream is polled. Each output column holds the (possibly nested) field at the corresponding
+/// [FieldPath] of the projection.
 #[derive(Debug, Clone)]
-struct VortexScanExec {
-    array: Array,
-    scan_projection: Vec<usize>,
+pub(crate) struct VortexScanExec {
+    partitions: Arc<[Vec<Array>]>,
+    scan_projection: Vec<FieldPath>,
     plan_properties: PlanProperties,
 }
 
+impl VortexScanExec {
+    pub(crate) fn new(
+        partitions: Arc<[Vec<Array>]>,
+        scan_projection: Vec<FieldPath>,
+        output_schema: SchemaRef,
+    ) -> Self {
+        let plan_properties = PlanProperties::new(
+            EquivalenceProperties::new(output_schema),
+            Partitioning::UnknownPartitioning(partitions.len()),
+            ExecutionMode::Bounded,
+        );
+
+        Self {
+            partitions,
+            scan_projection,
+            plan_properties,
+        }
+    }
+
+    pub(crate) fn partitions(&self) -> Arc<[Vec<Array>]> {
+        self.partitions.clone()
+    }
+
+    pub(crate) fn projection(&self) -> &[FieldPath] {
+        self.scan_projection.as_slice()
+    }
+
+    /// Create a copy of this scan that produces the given fields instead.
+    pub(crate) fn with_projection(
+        &self,
+        scan_projection: Vec<FieldPath>,
+        output_schema: SchemaRef,
+    ) -> Self {
+        Self::new(self.partitions.clone(), scan_projection, output_schema)
+    }
+}
+
 impl DisplayAs for VortexScanExec {
     fn fmt_as(&self, _display_type: DisplayFormatType, f: &mut Formatter) -> std::fmt::Result {
         write!(f, "{:?}", self)
     }
 }
 
-/// Read a single array chunk from the source as a RecordBatch.
-///
-/// # Errors
-/// This function will return an Error if `array` is not struct-typed. It will also return an
-/// error if the projection references columns
-fn execute_unfiltered(
-    array: Array,
-    projection: &Vec<usize>,
-) -> DFResult<SendableRecordBatchStream> {
-    // Construct the RecordBatch by flattening each struct field and transmuting to an ArrayRef.
-    let struct_array = array
-        .clone()
-        .into_struct()
-        .map_err(|vortex_error| DataFusionError::Execution(format!("{}", vortex_error)))?;
-
-    let projected_struct = struct_array
-        .project(projection.as_slice())
-        .map_err(|vortex_err| {
-            exec_datafusion_err!("projection pushdown to Vortex failed: {vortex_err}")
-        })?;
-    let batch = RecordBatch::from(
-        projected_struct
-            .into_canonical()
-            .expect("struct arrays must canonicalize")
-            .into_arrow()
-            .as_any()
-            .downcast_ref::<ArrowStructArray>()
-            .expect("vortex StructArray must convert to arrow StructArray"),
-    );
-    Ok(Box::pin(VortexRecordBatchStream {
-        schema_ref: batch.schema(),
-        inner: futures::stream::iter(vec![batch]),
-    }))
-}
-
 // Row selector stream.
 // I.e., send a stream of RowSelector which allows us to pass in a bunch of binary arrays
 // back down to the other systems here instead.
@@ -409,23 +468,18 @@ pub(crate) struct VortexRecordBatchStream<I> {
 
 impl<I> Stream for VortexRecordBatchStream<I>
 where
-    I: Stream<Item = RecordBatch>,
+    I: Stream<Item = DFResult<RecordBatch>>,
 {
     type Item = DFResult<RecordBatch>;
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        let mut this = self.project();
-        match this.inner.poll_next_unpin(cx) {
-            Poll::Ready(Some(batch)) => Poll::Ready(Some(Ok(batch))),
-            Poll::Ready(None) => Poll::Ready(None),
-            Poll::Pending => Poll::Pending,
-        }
+        self.project().inner.poll_next_unpin(cx)
     }
 }
 
 impl<I> RecordBatchStream for VortexRecordBatchStream<I>
 where
-    I: Stream<Item = RecordBatch>,
+    I: Stream<Item = DFResult<RecordBatch>>,
 {
     fn schema(&self) -> SchemaRef {
         Arc::clone(&self.schema_ref)
@@ -453,37 +507,110 @@ impl ExecutionPlan for VortexScanExec {
         Ok(self)
     }
 
+    /// Row counts are exact, while null and distinct counts are merged from the statistics already
+    /// stored with each chunk. No statistics are computed while planning.
+    fn statistics(&self) -> DFResult<Statistics> {
+        let chunks: Vec<StructArray> = self
+            .partitions
+            .iter()
+            .flatten()
+            .map(|chunk| chunk.clone().into_struct())
+            .collect::<VortexResult<_>>()
+            .map_err(|vortex_err| exec_datafusion_err!("{vortex_err}"))?;
+
+        Ok(Statistics {
+            num_rows: Precision::Exact(chunks.iter().map(|chunk| chunk.len()).sum()),
+            total_byte_size: Precision::Absent,
+            column_statistics: self
+                .scan_projection
+                .iter()
+                .map(|path| column_statistics(&chunks, path))
+                .collect(),
+        })
+    }
+
     fn execute(
         &self,
         partition: usize,
         _context: Arc<TaskContext>,
     ) -> DFResult<SendableRecordBatchStream> {
-        let chunk = if let Ok(chunked_array) = ChunkedArray::try_from(&self.array) {
-            chunked_array
-                .chunk(partition)
-                .ok_or_else(|| exec_datafusion_err!("partition not found"))?
-        } else {
-            self.array.clone()
-        };
+        let chunks = self
+            .partitions
+            .get(partition)
+            .ok_or_else(|| exec_datafusion_err!("partition not found"))?
+            .clone();
+        let projection = self.scan_projection.clone();
+        let schema_ref = self.schema();
+
+        // Chunks are decoded one at a time as the stream is polled, so that each partition
+        // performs its own decoding work on whichever thread drives it.
+        Ok(Box::pin(VortexRecordBatchStream {
+            schema_ref: schema_ref.clone(),
+            inner: futures::stream::iter(chunks).map(move |chunk| {
+                decode_fields(chunk, projection.as_slice(), schema_ref.as_ref(), None)
+            }),
+        }))
+    }
+}
 
-        execute_unfiltered(chunk, &self.scan_projection)
+/// Merge the statistics stored with the field at `path` of every chunk.
+///
+/// Fields of nullable structs are not decoded, so their stored null counts only bound the number
+/// of nulls from below and are not reported.
+fn column_statistics(chunks: &[StructArray], path: &FieldPath) -> ColumnStatistics {
+    let stats = chunks
+        .iter()
+        .map(|chunk| {
+            chunk
+                .field_and_validity_by_path(path)
+                .map(|(field, validity)| {
+                    let exact = validity.to_logical(field.len()).all_valid();
+                    (field.statistics().to_set(), exact)
+                })
+        })
+        .reduce(|acc, stats| {
+            let (mut acc, acc_exact) = acc?;
+            let (stats, exact) = stats?;
+            acc.merge(&stats);
+            Ok((acc, acc_exact && exact))
+        });
+    let Some(Ok((stats, exact))) = stats else {
+        return ColumnStatistics::new_unknown();
+    };
+
+    let count = |stat| {
+        stats
+            .get(stat)
+            .and_then(|value| usize::try_from(value).ok())
+    };
+    ColumnStatistics {
+        null_count: count(Stat::NullCount)
+            .filter(|_| exact)
+            .map_or(Precision::Absent, Precision::Exact),
+        // Distinct counts are estimated from sketches of the values.
+        distinct_count: count(Stat::DistinctCount).map_or(Precision::Absent, Precision::Inexact),
+        ..ColumnStatistics::new_unknown()
     }
 }
 
 #[cfg(test)]
 mod test {
-    use arrow_array::types::Int64Type;
+    use arrow_array::types::{Int64Type, UInt32Type};
     use datafusion::arrow::array::AsArray;
-    use datafusion::prelude::SessionContext;
-    use datafusion_expr::{col, count_distinct, lit};
+    use datafusion::functions::core::expr_fn::get_field;
+    use datafusion::prelude::{SessionConfig, SessionContext};
+    use datafusion_common::stats::Precision;
+    use datafusion_expr::{col, count, count_distinct, lit};
+    use vortex::array::chunked::ChunkedArray;
     use vortex::array::primitive::PrimitiveArray;
     use vortex::array::struct_::StructArray;
     use vortex::array::varbin::VarBinArray;
+    use vortex::stats::ArrayStatistics;
     use vortex::validity::Validity;
-    use vortex::{Array, IntoArray};
+    use vortex::{Array, ArrayDType, IntoArray};
     use vortex_dtype::{DType, Nullability};
 
-    use crate::{SessionContextExt, VortexMemTableOptions};
+    use crate::{partition_chunks, SessionContextExt, VortexMemTableOptions};
 
     fn presidents_array() -> Array {
         let names = VarBinArray::from_vec(
@@ -573,4 +700,129 @@ mod test {
             4i64
         );
     }
+
+    #[tokio::test]
+    async fn test_scan_statistics() {
+        let array = presidents_array();
+        StructArray::try_from(array.clone())
+            .unwrap()
+            .field(0)
+            .unwrap()
+            .statistics()
+            .compute_distinct_count();
+
+        let ctx = SessionContext::new();
+        let plan = ctx
+            .read_vortex(array)
+            .unwrap()
+            .create_physical_plan()
+            .await
+            .unwrap();
+        let statistics = plan.statistics().unwrap();
+
+        assert_eq!(statistics.num_rows, Precision::Exact(6));
+        assert_eq!(
+            statistics.column_statistics[0].distinct_count,
+            Precision::Inexact(5)
+        );
+        // Distinct counts are never computed while planning.
+        assert_eq!(
+            statistics.column_statistics[1].distinct_count,
+            Precision::Absent
+        );
+    }
+
+    fn chunked_presidents_array() -> Array {
+        let chunks = vec![presidents_array(), presidents_array(), presidents_array()];
+        let dtype = chunks[0].dtype().clone();
+        ChunkedArray::try_new(chunks, dtype).unwrap().into_array()
+    }
+
+    #[test]
+    fn test_partition_chunks() {
+        let chunked = chunked_presidents_array();
+
+        let partitions = partition_chunks(&chunked, 2);
+        assert_eq!(
+            partitions.iter().map(|p| p.len()).collect::<Vec<_>>(),
+            vec![2, 1]
+        );
+
+        // Never more partitions than there are chunks.
+        assert_eq!(partition_chunks(&chunked, 16).len(), 3);
+
+        // Non-chunked arrays are a single partition.
+        assert_eq!(partition_chunks(&presidents_array(), 4).len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_datafusion_partitioned_scan() {
+        for disable_pushdown in [false, true] {
+            let ctx =
+                SessionContext::new_with_config(SessionConfig::new().with_target_partitions(2));
+
+            let df = ctx
+                .read_vortex_opts(
+                    chunked_presidents_array(),
+                    VortexMemTableOptions::default().with_disable_pushdown(disable_pushdown),
+                )
+                .unwrap();
+
+            let counts = df
+                .filter(col("term_start").gt_eq(lit(1795)))
+                .unwrap()
+                .aggregate(
+                    vec![],
+                    vec![count(col("president")), count_distinct(col("president"))],
+                )
+                .unwrap()
+                .collect()
+                .await
+                .unwrap();
+
+            assert_eq!(counts.len(), 1);
+            assert_eq!(counts[0].column(0).as_primitive::<Int64Type>().value(0), 15);
+            assert_eq!(counts[0].column(1).as_primitive::<Int64Type>().value(0), 4);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_nested_filter_with_null_parent() {
+        let b = PrimitiveArray::from_vec(vec![1i64, 1, 2], Validity::NonNullable);
+        let a = StructArray::try_new(
+            ["b".into()].into(),
+            vec![b.into_array()],
+            3,
+            Validity::from(vec![true, false, true]),
+        )
+        .unwrap();
+        let ids = PrimitiveArray::from_vec(vec![0u32, 1, 2], Validity::NonNullable);
+        let table = StructArray::from_fields(&[("a", a.into_array()), ("id", ids.into_array())])
+            .into_array();
+
+        let ctx = SessionContext::new();
+        let selected = ctx
+            .read_vortex(table)
+            .unwrap()
+            .filter(get_field(col("a"), "b").eq(lit(1i64)))
+            .unwrap()
+            .select_columns(&["id"])
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        // The second row holds b = 1 within a null struct.
+        let ids = selected
+            .iter()
+            .flat_map(|batch| {
+                batch
+                    .column(0)
+                    .as_primitive::<UInt32Type>()
+                    .values()
+                    .to_vec()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec![0]);
+    }
 }
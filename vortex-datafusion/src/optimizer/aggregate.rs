@@ -0,0 +1,452 @@
+use std::sync::Arc;
+
+use arrow_schema::{DataType, SchemaRef};
+use datafusion::config::ConfigOptions;
+use datafusion::physical_optimizer::PhysicalOptimizerRule;
+use datafusion_common::tree_node::{Transformed, TreeNode};
+use datafusion_common::Result as DFResult;
+use datafusion_expr::utils::COUNT_STAR_EXPANSION;
+use datafusion_physical_expr::expressions::{Column, Count, Literal, Max, Min};
+use datafusion_physical_expr::{AggregateExpr, PhysicalExpr};
+use datafusion_physical_plan::aggregates::AggregateExec;
+use datafusion_physical_plan::coalesce_batches::CoalesceBatchesExec;
+use datafusion_physical_plan::repartition::RepartitionExec;
+use datafusion_physical_plan::udaf::AggregateFunctionExpr;
+use datafusion_physical_plan::ExecutionPlan;
+use vortex::Array;
+use vortex_dtype::field::FieldPath;
+
+use crate::plans::{
+    AggregateFilter, RowSelectorExec, StatisticsAggregate, StatisticsAggregateExec, TakeRowsExec,
+};
+use crate::VortexScanExec;
+
+/// Physical optimizer rule that answers simple aggregates over a Vortex scan without decoding
+/// the scanned columns.
+///
+/// Ungrouped `count(*)`, `count(col)`, `min(col)` and `max(col)` aggregates over a Vortex scan
+/// are replaced with a [StatisticsAggregateExec], which computes its results from the statistics
+/// of each chunk (`NullCount`, `TrueCount`, `Min` and `Max`). Statistics that were persisted
+/// alongside the data are used as-is, and the remaining ones are computed by the encodings
+/// themselves.
+///
+/// Filters pushed down into the scan are applied by the [StatisticsAggregateExec]: chunks whose
+/// statistics prove that none or all of their rows pass the filter are skipped or aggregated from
+/// their statistics, and only the selected rows of the other chunks are aggregated.
+#[derive(Debug, Default)]
+pub struct AggregatePushdown;
+
+impl PhysicalOptimizerRule for AggregatePushdown {
+    fn optimize(
+        &self,
+        plan: Arc<dyn ExecutionPlan>,
+        _config: &ConfigOptions,
+    ) -> DFResult<Arc<dyn ExecutionPlan>> {
+        plan.transform_down(|plan| {
+            Ok(match push_aggregate(&plan) {
+                Some(new_plan) => Transformed::yes(new_plan),
+                None => Transformed::no(plan),
+            })
+        })
+        .map(|transformed| transformed.data)
+    }
+
+    fn name(&self) -> &str {
+        "vortex_aggregate_pushdown"
+    }
+}
+
+/// Replace an ungrouped aggregation over a Vortex scan with a [StatisticsAggregateExec], if all of
+/// its aggregate expressions can be answered from statistics.
+fn push_aggregate(plan: &Arc<dyn ExecutionPlan>) -> Option<Arc<dyn ExecutionPlan>> {
+    let aggregate = plan.as_any().downcast_ref::<AggregateExec>()?;
+    if !aggregate.group_expr().is_empty() {
+        return None;
+    }
+
+    // A final aggregate merges the results of a partial aggregate somewhere below it.
+    let mut first_stage_plan = plan.clone();
+    while !first_stage_plan
+        .as_any()
+        .downcast_ref::<AggregateExec>()
+        .is_some_and(|agg| agg.mode().is_first_stage())
+    {
+        let child = match first_stage_plan.children().as_slice() {
+            [child] => Arc::clone(child),
+            _ => return None,
+        };
+        first_stage_plan = child;
+    }
+    let first_stage = first_stage_plan
+        .as_any()
+        .downcast_ref::<AggregateExec>()
+        .expect("first stage is an aggregate");
+    if !first_stage.group_expr().is_empty() || first_stage.filter_expr().iter().any(Option::is_some)
+    {
+        return None;
+    }
+
+    let scan = find_scan(first_stage.input())?;
+    let scan_schema = scan.schema;
+    let aggregates = first_stage
+        .aggr_expr()
+        .iter()
+        .map(|expr| {
+            let aggregate = statistics_aggregate(expr.as_ref())?;
+            let column_index = match &aggregate {
+                StatisticsAggregate::CountRows => return Some(aggregate),
+                StatisticsAggregate::CountValid(idx)
+                | StatisticsAggregate::Min(idx)
+                | StatisticsAggregate::Max(idx) => *idx,
+            };
+
+            // Min and max statistics are only available for scalar types. Those of floats skip
+            // NaNs, which DataFusion's MIN and MAX don't.
+            let data_type = scan_schema.field(column_index).data_type();
+            let supports_min_max = matches!(
+                data_type,
+                DataType::Boolean | DataType::Utf8 | DataType::Binary
+            ) || (data_type.is_numeric() && !data_type.is_floating());
+            match aggregate {
+                StatisticsAggregate::Min(_) | StatisticsAggregate::Max(_) if !supports_min_max => {
+                    None
+                }
+                _ => Some(aggregate),
+            }
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let exec =
+        StatisticsAggregateExec::new(scan.partitions, scan.projection, aggregates, plan.schema());
+    Some(Arc::new(match scan.filter {
+        Some(filter) => exec.with_filter(filter),
+        None => exec,
+    }))
+}
+
+/// The chunks read by a Vortex scan, and the filter pushed down into it.
+struct Scan {
+    partitions: Arc<[Vec<Array>]>,
+    projection: Vec<FieldPath>,
+    filter: Option<AggregateFilter>,
+    schema: SchemaRef,
+}
+
+/// Find the Vortex scan feeding an aggregate, looking through operators that only redistribute
+/// batches without changing their contents.
+///
+/// Scans with pushed-down filters take the rows selected by a [RowSelectorExec].
+fn find_scan(plan: &Arc<dyn ExecutionPlan>) -> Option<Scan> {
+    let mut plan = plan;
+    loop {
+        if let Some(scan) = plan.as_any().downcast_ref::<VortexScanExec>() {
+            return Some(Scan {
+                partitions: scan.partitions(),
+                projection: scan.projection().to_vec(),
+                filter: None,
+                schema: scan.schema(),
+            });
+        }
+
+        if let Some(take) = plan.as_any().downcast_ref::<TakeRowsExec>() {
+            let selector = take.input().as_any().downcast_ref::<RowSelectorExec>()?;
+            return Some(Scan {
+                partitions: take.partitions(),
+                projection: take.projection().to_vec(),
+                filter: Some(AggregateFilter {
+                    filter_exprs: selector.filter_exprs().to_vec(),
+                    partitions: selector.filter_partitions(),
+                }),
+                schema: take.schema(),
+            });
+        }
+
+        if let Some(repartition) = plan.as_any().downcast_ref::<RepartitionExec>() {
+            plan = repartition.input();
+        } else if let Some(coalesce) = plan.as_any().downcast_ref::<CoalesceBatchesExec>() {
+            plan = coalesce.input();
+        } else {
+            return None;
+        }
+    }
+}
+
+/// Match an aggregate expression against the aggregates that can be computed from statistics.
+fn statistics_aggregate(expr: &dyn AggregateExpr) -> Option<StatisticsAggregate> {
+    let count_input = if let Some(count) = expr.as_any().downcast_ref::<Count>() {
+        Some(count.expressions())
+    } else if let Some(func) = expr.as_any().downcast_ref::<AggregateFunctionExpr>() {
+        (func.fun().name().eq_ignore_ascii_case("count") && !func.is_distinct())
+            .then(|| func.expressions())
+    } else {
+        None
+    };
+
+    if let Some(inputs) = count_input {
+        let [input] = inputs.as_slice() else {
+            return None;
+        };
+        if let Some(literal) = input.as_any().downcast_ref::<Literal>() {
+            return (literal.value() == &COUNT_STAR_EXPANSION)
+                .then_some(StatisticsAggregate::CountRows);
+        }
+        return column_index(input).map(StatisticsAggregate::CountValid);
+    }
+
+    if let Some(min) = expr.as_any().downcast_ref::<Min>() {
+        let [input] = min.expressions().try_into().ok()?;
+        return column_index(&input).map(StatisticsAggregate::Min);
+    }
+
+    if let Some(max) = expr.as_any().downcast_ref::<Max>() {
+        let [input] = max.expressions().try_into().ok()?;
+        return column_index(&input).map(StatisticsAggregate::Max);
+    }
+
+    None
+}
+
+fn column_index(expr: &Arc<dyn PhysicalExpr>) -> Option<usize> {
+    expr.as_any()
+        .downcast_ref::<Column>()
+        .map(|column| column.index())
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::RecordBatch;
+    use datafusion::execution::context::SessionState;
+    use datafusion::execution::runtime_env::RuntimeEnv;
+    use datafusion::prelude::{SessionConfig, SessionContext};
+    use datafusion_common::ScalarValue;
+    use datafusion_physical_plan::displayable;
+    use vortex::array::chunked::ChunkedArray;
+    use vortex::array::primitive::PrimitiveArray;
+    use vortex::array::struct_::StructArray;
+    use vortex::array::varbin::VarBinArray;
+    use vortex::compress::Compressor;
+    use vortex::validity::Validity;
+    use vortex::{Array, ArrayDType, ArrayDef, Context, IntoArray};
+    use vortex_dict::{Dict, DictEncoding};
+    use vortex_dtype::{DType, Nullability};
+
+    use crate::{AggregatePushdown, VortexMemTable, VortexMemTableOptions};
+
+    fn table() -> Array {
+        let chunk = |ts: Vec<Option<i64>>, names: Vec<&str>| {
+            StructArray::from_fields(&[
+                ("ts", PrimitiveArray::from_nullable_vec(ts).into_array()),
+                (
+                    "name",
+                    VarBinArray::from_vec(names, DType::Utf8(Nullability::NonNullable))
+                        .into_array(),
+                ),
+                (
+                    "id",
+                    PrimitiveArray::from_vec(vec![1u32, 2, 3], Validity::NonNullable).into_array(),
+                ),
+            ])
+            .into_array()
+        };
+        let chunks = vec![
+            chunk(vec![Some(5), None, Some(3)], vec!["c", "d", "e"]),
+            chunk(vec![None, None, None], vec!["f", "a", "b"]),
+            chunk(vec![Some(10), Some(-2), None], vec!["z", "y", "x"]),
+        ];
+        let dtype = chunks[0].dtype().clone();
+        ChunkedArray::try_new(chunks, dtype).unwrap().into_array()
+    }
+
+    fn context(table: Array, pushdown: bool) -> SessionContext {
+        let mut state = SessionState::new_with_config_rt(
+            SessionConfig::default(),
+            Arc::new(RuntimeEnv::default()),
+        );
+        if pushdown {
+            state = state.add_physical_optimizer_rule(Arc::new(AggregatePushdown));
+        }
+        let ctx = SessionContext::new_with_state(state);
+        ctx.register_table(
+            "t",
+            Arc::new(VortexMemTable::try_new(table, VortexMemTableOptions::default()).unwrap()),
+        )
+        .unwrap();
+        ctx
+    }
+
+    async fn run(ctx: &SessionContext, sql: &str) -> (String, Vec<RecordBatch>) {
+        let df = ctx.sql(sql).await.unwrap();
+        let plan = df.clone().create_physical_plan().await.unwrap();
+        let plan_display = displayable(plan.as_ref()).indent(true).to_string();
+        (plan_display, df.collect().await.unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_aggregate_pushdown() {
+        let sql =
+            "SELECT count(*), count(ts), min(ts), max(ts), min(name), max(name), max(id) FROM t";
+
+        let (plan, pushed) = run(&context(table(), true), sql).await;
+        assert!(plan.contains("StatisticsAggregateExec"), "{plan}");
+        assert!(!plan.contains("VortexScanExec"), "{plan}");
+
+        let (plan, expected) = run(&context(table(), false), sql).await;
+        assert!(!plan.contains("StatisticsAggregateExec"), "{plan}");
+
+        assert_eq!(pushed, expected);
+        let values: Vec<ScalarValue> = (0..pushed[0].num_columns())
+            .map(|idx| ScalarValue::try_from_array(pushed[0].column(idx), 0).unwrap())
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                ScalarValue::Int64(Some(9)),
+                ScalarValue::Int64(Some(4)),
+                ScalarValue::Int64(Some(-2)),
+                ScalarValue::Int64(Some(10)),
+                ScalarValue::Utf8(Some("a".to_string())),
+                ScalarValue::Utf8(Some("z".to_string())),
+                ScalarValue::UInt32(Some(3)),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_aggregate_pushdown_long_strings() {
+        let names = |names: Vec<String>| {
+            StructArray::from_fields(&[(
+                "name",
+                VarBinArray::from_vec(names, DType::Utf8(Nullability::NonNullable)).into_array(),
+            )])
+            .into_array()
+        };
+        let chunks = vec![
+            names(vec!["b".repeat(100), "c".repeat(100)]),
+            names(vec!["a".repeat(100), "b".repeat(10)]),
+        ];
+        let dtype = chunks[0].dtype().clone();
+        let table = ChunkedArray::try_new(chunks, dtype).unwrap().into_array();
+
+        // The statistics only hold truncated bounds of the strings.
+        let (plan, pushed) = run(&context(table, true), "SELECT min(name), max(name) FROM t").await;
+        assert!(plan.contains("StatisticsAggregateExec"), "{plan}");
+        let values: Vec<ScalarValue> = (0..pushed[0].num_columns())
+            .map(|idx| ScalarValue::try_from_array(pushed[0].column(idx), 0).unwrap())
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                ScalarValue::Utf8(Some("a".repeat(100))),
+                ScalarValue::Utf8(Some("c".repeat(100))),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_aggregate_pushdown_compressed_long_strings() {
+        let names = |names: [String; 2]| {
+            let names = (0..1000).map(|i| names[i % 2].clone()).collect::<Vec<_>>();
+            StructArray::from_fields(&[(
+                "name",
+                VarBinArray::from_vec(names, DType::Utf8(Nullability::NonNullable)).into_array(),
+            )])
+            .into_array()
+        };
+        let chunks = vec![
+            names(["b".repeat(100), "c".repeat(100)]),
+            names(["a".repeat(100), "b".repeat(10)]),
+        ];
+        let dtype = chunks[0].dtype().clone();
+        let table = ChunkedArray::try_new(chunks, dtype).unwrap().into_array();
+
+        let ctx = Context::default().with_encoding(&DictEncoding);
+        let table = Compressor::new(&ctx).compress(&table, None).unwrap();
+        let chunk = ChunkedArray::try_from(&table).unwrap().chunk(0).unwrap();
+        let field = StructArray::try_from(&chunk).unwrap().field(0).unwrap();
+        assert_eq!(field.encoding().id(), Dict::ID);
+
+        let (plan, pushed) = run(&context(table, true), "SELECT min(name), max(name) FROM t").await;
+        assert!(plan.contains("StatisticsAggregateExec"), "{plan}");
+        let values: Vec<ScalarValue> = (0..pushed[0].num_columns())
+            .map(|idx| ScalarValue::try_from_array(pushed[0].column(idx), 0).unwrap())
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                ScalarValue::Utf8(Some("a".repeat(100))),
+                ScalarValue::Utf8(Some("c".repeat(100))),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_filtered_aggregate_pushdown() {
+        for (sql, expected) in [
+            // The first chunk only holds smaller names and the last one only larger names, so
+            // only the second chunk is filtered row by row.
+            (
+                "SELECT count(*), count(ts), min(ts), max(ts) FROM t WHERE name >= 'f'",
+                vec![
+                    ScalarValue::Int64(Some(4)),
+                    ScalarValue::Int64(Some(2)),
+                    ScalarValue::Int64(Some(-2)),
+                    ScalarValue::Int64(Some(10)),
+                ],
+            ),
+            (
+                "SELECT count(*), min(name), max(ts) FROM t WHERE id > 1 AND ts IS NOT NULL",
+                vec![
+                    ScalarValue::Int64(Some(2)),
+                    ScalarValue::Utf8(Some("e".to_string())),
+                    ScalarValue::Int64(Some(3)),
+                ],
+            ),
+            (
+                "SELECT count(*), max(ts) FROM t WHERE id > 3",
+                vec![ScalarValue::Int64(Some(0)), ScalarValue::Int64(None)],
+            ),
+        ] {
+            let (plan, pushed) = run(&context(table(), true), sql).await;
+            assert!(plan.contains("StatisticsAggregateExec"), "{sql}\n{plan}");
+            assert!(plan.contains("filter_exprs: Some"), "{sql}\n{plan}");
+            assert!(!plan.contains("TakeRowsExec"), "{sql}\n{plan}");
+
+            let (_, expected_batches) = run(&context(table(), false), sql).await;
+            assert_eq!(pushed, expected_batches, "{sql}");
+            let values: Vec<ScalarValue> = (0..pushed[0].num_columns())
+                .map(|idx| ScalarValue::try_from_array(pushed[0].column(idx), 0).unwrap())
+                .collect();
+            assert_eq!(values, expected, "{sql}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_aggregate_not_pushed_down() {
+        let ctx = context(table(), true);
+
+        for sql in [
+            "SELECT name, count(*) FROM t GROUP BY name",
+            "SELECT sum(id) FROM t",
+        ] {
+            let (plan, _) = run(&ctx, sql).await;
+            assert!(!plan.contains("StatisticsAggregateExec"), "{sql}\n{plan}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_float_min_max_not_pushed_down() {
+        let table = StructArray::from_fields(&[(
+            "score",
+            PrimitiveArray::from(vec![1.0f64, f64::NAN, 2.0]).into_array(),
+        )])
+        .into_array();
+
+        // The statistics of floats skip NaNs, while DataFusion's MAX returns them.
+        let (plan, batches) = run(&context(table, true), "SELECT max(score) FROM t").await;
+        assert!(!plan.contains("StatisticsAggregateExec"), "{plan}");
+        let max = ScalarValue::try_from_array(batches[0].column(0), 0).unwrap();
+        assert!(matches!(max, ScalarValue::Float64(Some(max)) if max.is_nan()));
+    }
+}
@@ -0,0 +1,10 @@
+//! Physical optimizer rules that push work from DataFusion plans down into Vortex scans.
+//!
+//! DataFusion does not apply these rules by default; register them on the session state with
+//! [`SessionState::add_physical_optimizer_rule`](datafusion::execution::context::SessionState::add_physical_optimizer_rule).
+
+pub use aggregate::AggregatePushdown;
+pub use projection::NestedProjectionPushdown;
+
+mod aggregate;
+mod projection;
@@ -0,0 +1,286 @@
+use std::iter;
+use std::sync::Arc;
+
+use arrow_schema::{Field as ArrowField, Schema};
+use datafusion::config::ConfigOptions;
+use datafusion::physical_optimizer::PhysicalOptimizerRule;
+use datafusion_common::tree_node::{Transformed, TreeNode, TreeNodeRecursion};
+use datafusion_common::{Result as DFResult, ScalarValue};
+use datafusion_physical_expr::expressions::{Column, Literal};
+use datafusion_physical_expr::{PhysicalExpr, ScalarFunctionExpr};
+use datafusion_physical_plan::projection::ProjectionExec;
+use datafusion_physical_plan::ExecutionPlan;
+use vortex_dtype::field::{Field, FieldPath};
+
+use crate::expr::GET_FIELD;
+use crate::plans::TakeRowsExec;
+use crate::VortexScanExec;
+
+/// Physical optimizer rule that pushes accesses to nested struct fields into Vortex scans.
+///
+/// DataFusion only pushes projections of top-level columns into a table scan, so a query such
+/// as `SELECT a.b.c FROM t` would decode the whole of column `a`. This rule rewrites projections
+/// over a Vortex scan so that the scan emits the nested field `a.b.c` directly, without decoding
+/// any of its siblings.
+#[derive(Debug, Default)]
+pub struct NestedProjectionPushdown;
+
+impl PhysicalOptimizerRule for NestedProjectionPushdown {
+    fn optimize(
+        &self,
+        plan: Arc<dyn ExecutionPlan>,
+        _config: &ConfigOptions,
+    ) -> DFResult<Arc<dyn ExecutionPlan>> {
+        plan.transform_up(|plan| {
+            let Some(projection) = plan.as_any().downcast_ref::<ProjectionExec>() else {
+                return Ok(Transformed::no(plan));
+            };
+
+            Ok(match push_nested_projection(projection)? {
+                Some(new_plan) => Transformed::yes(new_plan),
+                None => Transformed::no(plan),
+            })
+        })
+        .map(|transformed| transformed.data)
+    }
+
+    fn name(&self) -> &str {
+        "vortex_nested_projection_pushdown"
+    }
+
+    fn schema_check(&self) -> bool {
+        true
+    }
+}
+
+/// Rewrite a projection over a Vortex scan such that every nested field access becomes a plain
+/// column produced by the scan itself.
+///
+/// Returns `None` if the input is not a Vortex scan, or if the projection does not access any
+/// nested fields.
+fn push_nested_projection(projection: &ProjectionExec) -> DFResult<Option<Arc<dyn ExecutionPlan>>> {
+    let input = projection.input();
+    let input_paths = if let Some(scan) = input.as_any().downcast_ref::<VortexScanExec>() {
+        scan.projection()
+    } else if let Some(take) = input.as_any().downcast_ref::<TakeRowsExec>() {
+        take.projection()
+    } else {
+        return Ok(None);
+    };
+    let input_schema = input.schema();
+
+    let mut scan_paths: Vec<FieldPath> = Vec::new();
+    let mut scan_fields: Vec<ArrowField> = Vec::new();
+    let mut any_nested = false;
+
+    let mut exprs = Vec::with_capacity(projection.expr().len());
+    for (expr, alias) in projection.expr() {
+        let rewritten = expr.clone().transform_down(|node| {
+            let Some((path, nested)) = physical_field_path(&node, input_paths) else {
+                return Ok(Transformed::no(node));
+            };
+            any_nested |= nested;
+
+            let index = match scan_paths.iter().position(|p| p == &path) {
+                Some(index) => index,
+                None => {
+                    scan_fields.push(ArrowField::new(
+                        path.to_string(),
+                        node.data_type(input_schema.as_ref())?,
+                        node.nullable(input_schema.as_ref())?,
+                    ));
+                    scan_paths.push(path);
+                    scan_paths.len() - 1
+                }
+            };
+
+            let column: Arc<dyn PhysicalExpr> =
+                Arc::new(Column::new(scan_fields[index].name(), index));
+            Ok(Transformed::new(column, true, TreeNodeRecursion::Jump))
+        })?;
+
+        exprs.push((rewritten.data, alias.clone()));
+    }
+
+    if !any_nested {
+        return Ok(None);
+    }
+
+    let scan_schema = Arc::new(Schema::new(scan_fields));
+    let new_input: Arc<dyn ExecutionPlan> =
+        if let Some(scan) = input.as_any().downcast_ref::<VortexScanExec>() {
+            Arc::new(scan.with_projection(scan_paths, scan_schema))
+        } else if let Some(take) = input.as_any().downcast_ref::<TakeRowsExec>() {
+            Arc::new(take.with_projection(scan_paths, scan_schema))
+        } else {
+            unreachable!("input is a Vortex scan")
+        };
+
+    Ok(Some(Arc::new(ProjectionExec::try_new(exprs, new_input)?)))
+}
+
+/// Resolve a physical expression that references a (possibly nested) column of the scan into the
+/// [FieldPath] of the referenced field, along with whether the access is nested.
+fn physical_field_path(
+    expr: &Arc<dyn PhysicalExpr>,
+    input_paths: &[FieldPath],
+) -> Option<(FieldPath, bool)> {
+    if let Some(column) = expr.as_any().downcast_ref::<Column>() {
+        return input_paths
+            .get(column.index())
+            .map(|path| (path.clone(), false));
+    }
+
+    let func = expr.as_any().downcast_ref::<ScalarFunctionExpr>()?;
+    if func.name() != GET_FIELD {
+        return None;
+    }
+
+    let [base, name] = func.args() else {
+        return None;
+    };
+    let ScalarValue::Utf8(Some(name)) = name.as_any().downcast_ref::<Literal>()?.value() else {
+        return None;
+    };
+
+    let (path, _) = physical_field_path(base, input_paths)?;
+    Some((
+        path.path()
+            .iter()
+            .cloned()
+            .chain(iter::once(Field::from(name.as_str())))
+            .collect(),
+        true,
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int64Type;
+    use datafusion::execution::context::SessionState;
+    use datafusion::execution::runtime_env::RuntimeEnv;
+    use datafusion::functions::core::expr_ext::FieldAccessor;
+    use datafusion::prelude::{SessionConfig, SessionContext};
+    use datafusion_expr::{col, lit};
+    use datafusion_physical_plan::displayable;
+    use vortex::array::primitive::PrimitiveArray;
+    use vortex::array::struct_::StructArray;
+    use vortex::array::varbin::VarBinArray;
+    use vortex::validity::Validity;
+    use vortex::IntoArray;
+    use vortex_dtype::{DType, Nullability};
+
+    use crate::{NestedProjectionPushdown, VortexMemTable, VortexMemTableOptions};
+
+    fn nested_context() -> SessionContext {
+        let b = StructArray::from_fields(&[
+            (
+                "c",
+                PrimitiveArray::from_vec(vec![10i64, 20, 30, 40], Validity::NonNullable)
+                    .into_array(),
+            ),
+            (
+                "d",
+                VarBinArray::from_vec(
+                    vec!["w", "x", "y", "z"],
+                    DType::Utf8(Nullability::NonNullable),
+                )
+                .into_array(),
+            ),
+        ]);
+        let a = StructArray::from_fields(&[
+            ("b", b.into_array()),
+            (
+                "e",
+                PrimitiveArray::from_vec(vec![1i32, 4, 2, 8], Validity::NonNullable).into_array(),
+            ),
+        ]);
+        let table = StructArray::from_fields(&[("a", a.into_array())]).into_array();
+
+        let state = SessionState::new_with_config_rt(
+            SessionConfig::default(),
+            Arc::new(RuntimeEnv::default()),
+        )
+        .add_physical_optimizer_rule(Arc::new(NestedProjectionPushdown));
+        let ctx = SessionContext::new_with_state(state);
+        ctx.register_table(
+            "t",
+            Arc::new(VortexMemTable::try_new(table, VortexMemTableOptions::default()).unwrap()),
+        )
+        .unwrap();
+        ctx
+    }
+
+    #[tokio::test]
+    async fn test_nested_projection() {
+        let ctx = nested_context();
+
+        let df = ctx
+            .table("t")
+            .await
+            .unwrap()
+            .select(vec![col("a").field("b").field("c")])
+            .unwrap();
+        let plan = df.clone().create_physical_plan().await.unwrap();
+        let plan_display = displayable(plan.as_ref()).indent(true).to_string();
+        assert!(
+            plan_display
+                .contains("scan_projection: [FieldPath([Name(\"a\"), Name(\"b\"), Name(\"c\")])]"),
+            "{plan_display}"
+        );
+
+        let batches = df.collect().await.unwrap();
+        let values: Vec<i64> = batches
+            .iter()
+            .flat_map(|batch| {
+                batch
+                    .column(0)
+                    .as_primitive::<Int64Type>()
+                    .values()
+                    .to_vec()
+            })
+            .collect();
+        assert_eq!(values, vec![10, 20, 30, 40]);
+    }
+
+    #[tokio::test]
+    async fn test_nested_filter() {
+        let ctx = nested_context();
+
+        let df = ctx
+            .table("t")
+            .await
+            .unwrap()
+            .filter(col("a").field("e").gt(lit(3)))
+            .unwrap()
+            .select(vec![col("a").field("b").field("c")])
+            .unwrap();
+
+        // The filter is pushed into the scan, and the projection into the take.
+        let plan = df.clone().create_physical_plan().await.unwrap();
+        let plan_display = displayable(plan.as_ref()).indent(true).to_string();
+        assert!(plan_display.contains("RowSelectorExec"), "{plan_display}");
+        assert!(
+            plan_display.contains(
+                "TakeRowsExec { projection: [FieldPath([Name(\"a\"), Name(\"b\"), Name(\"c\")])]"
+            ),
+            "{plan_display}"
+        );
+
+        let batches = df.collect().await.unwrap();
+        let values: Vec<i64> = batches
+            .iter()
+            .flat_map(|batch| {
+                batch
+                    .column(0)
+                    .as_primitive::<Int64Type>()
+                    .values()
+                    .to_vec()
+            })
+            .collect();
+        assert_eq!(values, vec![20, 40]);
+    }
+}
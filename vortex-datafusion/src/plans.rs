@@ -1,46 +1,64 @@
 //! Physical operators needed to implement scanning of Vortex arrays with pushdown.
 
 use std::any::Any;
+use std::cmp::Ordering;
 use std::fmt::{Debug, Formatter};
-use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll};
 
 use arrow_array::cast::AsArray;
 use arrow_array::types::UInt64Type;
-use arrow_array::{ArrayRef, RecordBatch, RecordBatchOptions, UInt64Array};
+use arrow_array::{Array as ArrowArray, ArrayRef, RecordBatch, RecordBatchOptions, UInt64Array};
 use arrow_schema::{DataType, Field, Schema, SchemaRef};
-use datafusion_common::{DFSchema, Result as DFResult};
+use datafusion_common::{exec_datafusion_err, DFSchema, Result as DFResult, ScalarValue};
 use datafusion_execution::{RecordBatchStream, SendableRecordBatchStream, TaskContext};
 use datafusion_expr::Expr;
 use datafusion_physical_expr::{create_physical_expr, EquivalenceProperties, Partitioning};
+use datafusion_physical_plan::stream::RecordBatchStreamAdapter;
 use datafusion_physical_plan::{
-    DisplayAs, DisplayFormatType, ExecutionMode, ExecutionPlan, PlanProperties,
+    DisplayAs, DisplayFormatType, EmptyRecordBatchStream, ExecutionMode, ExecutionPlan,
+    PlanProperties,
 };
 use futures::{ready, Stream};
+use itertools::Itertools;
 use lazy_static::lazy_static;
 use pin_project::pin_project;
+use vortex::accessor::ArrayAccessor;
+use vortex::array::primitive::PrimitiveArray;
 use vortex::array::struct_::StructArray;
-use vortex::arrow::FromArrowArray;
+use vortex::array::varbin::varbin_scalar;
+use vortex::arrow::infer_schema;
+use vortex::arrow::TryFromArrowArray;
 use vortex::compute::take::take;
-use vortex::{Array, ArrayDType, ArrayData, IntoArray, IntoCanonical};
+use vortex::stats::{can_skip, ArrayStatistics, Stat};
+use vortex::validity::{LogicalValidity, Validity};
+use vortex::{
+    Array, ArrayDType, ArrayData, ArrayTrait, IntoArray, IntoArrayVariant, IntoCanonical,
+};
+use vortex_dtype::field::FieldPath;
+use vortex_dtype::DType;
+use vortex_error::{vortex_err, VortexResult};
+use vortex_expr::{Operator, Predicate, Value};
+use vortex_scalar::Scalar;
 
-use crate::datatype::infer_schema;
-use crate::expr::{make_conjunction, simplify_expr};
+use crate::expr::{comparison_predicates, make_conjunction, simplify_expr};
 
 /// Physical plan operator that applies a set of [filters][Expr] against the input, producing a
 /// row mask that can be used downstream to force a take against the corresponding struct array
 /// chunks but for different columns.
+///
+/// Each partition emits exactly one batch of chunk-relative row indices per chunk it owns, in
+/// chunk order.
 pub(crate) struct RowSelectorExec {
     filter_exprs: Vec<Expr>,
 
     // cached PlanProperties object. We do not make use of this.
     cached_plan_props: PlanProperties,
 
-    // A Vortex struct array that contains all columns necessary for executing the filter
-    // expressions.
-    filter_struct: StructArray,
+    // For each partition, the Vortex struct array chunks that contain all columns necessary for
+    // executing the filter expressions.
+    filter_partitions: Arc<[Vec<StructArray>]>,
 }
 
 lazy_static! {
@@ -52,25 +70,36 @@ lazy_static! {
 }
 
 impl RowSelectorExec {
-    pub(crate) fn new(filter_exprs: &[Expr], filter_struct: &StructArray) -> Self {
+    pub(crate) fn new(filter_exprs: &[Expr], filter_partitions: Vec<Vec<StructArray>>) -> Self {
         let cached_plan_props = PlanProperties::new(
             EquivalenceProperties::new(ROW_SELECTOR_SCHEMA_REF.clone()),
-            Partitioning::RoundRobinBatch(1),
+            Partitioning::UnknownPartitioning(filter_partitions.len()),
             ExecutionMode::Bounded,
         );
 
         Self {
             filter_exprs: filter_exprs.to_owned(),
-            filter_struct: filter_struct.clone(),
+            filter_partitions: filter_partitions.into(),
             cached_plan_props,
         }
     }
 }
 
+impl RowSelectorExec {
+    pub(crate) fn filter_exprs(&self) -> &[Expr] {
+        self.filter_exprs.as_slice()
+    }
+
+    pub(crate) fn filter_partitions(&self) -> Arc<[Vec<StructArray>]> {
+        self.filter_partitions.clone()
+    }
+}
+
 impl Debug for RowSelectorExec {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("RowSelectorExec")
             .field("filter_exprs", &self.filter_exprs)
+            .field("partitions", &self.filter_partitions.len())
             .finish()
     }
 }
@@ -109,75 +138,95 @@ impl ExecutionPlan for RowSelectorExec {
     fn execute(
         &self,
         partition: usize,
-        context: Arc<TaskContext>,
+        _context: Arc<TaskContext>,
     ) -> DFResult<SendableRecordBatchStream> {
-        assert_eq!(
-            partition, 0,
-            "single partitioning only supported by TakeOperator"
-        );
-
-        let stream_schema = Arc::new(infer_schema(self.filter_struct.dtype()));
-
-        let filter_struct = self.filter_struct.clone();
-        let one_shot = Box::pin(async move { filter_struct.into_array() });
-
-        let conjunction_expr = simplify_expr(
-            &make_conjunction(&self.filter_exprs)?,
-            stream_schema.clone(),
-        )?;
+        let chunks = self
+            .filter_partitions
+            .get(partition)
+            .ok_or_else(|| exec_datafusion_err!("partition not found"))?
+            .clone();
+
+        // All chunks share the same dtype, but a partition may own no chunks at all.
+        let filter = match chunks.first() {
+            Some(chunk) => ChunkFilter::try_new(&self.filter_exprs, chunk.dtype())?,
+            None => {
+                return Ok(Box::pin(EmptyRecordBatchStream::new(
+                    ROW_SELECTOR_SCHEMA_REF.clone(),
+                )))
+            }
+        };
 
-        Ok(Box::pin(RowIndicesStream {
-            one_shot,
-            polled_inner: false,
-            conjunction_expr,
-            schema_ref: stream_schema,
-            context: context.clone(),
-        }))
+        Ok(Box::pin(RowIndicesStream::new(chunks, filter)))
     }
 }
 
-/// [RecordBatchStream] of row indices, emitted by the [RowSelectorExec] physical plan node.
-#[pin_project::pin_project]
-pub(crate) struct RowIndicesStream<F> {
-    /// The inner future that returns `DFResult<RecordBatch>`.
-    /// This future should only poll one time.
-    #[pin]
-    one_shot: F,
+/// The rows of a chunk selected by a [ChunkFilter].
+pub(crate) enum RowSelection {
+    /// None of the rows.
+    None,
+    /// All of the rows.
+    All,
+    /// The rows at the given indices.
+    Indices(Vec<u64>),
+}
 
-    polled_inner: bool,
+/// What the statistics of a chunk prove about the rows selected by a filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StatisticsSelection {
+    None,
+    All,
+    Unknown,
+}
 
+/// A conjunction of filter expressions, evaluated against the struct chunks holding the fields it
+/// references.
+pub(crate) struct ChunkFilter {
+    /// The part of the filter that can be checked against the statistics of a chunk, to select
+    /// none or all of its rows without decoding it.
+    predicates: Vec<Predicate>,
+    /// Whether `predicates` make up the entire filter.
+    complete: bool,
     conjunction_expr: Expr,
     schema_ref: SchemaRef,
-    context: Arc<TaskContext>,
 }
 
-impl<F> Stream for RowIndicesStream<F>
-where
-    F: Future<Output = Array>,
-{
-    type Item = DFResult<RecordBatch>;
+impl ChunkFilter {
+    fn new(conjunction_expr: Expr, schema_ref: SchemaRef) -> Self {
+        let (predicates, complete) = comparison_predicates(&conjunction_expr);
+        Self {
+            predicates,
+            complete,
+            conjunction_expr,
+            schema_ref,
+        }
+    }
 
-    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        let this = self.project();
+    /// Create the filter for chunks of the given struct dtype.
+    pub(crate) fn try_new(filter_exprs: &[Expr], dtype: &DType) -> DFResult<Self> {
+        let schema_ref = Arc::new(
+            infer_schema(dtype).map_err(|vortex_err| exec_datafusion_err!("{vortex_err}"))?,
+        );
+        let conjunction_expr = simplify_expr(&make_conjunction(filter_exprs)?, schema_ref.clone())?;
+        Ok(Self::new(conjunction_expr, schema_ref))
+    }
 
-        // If we have already polled the one-shot future of filter records, indicate
-        // that the stream has finished.
-        if *this.polled_inner {
-            return Poll::Ready(None);
+    /// Select the rows of a chunk, only decoding it if its statistics don't decide the filter.
+    pub(crate) fn select(&self, vortex_struct: StructArray) -> DFResult<RowSelection> {
+        match self
+            .select_from_statistics(&vortex_struct)
+            .map_err(|vortex_err| exec_datafusion_err!("{vortex_err}"))?
+        {
+            StatisticsSelection::None => return Ok(RowSelection::None),
+            StatisticsSelection::All => return Ok(RowSelection::All),
+            StatisticsSelection::Unknown => {}
         }
 
-        // Get the unfiltered record batch.
-        // Since this is a one-shot, we only want to poll the inner future once, to create the
-        // initial batch for us to process.
-        let vortex_struct = ready!(this.one_shot.poll(cx));
-        *this.polled_inner = true;
-
         // Immediately convert to Arrow RecordBatch for processing.
         // TODO(aduffy): attempt to pushdown the filter to Vortex without decoding.
         let record_batch = RecordBatch::from(
             vortex_struct
                 .into_canonical()
-                .unwrap()
+                .map_err(|vortex_err| exec_datafusion_err!("{vortex_err}"))?
                 .into_arrow()
                 .as_struct(),
         );
@@ -186,77 +235,269 @@ where
         //
         // The result of a conjunction expression is a BooleanArray containing `true` for rows
         // where the conjunction was satisfied, and `false` otherwise.
-        let df_schema = DFSchema::try_from(this.schema_ref.clone())?;
+        let df_schema = DFSchema::try_from(self.schema_ref.clone())?;
         let physical_expr =
-            create_physical_expr(this.conjunction_expr, &df_schema, &Default::default())?;
+            create_physical_expr(&self.conjunction_expr, &df_schema, &Default::default())?;
         let selection = physical_expr
             .evaluate(&record_batch)?
             .into_array(record_batch.num_rows())?;
 
-        // Convert the `selection` BooleanArray into a UInt64Array of indices.
-        let selection_indices: Vec<u64> = selection
-            .as_boolean()
-            .clone()
-            .values()
-            .set_indices()
-            .map(|idx| idx as u64)
-            .collect();
+        // Convert the `selection` BooleanArray into indices. Rows for which the conjunction is
+        // null, e.g. comparisons of null fields, are not selected.
+        let selection = selection.as_boolean();
+        let selected = match selection.nulls() {
+            Some(nulls) => selection.values() & nulls.inner(),
+            None => selection.values().clone(),
+        };
+        Ok(RowSelection::Indices(
+            selected.set_indices().map(|idx| idx as u64).collect(),
+        ))
+    }
+
+    fn select_from_statistics(&self, chunk: &StructArray) -> VortexResult<StatisticsSelection> {
+        let mut selection = if self.complete {
+            StatisticsSelection::All
+        } else {
+            StatisticsSelection::Unknown
+        };
+        for predicate in &self.predicates {
+            match predicate_selection(chunk, predicate)? {
+                StatisticsSelection::None => return Ok(StatisticsSelection::None),
+                StatisticsSelection::All => {}
+                StatisticsSelection::Unknown => selection = StatisticsSelection::Unknown,
+            }
+        }
+        Ok(selection)
+    }
+}
+
+/// What the statistics of the field compared by a predicate prove about the rows of a chunk that
+/// satisfy it.
+///
+/// Truncated string bounds still bound the values from below and above, so they only prevent
+/// proving that all values equal the literal.
+fn predicate_selection(
+    chunk: &StructArray,
+    predicate: &Predicate,
+) -> VortexResult<StatisticsSelection> {
+    if can_skip(chunk, predicate)? {
+        return Ok(StatisticsSelection::None);
+    }
+    let Value::Literal(value) = &predicate.rhs else {
+        return Ok(StatisticsSelection::Unknown);
+    };
+
+    let (field, validity) = chunk.field_and_validity_by_path(&predicate.lhs)?;
+    let null_count = if field.dtype().is_nullable() {
+        field.statistics().compute_as::<usize>(Stat::NullCount)
+    } else {
+        Some(0)
+    };
+    // Null values never satisfy a comparison.
+    if null_count == Some(field.len()) || validity.to_logical(field.len()).all_invalid() {
+        return Ok(StatisticsSelection::None);
+    }
+    // NaNs compare greater than any other float, but are not reflected in min/max statistics.
+    if field.dtype().is_float() {
+        return Ok(StatisticsSelection::Unknown);
+    }
+
+    let (Some(min), Some(max)) = (
+        field.statistics().compute_bound(Stat::Min),
+        field.statistics().compute_bound(Stat::Max),
+    ) else {
+        return Ok(StatisticsSelection::Unknown);
+    };
+    let (Some(min_cmp), Some(max_cmp)) = (
+        compare_literal(value, &min.value),
+        compare_literal(value, &max.value),
+    ) else {
+        return Ok(StatisticsSelection::Unknown);
+    };
+    let exact = !min.is_truncated && !max.is_truncated;
+    let all_equal = exact && min_cmp == Ordering::Equal && max_cmp == Ordering::Equal;
+    let outside = min_cmp == Ordering::Less || max_cmp == Ordering::Greater;
+
+    let none = match predicate.op {
+        Operator::Eq => outside,
+        Operator::NotEq => all_equal,
+        Operator::Lt => min_cmp != Ordering::Greater,
+        Operator::Lte => min_cmp == Ordering::Less,
+        Operator::Gt => max_cmp != Ordering::Less,
+        Operator::Gte => max_cmp == Ordering::Greater,
+    };
+    if none {
+        return Ok(StatisticsSelection::None);
+    }
+
+    let all = match predicate.op {
+        Operator::Eq => all_equal,
+        Operator::NotEq => outside,
+        Operator::Lt => max_cmp == Ordering::Greater,
+        Operator::Lte => max_cmp != Ordering::Less,
+        Operator::Gt => min_cmp == Ordering::Less,
+        Operator::Gte => min_cmp != Ordering::Greater,
+    };
+    let no_nulls = null_count == Some(0) && validity.to_logical(field.len()).all_valid();
+    Ok(if all && no_nulls {
+        StatisticsSelection::All
+    } else {
+        StatisticsSelection::Unknown
+    })
+}
+
+/// Order a literal relative to a (non-null) statistic of a field with the same type.
+fn compare_literal(value: &Scalar, statistic: &Scalar) -> Option<Ordering> {
+    if statistic.is_null() || !value.dtype().eq_ignore_nullability(statistic.dtype()) {
+        return None;
+    }
+    match (value.value(), statistic.value()) {
+        // Statistics may be stored with a different width than the values.
+        (
+            vortex_scalar::ScalarValue::Primitive(value),
+            vortex_scalar::ScalarValue::Primitive(statistic),
+        ) => {
+            if let (Ok(value), Ok(statistic)) = (i64::try_from(*value), i64::try_from(*statistic)) {
+                Some(value.cmp(&statistic))
+            } else if let (Ok(value), Ok(statistic)) =
+                (u64::try_from(*value), u64::try_from(*statistic))
+            {
+                Some(value.cmp(&statistic))
+            } else {
+                None
+            }
+        }
+        (value, statistic) => value.partial_cmp(statistic),
+    }
+}
+
+/// [RecordBatchStream] of row indices, emitted by the [RowSelectorExec] physical plan node.
+///
+/// Every chunk is filtered lazily when the stream is polled, yielding the indices of the
+/// selected rows relative to the start of that chunk.
+pub(crate) struct RowIndicesStream {
+    /// The remaining struct chunks to evaluate the filter against.
+    chunks: std::vec::IntoIter<StructArray>,
+    filter: ChunkFilter,
+}
+
+impl RowIndicesStream {
+    fn new(chunks: Vec<StructArray>, filter: ChunkFilter) -> Self {
+        Self {
+            chunks: chunks.into_iter(),
+            filter,
+        }
+    }
+
+    fn filter_chunk(&self, vortex_struct: StructArray) -> DFResult<RecordBatch> {
+        let len = vortex_struct.len() as u64;
+        let selection_indices = match self.filter.select(vortex_struct)? {
+            RowSelection::None => {
+                return Ok(RecordBatch::new_empty(ROW_SELECTOR_SCHEMA_REF.clone()))
+            }
+            RowSelection::All => (0..len).collect(),
+            RowSelection::Indices(indices) => indices,
+        };
 
         let indices: ArrayRef = Arc::new(UInt64Array::from(selection_indices));
-        let indices_batch = RecordBatch::try_new(ROW_SELECTOR_SCHEMA_REF.clone(), vec![indices])?;
+        Ok(RecordBatch::try_new(
+            ROW_SELECTOR_SCHEMA_REF.clone(),
+            vec![indices],
+        )?)
+    }
+}
+
+impl Stream for RowIndicesStream {
+    type Item = DFResult<RecordBatch>;
+
+    fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let Some(vortex_struct) = self.chunks.next() else {
+            return Poll::Ready(None);
+        };
+
+        Poll::Ready(Some(self.filter_chunk(vortex_struct)))
+    }
 
-        Poll::Ready(Some(Ok(indices_batch)))
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
     }
 }
 
-impl<F> RecordBatchStream for RowIndicesStream<F>
-where
-    F: Future<Output = Array>,
-{
+impl RecordBatchStream for RowIndicesStream {
     fn schema(&self) -> SchemaRef {
-        self.schema_ref.clone()
+        ROW_SELECTOR_SCHEMA_REF.clone()
     }
 }
 
 /// Physical that receives a stream of row indices from a child operator, and uses that to perform
 /// a `take` operation on tha backing Vortex array.
+///
+/// The input must be partitioned identically to this operator, and emit one batch of indices per
+/// chunk, such that the n-th batch of a partition selects rows from the n-th chunk of the same
+/// partition.
 pub(crate) struct TakeRowsExec {
     plan_properties: PlanProperties,
 
-    // Array storing the indices used to take the plan nodes.
-    projection: Vec<usize>,
+    // The (possibly nested) fields to take from the chunks, one per output column.
+    projection: Vec<FieldPath>,
 
     // Input plan, a stream of indices on which we perform a take against the original dataset.
     input: Arc<dyn ExecutionPlan>,
 
     output_schema: SchemaRef,
 
-    // The original Vortex array holding the fields we have not decoded yet.
-    table: StructArray,
+    // For each partition, the chunks of the original Vortex array holding the fields we have not
+    // decoded yet.
+    partitions: Arc<[Vec<Array>]>,
 }
 
 impl TakeRowsExec {
     pub(crate) fn new(
-        schema_ref: SchemaRef,
-        projection: &[usize],
+        output_schema: SchemaRef,
+        projection: Vec<FieldPath>,
         row_indices: Arc<dyn ExecutionPlan>,
-        table: &StructArray,
+        partitions: Arc<[Vec<Array>]>,
     ) -> Self {
-        let output_schema = Arc::new(schema_ref.project(projection).unwrap());
         let plan_properties = PlanProperties::new(
             EquivalenceProperties::new(output_schema.clone()),
-            Partitioning::RoundRobinBatch(1),
+            Partitioning::UnknownPartitioning(partitions.len()),
             ExecutionMode::Bounded,
         );
 
         Self {
             plan_properties,
-            projection: projection.to_owned(),
+            projection,
             input: row_indices,
-            output_schema: output_schema.clone(),
-            table: table.clone(),
+            output_schema,
+            partitions,
         }
     }
+
+    pub(crate) fn projection(&self) -> &[FieldPath] {
+        self.projection.as_slice()
+    }
+
+    pub(crate) fn input(&self) -> &Arc<dyn ExecutionPlan> {
+        &self.input
+    }
+
+    pub(crate) fn partitions(&self) -> Arc<[Vec<Array>]> {
+        self.partitions.clone()
+    }
+
+    /// Create a copy of this operator that takes the given fields instead.
+    pub(crate) fn with_projection(
+        &self,
+        projection: Vec<FieldPath>,
+        output_schema: SchemaRef,
+    ) -> Self {
+        Self::new(
+            output_schema,
+            projection,
+            self.input.clone(),
+            self.partitions.clone(),
+        )
+    }
 }
 
 impl Debug for TakeRowsExec {
@@ -264,6 +505,7 @@ impl Debug for TakeRowsExec {
         f.debug_struct("TakeRowsExec")
             .field("projection", &self.projection)
             .field("output_schema", &self.output_schema)
+            .field("partitions", &self.partitions.len())
             .finish()
     }
 }
@@ -299,19 +541,19 @@ impl ExecutionPlan for TakeRowsExec {
         partition: usize,
         context: Arc<TaskContext>,
     ) -> DFResult<SendableRecordBatchStream> {
-        assert_eq!(
-            partition, 0,
-            "single partitioning only supported by TakeOperator"
-        );
+        let chunks = self
+            .partitions
+            .get(partition)
+            .ok_or_else(|| exec_datafusion_err!("partition not found"))?
+            .clone();
 
         let row_indices_stream = self.input.execute(partition, context)?;
 
         Ok(Box::pin(TakeRowsStream {
             row_indices_stream,
-            completed: false,
+            chunks: chunks.into_iter(),
             output_projection: self.projection.clone(),
             output_schema: self.output_schema.clone(),
-            vortex_array: self.table.clone(),
         }))
     }
 }
@@ -323,14 +565,12 @@ pub(crate) struct TakeRowsStream<F> {
     #[pin]
     row_indices_stream: F,
 
-    completed: bool,
+    // The remaining chunks of the original Vortex array we're taking from.
+    chunks: std::vec::IntoIter<Array>,
 
     // Projection based on the schema here
-    output_projection: Vec<usize>,
+    output_projection: Vec<FieldPath>,
     output_schema: SchemaRef,
-
-    // The original Vortex array we're taking from
-    vortex_array: StructArray,
 }
 
 impl<F> Stream for TakeRowsStream<F>
@@ -342,54 +582,36 @@ where
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let this = self.project();
 
-        // If `poll_next` has already fired, return None indicating end of the stream.
-        if *this.completed {
-            return Poll::Ready(None);
-        }
-
         // Get the indices provided by the upstream operator.
         let record_batch = match ready!(this.row_indices_stream.poll_next(cx)) {
             None => {
                 // Row indices stream is complete, we are also complete.
-                // This should never happen right now given we only emit one recordbatch upstream.
                 return Poll::Ready(None);
             }
-            Some(result) => {
-                *this.completed = true;
-                result?
-            }
+            Some(result) => result?,
+        };
+
+        // Every batch of indices selects from the next chunk of this partition.
+        let Some(chunk) = this.chunks.next() else {
+            return Poll::Ready(Some(Err(exec_datafusion_err!(
+                "received more row selections than chunks in partition"
+            ))));
         };
 
         let row_indices =
-            ArrayData::from_arrow(record_batch.column(0).as_primitive::<UInt64Type>(), false)
+            ArrayData::try_from_arrow(record_batch.column(0).as_primitive::<UInt64Type>(), false)
+                .map_err(|vortex_err| exec_datafusion_err!("{vortex_err}"))?
                 .into_array();
 
-        // If no columns in the output projection, we send back a RecordBatch with empty schema.
-        // This is common for COUNT queries.
-        if this.output_projection.is_empty() {
-            let opts = RecordBatchOptions::new().with_row_count(Some(row_indices.len()));
-            return Poll::Ready(Some(Ok(RecordBatch::try_new_with_options(
-                Arc::new(Schema::empty()),
-                vec![],
-                &opts,
-            )
-            .unwrap())));
-        }
-
         // TODO(aduffy): this re-decodes the fields from the filter schema, which is wasteful.
         //  We should find a way to avoid decoding the filter columns and only decode the other
         //  columns, then stitch the StructArray back together from those.
-        let projected_for_output = this.vortex_array.project(this.output_projection).unwrap();
-        let decoded = take(&projected_for_output.into_array(), &row_indices)
-            .expect("take")
-            .into_canonical()
-            .expect("into_canonical")
-            .into_arrow();
-
-        // Send back a single record batch of the decoded data.
-        let output_batch = RecordBatch::from(decoded.as_struct());
-
-        Poll::Ready(Some(Ok(output_batch)))
+        Poll::Ready(Some(decode_fields(
+            chunk,
+            this.output_projection,
+            this.output_schema,
+            Some(&row_indices),
+        )))
     }
 }
 
@@ -402,22 +624,459 @@ where
     }
 }
 
+/// An aggregate that can be computed from the statistics of an array, without decoding it.
+///
+/// Columns are referenced by their index into the projection of the scanned fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum StatisticsAggregate {
+    /// `count(*)`, the total number of rows.
+    CountRows,
+    /// `count(col)`, the number of non-null values of a column.
+    CountValid(usize),
+    /// `min(col)`
+    Min(usize),
+    /// `max(col)`
+    Max(usize),
+}
+
+/// Filter expressions restricting the rows aggregated by a [StatisticsAggregateExec].
+#[derive(Debug, Clone)]
+pub(crate) struct AggregateFilter {
+    pub(crate) filter_exprs: Vec<Expr>,
+    /// The chunks holding the fields referenced by the filter, partitioned like the aggregated
+    /// chunks.
+    pub(crate) partitions: Arc<[Vec<StructArray>]>,
+}
+
+/// Physical plan operator that computes a set of ungrouped [aggregates][StatisticsAggregate] over
+/// the chunks of a Vortex array from their statistics.
+///
+/// Statistics are computed lazily when the operator is executed, reusing any statistics already
+/// stored with the chunks. Only fields whose statistics cannot be computed by their encoding are
+/// canonicalized.
+///
+/// With a [filter][AggregateFilter], chunks whose statistics prove that none or all of their rows
+/// are selected are skipped or aggregated as a whole. Only the selected rows of the remaining
+/// chunks are taken and aggregated.
+pub(crate) struct StatisticsAggregateExec {
+    partitions: Arc<[Vec<Array>]>,
+    projection: Vec<FieldPath>,
+    aggregates: Vec<StatisticsAggregate>,
+    filter: Option<AggregateFilter>,
+    plan_properties: PlanProperties,
+}
+
+impl StatisticsAggregateExec {
+    pub(crate) fn new(
+        partitions: Arc<[Vec<Array>]>,
+        projection: Vec<FieldPath>,
+        aggregates: Vec<StatisticsAggregate>,
+        output_schema: SchemaRef,
+    ) -> Self {
+        let plan_properties = PlanProperties::new(
+            EquivalenceProperties::new(output_schema),
+            Partitioning::UnknownPartitioning(1),
+            ExecutionMode::Bounded,
+        );
+
+        Self {
+            partitions,
+            projection,
+            aggregates,
+            filter: None,
+            plan_properties,
+        }
+    }
+
+    /// Only aggregate the rows selected by the given filter.
+    pub(crate) fn with_filter(mut self, filter: AggregateFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+}
+
+impl Debug for StatisticsAggregateExec {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StatisticsAggregateExec")
+            .field("projection", &self.projection)
+            .field("aggregates", &self.aggregates)
+            .field(
+                "filter_exprs",
+                &self.filter.as_ref().map(|filter| &filter.filter_exprs),
+            )
+            .finish()
+    }
+}
+
+impl DisplayAs for StatisticsAggregateExec {
+    fn fmt_as(&self, _display_type: DisplayFormatType, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl ExecutionPlan for StatisticsAggregateExec {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn properties(&self) -> &PlanProperties {
+        &self.plan_properties
+    }
+
+    fn children(&self) -> Vec<&Arc<dyn ExecutionPlan>> {
+        // Leaf node
+        vec![]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        _children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> DFResult<Arc<dyn ExecutionPlan>> {
+        Ok(self)
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        _context: Arc<TaskContext>,
+    ) -> DFResult<SendableRecordBatchStream> {
+        if partition != 0 {
+            return Err(exec_datafusion_err!("partition not found"));
+        }
+
+        let chunks: Vec<StructArray> = self
+            .partitions
+            .iter()
+            .flatten()
+            .map(|chunk| chunk.clone().into_struct())
+            .collect::<VortexResult<_>>()
+            .map_err(|vortex_err| exec_datafusion_err!("{vortex_err}"))?;
+        let projection = self.projection.clone();
+        let aggregates = self.aggregates.clone();
+        let filter = self.filter.clone();
+        let schema_ref = self.schema();
+
+        let batch = futures::stream::once(async move {
+            let chunks = select_fields(chunks, &projection, filter.as_ref())?;
+            let columns = aggregates
+                .iter()
+                .zip(schema_ref.fields().iter())
+                .map(|(aggregate, field)| {
+                    compute_aggregate(&chunks, aggregate, field.data_type())
+                        .map_err(|vortex_err| exec_datafusion_err!("{vortex_err}"))?
+                        .to_array()
+                })
+                .collect::<DFResult<Vec<_>>>()?;
+
+            Ok(RecordBatch::try_new(schema_ref, columns)?)
+        });
+
+        Ok(Box::pin(RecordBatchStreamAdapter::new(
+            self.schema(),
+            batch,
+        )))
+    }
+}
+
+/// The projected fields of a chunk, restricted to the rows selected by a filter.
+struct SelectedFields {
+    len: usize,
+    /// Each field as stored, together with the validity of the structs along its path.
+    fields: Vec<(Array, Validity)>,
+}
+
+/// Select the projected fields of every chunk, taking only the rows selected by the filter.
+///
+/// Chunks with no selected rows are dropped, and chunks with all of their rows selected are kept
+/// as they are, so that their statistics can still be used.
+fn select_fields(
+    chunks: Vec<StructArray>,
+    projection: &[FieldPath],
+    filter: Option<&AggregateFilter>,
+) -> DFResult<Vec<SelectedFields>> {
+    let fields = |chunk: &StructArray| {
+        projection
+            .iter()
+            .map(|path| chunk.field_and_validity_by_path(path))
+            .collect::<VortexResult<Vec<_>>>()
+            .map_err(|vortex_err| exec_datafusion_err!("{vortex_err}"))
+    };
+
+    let Some(filter) = filter else {
+        return chunks
+            .iter()
+            .map(|chunk| {
+                Ok(SelectedFields {
+                    len: chunk.len(),
+                    fields: fields(chunk)?,
+                })
+            })
+            .collect();
+    };
+
+    let filter_chunks = filter.partitions.iter().flatten().cloned().collect_vec();
+    if filter_chunks.len() != chunks.len() {
+        return Err(exec_datafusion_err!(
+            "expected {} filtered chunks, found {}",
+            chunks.len(),
+            filter_chunks.len()
+        ));
+    }
+    let Some(first) = filter_chunks.first() else {
+        return Ok(Vec::new());
+    };
+    let chunk_filter = ChunkFilter::try_new(&filter.filter_exprs, first.dtype())?;
+
+    let mut selected = Vec::with_capacity(chunks.len());
+    for (chunk, filter_chunk) in chunks.iter().zip(filter_chunks) {
+        match chunk_filter.select(filter_chunk)? {
+            RowSelection::None => {}
+            RowSelection::All => selected.push(SelectedFields {
+                len: chunk.len(),
+                fields: fields(chunk)?,
+            }),
+            RowSelection::Indices(indices) if indices.is_empty() => {}
+            RowSelection::Indices(indices) => {
+                let len = indices.len();
+                let indices = PrimitiveArray::from(indices).into_array();
+                let fields = fields(chunk)?
+                    .into_iter()
+                    .map(|(field, validity)| {
+                        Ok((take(&field, &indices)?, validity.take(&indices)?))
+                    })
+                    .collect::<VortexResult<_>>()
+                    .map_err(|vortex_err| exec_datafusion_err!("{vortex_err}"))?;
+                selected.push(SelectedFields { len, fields });
+            }
+        }
+    }
+    Ok(selected)
+}
+
+/// Compute a single aggregate across all chunks from their statistics.
+fn compute_aggregate(
+    chunks: &[SelectedFields],
+    aggregate: &StatisticsAggregate,
+    data_type: &DataType,
+) -> VortexResult<ScalarValue> {
+    match aggregate {
+        StatisticsAggregate::CountRows => Ok(ScalarValue::Int64(Some(
+            chunks.iter().map(|chunk| chunk.len as i64).sum(),
+        ))),
+        StatisticsAggregate::CountValid(column) => {
+            let mut count = 0i64;
+            for chunk in chunks {
+                let (field, validity) = &chunk.fields[*column];
+                count += valid_count(field, validity)? as i64;
+            }
+            Ok(ScalarValue::Int64(Some(count)))
+        }
+        StatisticsAggregate::Min(column) | StatisticsAggregate::Max(column) => {
+            let (stat, ordering) = match aggregate {
+                StatisticsAggregate::Min(_) => (Stat::Min, Ordering::Less),
+                _ => (Stat::Max, Ordering::Greater),
+            };
+
+            let mut result: Option<Scalar> = None;
+            for chunk in chunks {
+                let (field, validity) = &chunk.fields[*column];
+                let Some(value) = min_max_statistic(field, validity, stat)? else {
+                    continue;
+                };
+                if result
+                    .as_ref()
+                    .map_or(true, |current| value.partial_cmp(current) == Some(ordering))
+                {
+                    result = Some(value);
+                }
+            }
+
+            match result {
+                Some(value) => Ok(ScalarValue::from(value)),
+                None => ScalarValue::try_from(data_type).map_err(|err| vortex_err!("{err}")),
+            }
+        }
+    }
+}
+
+/// Count the values of a field that are valid, and within valid structs along its path.
+///
+/// Only the validity of the field is combined with that of the structs, its values stay encoded.
+fn valid_count(field: &Array, validity: &Validity) -> VortexResult<usize> {
+    match validity.to_logical(field.len()) {
+        LogicalValidity::AllValid(len) => Ok(len - null_count(field)?),
+        LogicalValidity::AllInvalid(_) => Ok(0),
+        LogicalValidity::Array(_) => true_count(
+            field
+                .with_dyn(|a| a.logical_validity())
+                .into_validity()
+                .and(validity)?
+                .to_logical(field.len()),
+        ),
+    }
+}
+
+/// Count the null values of an array, preferring its `NullCount` statistic and falling back to
+/// the `TrueCount` of its validity.
+fn null_count(array: &Array) -> VortexResult<usize> {
+    if !array.dtype().is_nullable() {
+        return Ok(0);
+    }
+
+    if let Some(null_count) = array.statistics().compute_as::<usize>(Stat::NullCount) {
+        return Ok(null_count);
+    }
+
+    Ok(array.len() - true_count(array.with_dyn(|a| a.logical_validity()))?)
+}
+
+/// Count the valid values of a logical validity.
+fn true_count(validity: LogicalValidity) -> VortexResult<usize> {
+    match validity {
+        LogicalValidity::AllValid(len) => Ok(len),
+        LogicalValidity::AllInvalid(_) => Ok(0),
+        LogicalValidity::Array(validity) => {
+            match validity.statistics().compute_as::<usize>(Stat::TrueCount) {
+                Some(true_count) => Ok(true_count),
+                None => Ok(validity.into_bool()?.boolean_buffer().count_set_bits()),
+            }
+        }
+    }
+}
+
+/// Compute the min or max statistic of a field, ignoring nulls and the values within null
+/// structs along its path.
+///
+/// The field is only decoded to apply the validity of the structs if some of them are null.
+fn min_max_statistic(
+    field: &Array,
+    validity: &Validity,
+    stat: Stat,
+) -> VortexResult<Option<Scalar>> {
+    if validity.to_logical(field.len()).all_invalid() {
+        return Ok(None);
+    }
+    array_min_max_statistic(&validity.mask(field.clone())?, stat)
+}
+
+/// Compute the min or max statistic of an array, ignoring nulls.
+///
+/// Returns `None` if the array contains no non-null values. Arrays whose encoding cannot compute
+/// the statistic are canonicalized first. Long strings only have truncated bounds as statistics,
+/// in which case the exact value is computed from the strings themselves.
+fn array_min_max_statistic(array: &Array, stat: Stat) -> VortexResult<Option<Scalar>> {
+    let (array, bound) = match array.statistics().compute_bound(stat) {
+        Some(bound) => (array.clone(), Some(bound)),
+        None => {
+            let canonical = array.clone().into_canonical()?.into_array();
+            let bound = canonical.statistics().compute_bound(stat);
+            (canonical, bound)
+        }
+    };
+
+    match bound {
+        Some(bound) if bound.is_truncated => {
+            let dtype = array.dtype().clone();
+            array.into_varbin()?.with_iterator(|iter| {
+                let values = iter.flatten();
+                match stat {
+                    Stat::Max => values.max(),
+                    _ => values.min(),
+                }
+                .map(|value| varbin_scalar(value.to_vec(), &dtype))
+            })
+        }
+        bound => Ok(bound
+            .map(|bound| bound.value)
+            .filter(|value| !value.is_null())),
+    }
+}
+
+/// Decode the (possibly nested) fields at `projection` of a single struct chunk into a
+/// [RecordBatch] with one column per field path, named after the fields of `schema`.
+///
+/// If `indices` are provided, only the selected rows of each field are decoded. Sibling fields
+/// of the projected nested fields are never decoded.
+pub(crate) fn decode_fields(
+    chunk: Array,
+    projection: &[FieldPath],
+    schema: &Schema,
+    indices: Option<&Array>,
+) -> DFResult<RecordBatch> {
+    let struct_array = chunk
+        .into_struct()
+        .map_err(|vortex_err| exec_datafusion_err!("{vortex_err}"))?;
+    let row_count = indices.map_or_else(|| struct_array.len(), |indices| indices.len());
+
+    // If no columns in the output projection, we send back a RecordBatch with empty schema.
+    // This is common for COUNT queries.
+    if projection.is_empty() {
+        let opts = RecordBatchOptions::new().with_row_count(Some(row_count));
+        return Ok(RecordBatch::try_new_with_options(
+            Arc::new(Schema::empty()),
+            vec![],
+            &opts,
+        )?);
+    }
+
+    let (fields, columns): (Vec<Field>, Vec<ArrayRef>) = projection
+        .iter()
+        .zip(schema.fields().iter())
+        .map(|(path, field)| {
+            // The validity of the structs along the path is applied after the take, such that
+            // only the selected rows are decoded.
+            let (vortex_field, validity) = struct_array.field_and_validity_by_path(path)?;
+            let (vortex_field, validity) = match indices {
+                Some(indices) => (take(&vortex_field, indices)?, validity.take(indices)?),
+                None => (vortex_field, validity),
+            };
+            let column = validity.mask(vortex_field)?.into_canonical()?.into_arrow();
+
+            Ok((
+                Field::new(
+                    field.name(),
+                    column.data_type().clone(),
+                    field.is_nullable(),
+                ),
+                column,
+            ))
+        })
+        .collect::<VortexResult<Vec<_>>>()
+        .map_err(|vortex_err| {
+            exec_datafusion_err!("projection pushdown to Vortex failed: {vortex_err}")
+        })?
+        .into_iter()
+        .unzip();
+
+    Ok(RecordBatch::try_new(
+        Arc::new(Schema::new(fields)),
+        columns,
+    )?)
+}
+
 #[cfg(test)]
 mod test {
     use std::sync::Arc;
 
-    use arrow_array::{RecordBatch, UInt64Array};
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int64Type;
+    use arrow_array::{Int64Array, RecordBatch, UInt64Array};
     use arrow_schema::{DataType, Field, Schema};
     use datafusion_expr::{and, col, lit};
     use itertools::Itertools;
     use vortex::array::bool::BoolArray;
     use vortex::array::primitive::PrimitiveArray;
     use vortex::array::struct_::StructArray;
+    use vortex::stats::{ArrayStatistics, BloomFilter, Stat};
     use vortex::validity::Validity;
     use vortex::IntoArray;
+    use vortex_dtype::field::FieldPath;
+    use vortex_dtype::DType;
     use vortex_dtype::FieldName;
 
-    use crate::plans::{RowIndicesStream, ROW_SELECTOR_SCHEMA_REF};
+    use crate::plans::{
+        decode_fields, ChunkFilter, RowIndicesStream, RowSelection, ROW_SELECTOR_SCHEMA_REF,
+    };
 
     #[tokio::test]
     async fn test_filtering_stream() {
@@ -426,29 +1085,25 @@ mod test {
             Field::new("b", DataType::Boolean, false),
         ]));
 
-        let _schema = schema.clone();
-        let one_shot = Box::pin(async move {
-            StructArray::try_new(
-                Arc::new([FieldName::from("a"), FieldName::from("b")]),
-                vec![
-                    PrimitiveArray::from(vec![0u64, 1, 2]).into_array(),
-                    BoolArray::from(vec![false, false, true]).into_array(),
-                ],
-                3,
-                Validity::NonNullable,
-            )
-            .unwrap()
-            .into_array()
-        });
+        let chunk = StructArray::try_new(
+            Arc::new([FieldName::from("a"), FieldName::from("b")]),
+            vec![
+                PrimitiveArray::from(vec![0u64, 1, 2]).into_array(),
+                BoolArray::from(vec![false, false, true]).into_array(),
+            ],
+            3,
+            Validity::NonNullable,
+        )
+        .unwrap();
 
         let _schema = schema.clone();
-        let filtering_stream = RowIndicesStream {
-            one_shot,
-            polled_inner: false,
-            conjunction_expr: and((col("a") % lit(2u64)).eq(lit(0u64)), col("b").is_true()),
-            schema_ref: _schema,
-            context: Arc::new(Default::default()),
-        };
+        let filtering_stream = RowIndicesStream::new(
+            vec![chunk],
+            ChunkFilter::new(
+                and((col("a") % lit(2u64)).eq(lit(0u64)), col("b").is_true()),
+                _schema,
+            ),
+        );
 
         let rows: Vec<RecordBatch> = futures::executor::block_on_stream(filtering_stream)
             .try_collect()
@@ -467,4 +1122,98 @@ mod test {
             .unwrap()
         );
     }
+
+    #[test]
+    fn test_filtering_stream_skips_chunks() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::UInt64, false)]));
+        let chunk = |values: Vec<u64>, filtered: Vec<u64>| {
+            let a = PrimitiveArray::from(values).into_array();
+            let filter =
+                BloomFilter::try_build(&PrimitiveArray::from(filtered).into_array(), 0.001)
+                    .unwrap();
+            a.statistics().set(Stat::BloomFilter, filter.into());
+            StructArray::from_fields(&[("a", a)])
+        };
+        // The bloom filter of the first chunk doesn't contain its values, so that skipping it
+        // based on its statistics is observable.
+        let chunks = vec![
+            chunk(vec![4, 4, 4], vec![0, 1, 2]),
+            chunk(vec![3, 4, 5], vec![3, 4, 5]),
+        ];
+
+        let filtering_stream =
+            RowIndicesStream::new(chunks, ChunkFilter::new(col("a").eq(lit(4u64)), schema));
+        let rows: Vec<RecordBatch> = futures::executor::block_on_stream(filtering_stream)
+            .try_collect()
+            .unwrap();
+        assert_eq!(
+            rows,
+            vec![
+                RecordBatch::new_empty(ROW_SELECTOR_SCHEMA_REF.clone()),
+                RecordBatch::try_new(
+                    ROW_SELECTOR_SCHEMA_REF.clone(),
+                    vec![Arc::new(UInt64Array::from(vec![1u64]))]
+                )
+                .unwrap()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_select_from_statistics() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::UInt64, false),
+            Field::new("b", DataType::Int64, true),
+        ]));
+        let chunk = StructArray::from_fields(&[
+            ("a", PrimitiveArray::from(vec![1u64, 2, 3]).into_array()),
+            (
+                "b",
+                PrimitiveArray::from_nullable_vec(vec![Some(1i64), None, Some(3)]).into_array(),
+            ),
+        ]);
+        let select = |expr| {
+            ChunkFilter::new(expr, schema.clone())
+                .select(chunk.clone())
+                .unwrap()
+        };
+
+        assert!(matches!(select(col("a").gt(lit(0u64))), RowSelection::All));
+        assert!(matches!(select(col("a").gt(lit(3u64))), RowSelection::None));
+        assert!(matches!(select(lit(3u64).lt(col("a"))), RowSelection::None));
+        assert!(matches!(
+            select(col("a").gt_eq(lit(2u64))),
+            RowSelection::Indices(indices) if indices == vec![1, 2]
+        ));
+        // Null values are never selected.
+        assert!(matches!(
+            select(col("b").gt(lit(0i64))),
+            RowSelection::Indices(indices) if indices == vec![0, 2]
+        ));
+        assert!(matches!(
+            select(and(col("a").gt(lit(0u64)), col("b").lt(lit(0i64)))),
+            RowSelection::None
+        ));
+    }
+
+    #[test]
+    fn test_decode_nested_field_of_null_struct() {
+        let b = PrimitiveArray::from_vec(vec![1i64, 1, 2], Validity::NonNullable);
+        let a = StructArray::try_new(
+            [FieldName::from("b")].into(),
+            vec![b.into_array()],
+            3,
+            Validity::from(vec![true, false, true]),
+        )
+        .unwrap();
+        let chunk = StructArray::from_fields(&[("a", a.into_array())]).into_array();
+
+        let schema = Schema::new(vec![Field::new("b", DataType::Int64, true)]);
+        let path = FieldPath::from(vec![vortex_dtype::field::Field::from("a"), "b".into()]);
+        let batch = decode_fields(chunk, &[path], &schema, None).unwrap();
+        assert_eq!(
+            batch.column(0).as_primitive::<Int64Type>(),
+            &Int64Array::from(vec![Some(1), None, Some(2)])
+        );
+    }
 }
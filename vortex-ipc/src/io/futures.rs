@@ -3,9 +3,10 @@
 use std::io;
 
 use bytes::BytesMut;
-use futures_util::{AsyncRead, AsyncReadExt};
+use futures_util::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use vortex_buffer::io_buf::IoBuf;
 
-use crate::io::VortexRead;
+use crate::io::{VortexRead, VortexWrite};
 
 pub struct FuturesAdapter<IO>(pub IO);
 
@@ -15,3 +16,18 @@ impl<R: AsyncRead + Unpin> VortexRead for FuturesAdapter<R> {
         Ok(buffer)
     }
 }
+
+impl<W: AsyncWrite + Unpin> VortexWrite for FuturesAdapter<W> {
+    async fn write_all<B: IoBuf>(&mut self, buffer: B) -> io::Result<B> {
+        self.0.write_all(buffer.as_slice()).await?;
+        Ok(buffer)
+    }
+
+    async fn flush(&mut self) -> io::Result<()> {
+        self.0.flush().await
+    }
+
+    async fn shutdown(&mut self) -> io::Result<()> {
+        self.0.close().await
+    }
+}
@@ -51,13 +51,22 @@ pub mod test {
     use itertools::Itertools;
     use vortex::array::chunked::ChunkedArray;
     use vortex::array::primitive::{PrimitiveArray, PrimitiveEncoding};
+    use vortex::array::struct_::StructArray;
+    use vortex::array::varbin::VarBinArray;
+    use vortex::compute::unary::scalar_at::scalar_at;
     use vortex::encoding::ArrayEncoding;
     use vortex::encoding::EncodingRef;
+    use vortex::stats::{ArrayStatistics, BloomFilter, Stat};
     use vortex::stream::ArrayStreamExt;
+    use vortex::validity::Validity;
     use vortex::{ArrayDType, Context, IntoArray, ViewContext};
     use vortex_alp::ALPEncoding;
+    use vortex_dtype::field::FieldPath;
+    use vortex_dtype::{DType, Nullability};
     use vortex_error::VortexResult;
+    use vortex_expr::{lit, Disjunction};
     use vortex_fastlanes::BitPackedEncoding;
+    use vortex_roaring::{RoaringIntArray, RoaringIntEncoding};
 
     use crate::io::FuturesAdapter;
     use crate::writer::ArrayWriter;
@@ -162,4 +171,212 @@ pub mod test {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_distinct_count_stats() -> VortexResult<()> {
+        let chunks = [0i32..1000, 500..1500]
+            .into_iter()
+            .map(|range| PrimitiveArray::from(range.collect_vec()).into_array())
+            .collect_vec();
+        let distinct_counts = chunks
+            .iter()
+            .map(|chunk| chunk.statistics().compute_distinct_count())
+            .collect_vec();
+        let dtype = chunks[0].dtype().clone();
+        let buffer = write_ipc(ChunkedArray::try_new(chunks, dtype)?).await;
+
+        let ctx = Context::default();
+        let mut messages = MessageReader::try_new(FuturesAdapter(Cursor::new(buffer))).await?;
+        let chunks: Vec<_> = messages
+            .array_stream_from_messages(&ctx)
+            .await?
+            .try_collect()
+            .await?;
+
+        for (i, chunk) in chunks.iter().enumerate() {
+            assert_eq!(
+                chunk.statistics().get_as::<usize>(Stat::DistinctCount),
+                distinct_counts[i]
+            );
+            // Only the estimate is persisted, not the sketch it was computed from.
+            assert_eq!(chunk.statistics().get(Stat::DistinctCountSketch), None);
+        }
+
+        // Without their sketches, the larger distinct count of the chunks is kept as a lower bound.
+        let mut stats = chunks[0].statistics().to_set();
+        stats.merge(&chunks[1].statistics().to_set());
+        assert_eq!(
+            stats.get_as::<usize>(Stat::DistinctCount),
+            distinct_counts[0].max(distinct_counts[1])
+        );
+        assert_eq!(stats.get(Stat::DistinctCountSketch), None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_truncated_bound_stats() -> VortexResult<()> {
+        let chunks = [
+            ["a".repeat(100), "b".repeat(10)],
+            ["a".repeat(10), "c".repeat(100)],
+        ]
+        .into_iter()
+        .map(|values| {
+            let chunk =
+                VarBinArray::from_vec(values.to_vec(), DType::Utf8(Nullability::NonNullable))
+                    .into_array();
+            chunk.statistics().compute(Stat::Min);
+            chunk
+        })
+        .collect_vec();
+        let dtype = chunks[0].dtype().clone();
+        let buffer = write_ipc(ChunkedArray::try_new(chunks, dtype)?).await;
+
+        let ctx = Context::default();
+        let mut messages = MessageReader::try_new(FuturesAdapter(Cursor::new(buffer))).await?;
+        let chunks: Vec<_> = messages
+            .array_stream_from_messages(&ctx)
+            .await?
+            .try_collect()
+            .await?;
+
+        let first = chunks[0].statistics();
+        assert_eq!(first.get(Stat::Min), Some("a".repeat(64).as_str().into()));
+        assert_eq!(first.get_as::<bool>(Stat::IsMinTruncated), Some(true));
+        assert_eq!(first.get(Stat::Max), Some("b".repeat(10).as_str().into()));
+        assert_eq!(first.get_as::<bool>(Stat::IsMaxTruncated), Some(false));
+
+        let mut stats = first.to_set();
+        stats.merge(&chunks[1].statistics().to_set());
+        // The exact minimum of the second chunk is smaller than the truncated one of the first.
+        assert_eq!(stats.get(Stat::Min), Some(&"a".repeat(10).as_str().into()));
+        assert_eq!(stats.get(Stat::IsMinTruncated), Some(&false.into()));
+        assert_eq!(
+            stats.get(Stat::Max),
+            Some(&format!("{}d", "c".repeat(63)).as_str().into())
+        );
+        assert_eq!(stats.get(Stat::IsMaxTruncated), Some(&true.into()));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_bloom_filter_stats() -> VortexResult<()> {
+        let array = PrimitiveArray::from((0i32..1000).map(|i| i * 3).collect_vec());
+        let filter = BloomFilter::try_build(array.array(), 0.01)?;
+        array
+            .statistics()
+            .set(Stat::BloomFilter, filter.clone().into());
+        let buffer = write_ipc(array).await;
+
+        let ctx = Context::default();
+        let mut messages = MessageReader::try_new(FuturesAdapter(Cursor::new(buffer))).await?;
+        let chunks: Vec<_> = messages
+            .array_stream_from_messages(&ctx)
+            .await?
+            .try_collect()
+            .await?;
+        assert_eq!(
+            chunks[0]
+                .statistics()
+                .get_as::<BloomFilter>(Stat::BloomFilter),
+            Some(filter)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_skip_chunks() -> VortexResult<()> {
+        let chunks = [0i64, 1000]
+            .into_iter()
+            .map(|start| {
+                let ids = PrimitiveArray::from((start..start + 1000).collect_vec()).into_array();
+                let filter = BloomFilter::try_build(&ids, 0.01)?;
+                ids.statistics().set(Stat::BloomFilter, filter.into());
+                Ok(StructArray::try_new(
+                    ["id".into()].into(),
+                    vec![ids],
+                    1000,
+                    Validity::NonNullable,
+                )?
+                .into_array())
+            })
+            .collect::<VortexResult<Vec<_>>>()?;
+        let dtype = chunks[0].dtype().clone();
+        let buffer = write_ipc(ChunkedArray::try_new(chunks, dtype)?).await;
+
+        let ctx = Context::default();
+        let mut messages = MessageReader::try_new(FuturesAdapter(Cursor::new(buffer))).await?;
+        let filter = Disjunction::from_iter([lit(1500i64).eq(FieldPath::from_name("id"))]);
+        let chunks: Vec<_> = messages
+            .array_stream_from_messages(&ctx)
+            .await?
+            .skip_chunks(filter)
+            .try_collect()
+            .await?;
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(
+            scalar_at(&StructArray::try_from(&chunks[0])?.field(0).unwrap(), 0)?,
+            1000i64.into()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_view_metadata_is_shared() -> VortexResult<()> {
+        let ctx = Context::default().with_encoding(&RoaringIntEncoding);
+        let array = RoaringIntArray::encode(PrimitiveArray::from(vec![1u32, 5, 7]).into_array())?;
+        let buffer = ArrayWriter::new(vec![], ViewContext::from(&ctx))
+            .write_context()
+            .await?
+            .write_array(array)
+            .await?
+            .into_inner();
+
+        let mut messages = MessageReader::try_new(FuturesAdapter(Cursor::new(buffer))).await?;
+        let chunks: Vec<_> = messages
+            .array_stream_from_messages(&ctx)
+            .await?
+            .try_collect()
+            .await?;
+        // The bitmap is deserialized once per view, not once per conversion of the view.
+        let first = RoaringIntArray::try_from(&chunks[0])?;
+        let second = RoaringIntArray::try_from(&chunks[0])?;
+        assert!(std::ptr::eq(first.bitmap(), second.bitmap()));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_reader_offsets() -> VortexResult<()> {
+        let chunk = PrimitiveArray::from((0i32..1000).collect_vec()).into_array();
+        let chunked =
+            ChunkedArray::try_new(vec![chunk.clone(), chunk.clone()], chunk.dtype().clone())?;
+        let writer = ArrayWriter::new(vec![], ViewContext::from(&Context::default()))
+            .write_context()
+            .await?
+            .write_array(chunked.into_array())
+            .await?;
+        let layout = writer.array_layouts()[0].chunks.clone();
+        let buffer = writer.into_inner();
+
+        let ctx = Context::default();
+        let mut messages = MessageReader::try_new(FuturesAdapter(Cursor::new(buffer))).await?;
+        let view_ctx = messages.read_view_context(&ctx).await?;
+        let dtype = messages.read_dtype().await?;
+
+        let mut byte_offsets = vec![messages.tell()];
+        while messages
+            .maybe_read_chunk(view_ctx.clone(), dtype.clone())
+            .await?
+            .is_some()
+        {
+            byte_offsets.push(messages.tell());
+        }
+        assert_eq!(byte_offsets, layout.byte_offsets);
+
+        Ok(())
+    }
 }
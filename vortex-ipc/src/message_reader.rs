@@ -20,6 +20,10 @@ pub struct MessageReader<R> {
     message: BytesMut,
     prev_message: BytesMut,
     finished: bool,
+    /// The number of bytes read so far.
+    offset: u64,
+    /// The offset of the current message.
+    message_offset: u64,
 }
 
 impl<R: VortexRead> MessageReader<R> {
@@ -29,12 +33,26 @@ impl<R: VortexRead> MessageReader<R> {
             message: BytesMut::new(),
             prev_message: BytesMut::new(),
             finished: false,
+            offset: 0,
+            message_offset: 0,
         };
-        reader.load_next_message().await?;
+        if !reader.load_next_message().await? {
+            reader.finished = true;
+        }
         Ok(reader)
     }
 
+    /// The offset in the underlying read of the next unread message, or of the end of the stream
+    /// once every message has been read.
+    ///
+    /// Offsets match those reported by [`MessageWriter::tell`](crate::MessageWriter::tell) when
+    /// the stream was written.
+    pub fn tell(&self) -> u64 {
+        self.message_offset
+    }
+
     async fn load_next_message(&mut self) -> VortexResult<bool> {
+        self.message_offset = self.offset;
         let mut buffer = std::mem::take(&mut self.message);
         buffer.resize(4, 0);
         let mut buffer = match self.read.read_into(buffer).await {
@@ -48,6 +66,7 @@ impl<R: VortexRead> MessageReader<R> {
         };
 
         let len = buffer.get_u32_le();
+        self.offset += 4;
         if len == u32::MAX {
             // Marker for no more messages.
             return Ok(false);
@@ -58,6 +77,7 @@ impl<R: VortexRead> MessageReader<R> {
         buffer.reserve(len as usize);
         unsafe { buffer.set_len(len as usize) };
         self.message = self.read.read_into(buffer).await?;
+        self.offset += len as u64;
 
         // Validate that the message is valid a flatbuffer.
         root::<fb::Message>(&self.message).map_err(
@@ -105,9 +125,11 @@ impl<R: VortexRead> MessageReader<R> {
         };
 
         // Issue a single read to grab all buffers
-        let mut all_buffers = BytesMut::with_capacity(chunk_msg.buffer_size() as usize);
-        unsafe { all_buffers.set_len(chunk_msg.buffer_size() as usize) };
+        let buffer_size = chunk_msg.buffer_size();
+        let mut all_buffers = BytesMut::with_capacity(buffer_size as usize);
+        unsafe { all_buffers.set_len(buffer_size as usize) };
         let mut all_buffers = self.read.read_into(all_buffers).await?;
+        self.offset += buffer_size;
 
         // Split out into individual buffers
         // Initialize the column's buffers for a vectored read.
@@ -257,6 +279,7 @@ impl<R: VortexRead> MessageReader<R> {
         let mut buffer = BytesMut::with_capacity(total_len);
         unsafe { buffer.set_len(total_len) }
         buffer = self.read.read_into(buffer).await?;
+        self.offset += total_len as u64;
         buffer.truncate(buffer_len);
         let page_buffer = Ok(Some(Buffer::from(buffer.freeze())));
         let _ = self.next().await?;
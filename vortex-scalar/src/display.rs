@@ -2,8 +2,13 @@ use std::fmt::{Display, Formatter};
 
 use vortex_dtype::{match_each_native_ptype, DType};
 
+use crate::binary::BinaryScalar;
 use crate::bool::BoolScalar;
+use crate::extension::ExtScalar;
+use crate::list::ListScalar;
 use crate::primitive::PrimitiveScalar;
+use crate::struct_::StructScalar;
+use crate::utf8::Utf8Scalar;
 use crate::Scalar;
 
 impl Display for Scalar {
@@ -20,17 +25,75 @@ impl Display for Scalar {
                     Some(v) => write!(f, "{}", v),
                 }
             }),
-            DType::Utf8(_) => todo!(),
-            DType::Binary(_) => todo!(),
-            DType::Struct(..) => todo!(),
-            DType::List(..) => todo!(),
-            DType::Extension(..) => todo!(),
+            DType::Utf8(_) => match Utf8Scalar::try_from(self).expect("utf8").value() {
+                None => write!(f, "null"),
+                Some(s) => write!(f, "\"{}\"", s.as_str().escape_debug()),
+            },
+            DType::Binary(_) => match BinaryScalar::try_from(self).expect("binary").value() {
+                None => write!(f, "null"),
+                Some(b) => {
+                    write!(f, "0x")?;
+                    b.as_slice()
+                        .iter()
+                        .try_for_each(|byte| write!(f, "{:02x}", byte))
+                }
+            },
+            DType::Struct(st, _) => {
+                if self.is_null() {
+                    return write!(f, "null");
+                }
+                let strct = StructScalar::try_from(self).expect("struct");
+                write!(f, "{{")?;
+                for (idx, (name, dtype)) in st.names().iter().zip(st.dtypes().iter()).enumerate() {
+                    if idx > 0 {
+                        write!(f, ", ")?;
+                    }
+                    match strct.field_by_idx(idx, dtype.clone()) {
+                        None => write!(f, "{}=null", name)?,
+                        Some(field) => write!(f, "{}={}", name, field)?,
+                    }
+                }
+                write!(f, "}}")
+            }
+            DType::List(..) => {
+                if self.is_null() {
+                    return write!(f, "null");
+                }
+                write!(f, "[")?;
+                for (idx, element) in ListScalar::try_from(self)
+                    .expect("list")
+                    .elements()
+                    .enumerate()
+                {
+                    if idx > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", element)?;
+                }
+                write!(f, "]")
+            }
+            DType::Extension(ext, _) => {
+                if self.is_null() {
+                    return write!(f, "null");
+                }
+                write!(
+                    f,
+                    "{}({:?})",
+                    ext.id(),
+                    ExtScalar::try_from(self).expect("extension").value()
+                )
+            }
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use vortex_dtype::Nullability::NonNullable;
+    use vortex_dtype::{DType, PType};
+
+    use crate::value::ScalarValue;
+    use crate::PValue;
     use crate::Scalar;
 
     #[test]
@@ -38,4 +101,28 @@ mod tests {
         let scalar = Scalar::from(false);
         assert_eq!(format!("{}", scalar), "false");
     }
+
+    #[test]
+    fn display_varbin() {
+        assert_eq!(
+            Scalar::utf8("a\"b".to_string(), NonNullable).to_string(),
+            "\"a\\\"b\""
+        );
+        assert_eq!(
+            Scalar::binary(vec![0u8, 255].into(), NonNullable).to_string(),
+            "0x00ff"
+        );
+    }
+
+    #[test]
+    fn display_list() {
+        let scalar = Scalar::list(
+            DType::Primitive(PType::U64, NonNullable),
+            vec![
+                ScalarValue::Primitive(PValue::U64(1)),
+                ScalarValue::Primitive(PValue::U64(2)),
+            ],
+        );
+        assert_eq!(scalar.to_string(), "[1, 2]");
+    }
 }